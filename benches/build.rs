@@ -0,0 +1,34 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use yatlv::{FrameBuilder, FrameBuilderLike};
+
+const FIELD_COUNT: u16 = 256;
+
+fn build(c: &mut Criterion) {
+    let mut data = Vec::with_capacity(4096);
+
+    let mut group = c.benchmark_group("build 256 fields");
+    group.bench_function("u8", |b| {
+        b.iter(|| {
+            data.clear();
+            let mut bld = FrameBuilder::new(&mut data);
+            for tag in 0..FIELD_COUNT {
+                bld.add_u8(tag, black_box(tag as u8));
+            }
+            bld.finish()
+        })
+    });
+    group.bench_function("u8 as data", |b| {
+        b.iter(|| {
+            data.clear();
+            let mut bld = FrameBuilder::new(&mut data);
+            for tag in 0..FIELD_COUNT {
+                bld.add_data(tag, &[black_box(tag as u8)]);
+            }
+            bld.finish()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, build);
+criterion_main!(benches);
@@ -0,0 +1,41 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+
+const VALUE_COUNT: u32 = 4096;
+
+fn build_frame() -> Vec<u8> {
+    let mut data = Vec::new();
+    {
+        let mut bld = FrameBuilder::new(&mut data);
+        for value in 0..VALUE_COUNT {
+            bld.add_u32(1, value);
+        }
+    }
+    data
+}
+
+fn decode(c: &mut Criterion) {
+    let data = build_frame();
+    let parser = FrameParser::new(&data).unwrap();
+    let mut values = Vec::with_capacity(VALUE_COUNT as usize);
+
+    let mut group = c.benchmark_group("decode 4096 repeated u32 fields");
+    group.bench_function("collect", |b| {
+        b.iter(|| {
+            black_box(&parser)
+                .get_u32s(1)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        })
+    });
+    group.bench_function("into reused buffer", |b| {
+        b.iter(|| {
+            values.clear();
+            black_box(&parser).get_u32s_into(1, &mut values).unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, decode);
+criterion_main!(benches);
@@ -0,0 +1,44 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+
+const FIELD_COUNT: u16 = 256;
+
+fn build_frame() -> Vec<u8> {
+    let mut data = Vec::new();
+    {
+        let mut bld = FrameBuilder::new(&mut data);
+        for tag in 0..FIELD_COUNT {
+            bld.add_u32(tag, tag as u32);
+        }
+    }
+    data
+}
+
+fn read_all(parser: &FrameParser) -> u32 {
+    (0..FIELD_COUNT)
+        .map(|tag| parser.get_u32(tag).unwrap().unwrap())
+        .sum()
+}
+
+fn lookup(c: &mut Criterion) {
+    let data = build_frame();
+
+    let mut group = c.benchmark_group("parse 256 fields");
+    group.bench_function("linear", |b| {
+        b.iter(|| FrameParser::new(black_box(&data)).unwrap())
+    });
+    group.bench_function("indexed", |b| {
+        b.iter(|| FrameParser::new_indexed(black_box(&data)).unwrap())
+    });
+    group.finish();
+
+    let linear = FrameParser::new(&data).unwrap();
+    let indexed = FrameParser::new_indexed(&data).unwrap();
+    let mut group = c.benchmark_group("read 256 fields");
+    group.bench_function("linear", |b| b.iter(|| read_all(black_box(&linear))));
+    group.bench_function("indexed", |b| b.iter(|| read_all(black_box(&indexed))));
+    group.finish();
+}
+
+criterion_group!(benches, lookup);
+criterion_main!(benches);
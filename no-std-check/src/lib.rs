@@ -0,0 +1,26 @@
+//! Checks that yatlv can be used from a `no_std` crate.
+//!
+//! Build with `cargo build -p yatlv-no-std-check`; building the whole workspace
+//! would unify yatlv's features and enable `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser, Result, SliceFrameBuilder};
+
+/// Write a frame holding `value`, then read it back.
+pub fn round_trip(value: u32) -> Result<Option<u32>> {
+    let mut data = Vec::new();
+    FrameBuilder::new(&mut data).add_u32(1, value);
+    FrameParser::new(&data)?.get_u32(1)
+}
+
+/// Write a frame holding `value` into `buf` without allocating.
+pub fn write_to_slice(buf: &mut [u8], value: u32) -> Result<usize> {
+    let mut bld = SliceFrameBuilder::new(buf)?;
+    bld.add_u32(1, value)?;
+    Ok(bld.finish())
+}
@@ -0,0 +1,135 @@
+use alloc::vec::Vec;
+
+use bytes::Bytes;
+
+use crate::{FrameParser, Result};
+
+/// BytesFrameParser reads a frame held in a [Bytes] buffer.
+///
+/// Field-values are returned as [Bytes] that share the frame's buffer, so reading
+/// them does not copy.  The typed `get_*` methods are available through
+/// [BytesFrameParser::parser].
+///
+/// Builders need no special support; a [Bytes] value can be added with
+/// [crate::FrameBuilderLike::add_data].
+///
+/// ```
+/// # use bytes::Bytes;
+/// # use yatlv::{BytesFrameParser, FrameBuilder, FrameBuilderLike, Result};
+/// # fn main() -> Result<()> {
+/// let mut frame_data = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new(&mut frame_data);
+///     bld.add_data(12, &Bytes::from_static(b"hello"));
+///     bld.add_u8(13, 9);
+/// }
+///
+/// let frame = BytesFrameParser::new(Bytes::from(frame_data))?;
+/// assert_eq!(Some(Bytes::from_static(b"hello")), frame.get_bytes(12));
+/// assert_eq!(Some(9), frame.parser().get_u8(13)?);
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BytesFrameParser {
+    data: Bytes,
+    fields: Vec<(u16, Bytes)>,
+}
+
+impl BytesFrameParser {
+    /// Parse the frame held in `frame_data`.
+    pub fn new(frame_data: Bytes) -> Result<BytesFrameParser> {
+        let fields = FrameParser::new(&frame_data)?
+            .fields()
+            .map(|(tag, value)| (tag, frame_data.slice_ref(value)))
+            .collect();
+        Ok(BytesFrameParser {
+            data: frame_data,
+            fields,
+        })
+    }
+
+    /// Create a [FrameParser] that borrows from this frame.
+    pub fn parser(&self) -> FrameParser<'_> {
+        FrameParser::new(&self.data).expect("frame was validated on construction")
+    }
+
+    /// Read field from frame.
+    pub fn get_bytes(&self, search_tag: u16) -> Option<Bytes> {
+        self.get_bytes_all(search_tag).next()
+    }
+
+    /// Read fields from frame.
+    pub fn get_bytes_all(&self, search_tag: u16) -> impl Iterator<Item = Bytes> + '_ {
+        self.fields
+            .iter()
+            .filter(move |(tag, _)| *tag == search_tag)
+            .map(|(_, value)| value.clone())
+    }
+
+    /// Read a child frame from a frame.
+    pub fn get_frame(&self, search_tag: u16) -> Result<Option<BytesFrameParser>> {
+        self.get_frames(search_tag).next().transpose()
+    }
+
+    /// Read child frames from a frame.
+    pub fn get_frames(
+        &self,
+        search_tag: u16,
+    ) -> impl Iterator<Item = Result<BytesFrameParser>> + '_ {
+        self.get_bytes_all(search_tag).map(BytesFrameParser::new)
+    }
+
+    /// Access the encoded frame.
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.data
+    }
+
+    /// Release the encoded frame.
+    pub fn into_bytes(self) -> Bytes {
+        self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, FrameBuilder, FrameBuilderLike};
+
+    #[test]
+    fn field_values_share_the_frame_buffer() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_str(1, "hello");
+            bld.add_str(1, "goodbye");
+            bld.add_frame(2).add_str(3, "child");
+        }
+        let frame = BytesFrameParser::new(Bytes::from(data)).unwrap();
+        let range = frame.as_bytes().as_ptr_range();
+
+        let values: Vec<Bytes> = frame.get_bytes_all(1).collect();
+        assert_eq!(vec![&b"hello"[..], &b"goodbye"[..]], values);
+        assert!(values.iter().all(|v| range.contains(&v.as_ptr())));
+
+        let child = frame.get_frame(2).unwrap().unwrap();
+        let value = child.get_bytes(3).unwrap();
+        assert_eq!(&b"child"[..], value);
+        assert!(range.contains(&value.as_ptr()));
+        assert_eq!(Some("child"), child.parser().get_str(3).unwrap());
+
+        assert_eq!(None, frame.get_bytes(4));
+        assert!(frame.get_frame(4).unwrap().is_none());
+        assert_eq!(
+            Some(Error::InvalidFrameFormat(b'h' as u32)),
+            frame.get_frame(1).err()
+        );
+    }
+
+    #[test]
+    fn can_not_parse_invalid_bytes() {
+        assert_eq!(
+            Some(Error::IncompleteFrameFieldCount),
+            BytesFrameParser::new(Bytes::from_static(&[1, 0, 0])).err()
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use alloc::vec::Vec;
+
+use crate::{Endianness, FrameBuilderLike, FrameParser, PacketFrameBuilder, SIZE_BYTES};
+
+/// CanonicalFrameBuilder builds a frame whose encoding does not depend on the order
+/// the fields were added in.
+///
+/// [FrameBuilder](crate::FrameBuilder) writes each field as it is added, so two
+/// frames with the same fields can have different encodings.  This builder instead
+/// collects the fields and, when finished, writes them sorted by tag (and fields
+/// with the same tag by field-value), giving a single canonical byte sequence.
+/// This is needed when frames are signed or hashed.
+///
+/// Child frames added with [FrameBuilderLike::add_frame] keep their fields in the
+/// order they were added; use [CanonicalFrameBuilder::add_canonical_frame] for
+/// child frames that must also be canonical.
+///
+/// The field positions are only known once the fields are sorted, so the `start`
+/// returned by the `add_*` methods is always 0.
+///
+/// ```
+/// use yatlv::{CanonicalFrameBuilder, FrameBuilderLike};
+/// let mut data1 = Vec::new();
+/// let mut bld = CanonicalFrameBuilder::new();
+/// bld.add_u8(2, 20);
+/// bld.add_u8(1, 10);
+/// bld.finish(&mut data1);
+///
+/// let mut data2 = Vec::new();
+/// let mut bld = CanonicalFrameBuilder::new();
+/// bld.add_u8(1, 10);
+/// bld.add_u8(2, 20);
+/// bld.finish(&mut data2);
+///
+/// assert_eq!(data1, data2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CanonicalFrameBuilder {
+    /// The field-tag and encoded field-length and field-value of each field.
+    fields: Vec<(u16, Vec<u8>)>,
+    endianness: Endianness,
+}
+
+impl Default for CanonicalFrameBuilder {
+    fn default() -> Self {
+        CanonicalFrameBuilder::new()
+    }
+}
+
+impl CanonicalFrameBuilder {
+    /// Create a CanonicalFrameBuilder that encodes numbers as big-endian.
+    pub fn new() -> CanonicalFrameBuilder {
+        CanonicalFrameBuilder::new_with_endianness(Endianness::Big)
+    }
+
+    /// Create a CanonicalFrameBuilder that encodes numbers using the given byte order.
+    pub fn new_with_endianness(endianness: Endianness) -> CanonicalFrameBuilder {
+        CanonicalFrameBuilder {
+            fields: Vec::new(),
+            endianness,
+        }
+    }
+
+    /// Add a child frame, which is also written in canonical order.
+    ///
+    /// ```
+    /// use yatlv::{CanonicalFrameBuilder, FrameBuilder, FrameBuilderLike};
+    /// let mut child = CanonicalFrameBuilder::new();
+    /// child.add_u8(2, 20);
+    /// child.add_u8(1, 10);
+    /// let mut bld = CanonicalFrameBuilder::new();
+    /// bld.add_canonical_frame(3, child);
+    /// let mut data = Vec::new();
+    /// bld.finish(&mut data);
+    ///
+    /// let mut expected = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut expected);
+    ///     let mut child = bld.add_frame(3);
+    ///     child.add_u8(1, 10);
+    ///     child.add_u8(2, 20);
+    /// }
+    /// assert_eq!(expected, data);
+    /// ```
+    pub fn add_canonical_frame(
+        &mut self,
+        tag: u16,
+        child: CanonicalFrameBuilder,
+    ) -> (usize, usize) {
+        let mut value = Vec::new();
+        child.finish(&mut value);
+        self.add_data(tag, &value)
+    }
+
+    /// Sort the fields and write the frame to the end of `data`, returning the number
+    /// of bytes written.
+    pub fn finish(mut self, data: &mut Vec<u8>) -> usize {
+        self.fields
+            .sort_unstable_by(|(tag1, field1), (tag2, field2)| {
+                (tag1, &field1[SIZE_BYTES..]).cmp(&(tag2, &field2[SIZE_BYTES..]))
+            });
+        let frame_start = data.len();
+        data.push(1);
+        data.extend_from_slice(&(self.fields.len() as u32).to_be_bytes());
+        for (tag, field) in &self.fields {
+            data.extend_from_slice(&tag.to_be_bytes());
+            data.extend_from_slice(field);
+        }
+        data.len() - frame_start
+    }
+}
+
+impl FrameBuilderLike for CanonicalFrameBuilder {
+    fn add_data(&mut self, tag: u16, value: &[u8]) -> (usize, usize) {
+        let mut field = Vec::with_capacity(SIZE_BYTES + value.len());
+        field.extend_from_slice(&(value.len() as u32).to_be_bytes());
+        field.extend_from_slice(value);
+        self.fields.push((tag, field));
+        (0, value.len())
+    }
+
+    fn add_frame(&mut self, tag: u16) -> PacketFrameBuilder<'_> {
+        self.fields.push((tag, Vec::new()));
+        let field = &mut self.fields.last_mut().unwrap().1;
+        PacketFrameBuilder::new_with_endianness(field, self.endianness)
+    }
+
+    fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+}
+
+/// CanonicalFrame is a view of the fields of a frame that ignores the order they
+/// were added in, so that frames can be used as keys in sets and maps.
+///
+/// The fields are sorted by tag (and fields with the same tag by field-value), the
+/// same order that [CanonicalFrameBuilder] writes them in.  Two frames with the same
+/// fields have equal views, which also hash the same.  Unlike
+/// [FrameParser::semantic_eq], child frames are compared by their encoding.
+///
+/// ```
+/// use std::collections::HashSet;
+/// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+/// let mut data1 = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new(&mut data1);
+///     bld.add_u8(1, 10);
+///     bld.add_u8(2, 20);
+/// }
+/// let mut data2 = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new_checked(&mut data2);
+///     bld.add_u8(2, 20);
+///     bld.add_u8(1, 10);
+/// }
+///
+/// let frame1 = FrameParser::new(&data1).unwrap();
+/// let frame2 = FrameParser::new(&data2).unwrap();
+/// let mut frames = HashSet::new();
+/// assert!(frames.insert(frame1.canonical()));
+/// assert!(!frames.insert(frame2.canonical()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CanonicalFrame<'a> {
+    fields: Vec<(u16, &'a [u8])>,
+}
+
+impl<'a> CanonicalFrame<'a> {
+    /// The tags and values of the fields, in canonical order.
+    pub fn fields(&self) -> impl Iterator<Item = (u16, &'a [u8])> + '_ {
+        self.fields.iter().copied()
+    }
+}
+
+impl<'a> FrameParser<'a> {
+    /// Create a view of this frame's fields that ignores their order, which
+    /// implements [Eq], [Ord] and [Hash](core::hash::Hash) (see [CanonicalFrame]).
+    pub fn canonical(&self) -> CanonicalFrame<'a> {
+        let mut fields: Vec<_> = self.fields.iter().map(|f| (f.tag, f.value)).collect();
+        fields.sort_unstable();
+        CanonicalFrame { fields }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FrameBuilder, FrameParser, ParseOptions};
+
+    #[test]
+    fn duplicate_tags_are_sorted_by_value() {
+        let mut data = Vec::new();
+        let mut bld = CanonicalFrameBuilder::new();
+        bld.add_str(1, "b");
+        bld.add_u16(0, 300);
+        bld.add_str(1, "ab");
+        bld.add_str(1, "a");
+        assert_eq!(35, bld.finish(&mut data));
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(
+            vec![(0, &[1, 44][..]), (1, b"a"), (1, b"ab"), (1, b"b")],
+            frame.fields().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn child_frames_are_written_as_field_values() {
+        let mut data = Vec::new();
+        let mut bld = CanonicalFrameBuilder::new_with_endianness(Endianness::Little);
+        bld.add_frame(2).add_u16(3, 4);
+        bld.add_frame(1);
+        let len = bld.finish(&mut data);
+
+        let mut expected = Vec::new();
+        {
+            let mut bld = FrameBuilder::new_with_endianness(&mut expected, Endianness::Little);
+            bld.add_frame(1);
+            bld.add_frame(2).add_u16(3, 4);
+        }
+        assert_eq!(expected, data);
+        assert_eq!(len, data.len());
+
+        let options = ParseOptions {
+            endianness: Endianness::Little,
+            ..ParseOptions::default()
+        };
+        let frame = FrameParser::new_with_options(&data, options).unwrap();
+        let child = frame.get_frame_required(2).unwrap();
+        assert_eq!(Some(4), child.get_u16(3).unwrap());
+    }
+
+    #[test]
+    fn canonical_views_match_canonical_encodings() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_str(1, "b");
+            bld.add_u16(0, 300);
+            bld.add_str(1, "a");
+        }
+        let mut canonical_data = Vec::new();
+        let mut bld = CanonicalFrameBuilder::new();
+        bld.add_all_from(&FrameParser::new(&data).unwrap());
+        bld.finish(&mut canonical_data);
+
+        let frame = FrameParser::new(&data).unwrap();
+        let canonical_frame = FrameParser::new(&canonical_data).unwrap();
+        assert_eq!(frame.canonical(), canonical_frame.canonical());
+        assert_eq!(
+            canonical_frame.fields().collect::<Vec<_>>(),
+            frame.canonical().fields().collect::<Vec<_>>()
+        );
+
+        let mut other_data = Vec::new();
+        FrameBuilder::new(&mut other_data).add_u16(0, 301);
+        let other_frame = FrameParser::new(&other_data).unwrap();
+        assert!(frame.canonical() < other_frame.canonical());
+    }
+
+    #[test]
+    fn frames_are_appended_to_the_buffer() {
+        let mut data = vec![9];
+        CanonicalFrameBuilder::default().finish(&mut data);
+        assert_eq!(&[9, 1, 0, 0, 0, 0], &data[..]);
+    }
+}
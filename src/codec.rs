@@ -0,0 +1,168 @@
+use std::convert::TryInto;
+use std::io::{Error, ErrorKind, Result};
+
+use bytes::{Buf, BufMut, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{frame_encoded_len, BytesFrameParser, SIZE_BYTES};
+
+/// YatlvCodec reads and writes packet-frames for use with `tokio_util::codec`.
+///
+/// Decoded packet-frames are returned as [BytesFrameParser]s that share the read
+/// buffer.  Frames are encoded from their bytes (e.g., as written by a
+/// [crate::FrameBuilder]), with the codec adding the size prefix.
+///
+/// Packet-frames larger than the maximum frame length (8 MiB by default) are
+/// rejected, so that a corrupt size prefix can not cause unbounded buffering.
+///
+/// ```
+/// # use bytes::BytesMut;
+/// # use tokio_util::codec::{Decoder, Encoder};
+/// # use yatlv::{FrameBuilder, FrameBuilderLike, YatlvCodec};
+/// # fn main() -> std::io::Result<()> {
+/// let mut frame_data = Vec::new();
+/// FrameBuilder::new(&mut frame_data).add_u8(12, 9);
+///
+/// let mut codec = YatlvCodec::new();
+/// let mut buf = BytesMut::new();
+/// codec.encode(&frame_data[..], &mut buf)?;
+///
+/// let frame = codec.decode(&mut buf)?.unwrap();
+/// assert_eq!(Some(9), frame.parser().get_u8(12).unwrap());
+/// assert!(codec.decode(&mut buf)?.is_none());
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct YatlvCodec {
+    max_frame_len: usize,
+}
+
+impl YatlvCodec {
+    pub fn new() -> YatlvCodec {
+        YatlvCodec::with_max_frame_len(8 * 1024 * 1024)
+    }
+
+    /// Create a codec that rejects frames longer than `max_frame_len` bytes.
+    pub fn with_max_frame_len(max_frame_len: usize) -> YatlvCodec {
+        YatlvCodec { max_frame_len }
+    }
+
+    /// The largest frame (excluding its size prefix) this codec will read or write.
+    pub fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+
+    fn check_frame_len(&self, frame_len: usize) -> Result<()> {
+        if frame_len > self.max_frame_len {
+            Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("frame length {} is too large", frame_len),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Default for YatlvCodec {
+    fn default() -> Self {
+        YatlvCodec::new()
+    }
+}
+
+impl Decoder for YatlvCodec {
+    type Item = BytesFrameParser;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<BytesFrameParser>> {
+        if src.len() < SIZE_BYTES {
+            return Ok(None);
+        }
+        let frame_len = u32::from_be_bytes(src[..SIZE_BYTES].try_into().unwrap()) as usize;
+        self.check_frame_len(frame_len)?;
+        if src.len() < SIZE_BYTES + frame_len {
+            src.reserve(SIZE_BYTES + frame_len - src.len());
+            return Ok(None);
+        }
+        src.advance(SIZE_BYTES);
+        let frame_data = src.split_to(frame_len).freeze();
+        BytesFrameParser::new(frame_data)
+            .map(Some)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+}
+
+impl<'a> Encoder<&'a [u8]> for YatlvCodec {
+    type Error = Error;
+
+    fn encode(&mut self, frame_data: &'a [u8], dst: &mut BytesMut) -> Result<()> {
+        self.check_frame_len(frame_data.len())?;
+        if frame_encoded_len(frame_data) != Ok(frame_data.len()) {
+            return Err(Error::new(ErrorKind::InvalidInput, "not a single frame"));
+        }
+        dst.reserve(SIZE_BYTES + frame_data.len());
+        dst.put_u32(frame_data.len() as u32);
+        dst.put_slice(frame_data);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FrameBuilderLike, PacketFrameBuilder};
+
+    fn packet_frames() -> Vec<u8> {
+        let mut data = Vec::new();
+        for i in 0..2 {
+            let mut bld = PacketFrameBuilder::new(&mut data);
+            bld.add_u8(1, i);
+        }
+        data
+    }
+
+    #[test]
+    fn can_decode_packet_frames_that_arrive_in_pieces() {
+        let data = packet_frames();
+        let mut codec = YatlvCodec::new();
+        let mut buf = BytesMut::new();
+        let mut frames = Vec::new();
+        // one byte at a time, so the size prefix also arrives in pieces
+        for b in data {
+            buf.put_u8(b);
+            if let Some(frame) = codec.decode(&mut buf).unwrap() {
+                frames.push(frame.parser().get_u8(1).unwrap());
+            }
+        }
+        assert_eq!(vec![Some(0), Some(1)], frames);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn can_encode_packet_frames() {
+        let data = packet_frames();
+        let mut codec = YatlvCodec::new();
+        let mut buf = BytesMut::new();
+        codec.encode(&data[4..16], &mut buf).unwrap();
+        codec.encode(&data[20..], &mut buf).unwrap();
+        assert_eq!(&data[..], &buf[..]);
+
+        let err = codec.encode(&data[4..15], &mut buf).unwrap_err();
+        assert_eq!(ErrorKind::InvalidInput, err.kind());
+    }
+
+    #[test]
+    fn can_not_decode_large_or_invalid_frames() {
+        let mut codec = YatlvCodec::with_max_frame_len(12);
+        let mut buf = BytesMut::from(&packet_frames()[..]);
+        assert!(codec.decode(&mut buf).unwrap().is_some());
+
+        let mut buf = BytesMut::from(&[0, 0, 0, 13][..]);
+        let err = codec.decode(&mut buf).unwrap_err();
+        assert_eq!(ErrorKind::InvalidData, err.kind());
+
+        let mut buf = BytesMut::from(&[0, 0, 0, 3, 1, 0, 0][..]);
+        let err = codec.decode(&mut buf).unwrap_err();
+        assert_eq!(ErrorKind::InvalidData, err.kind());
+    }
+}
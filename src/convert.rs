@@ -0,0 +1,477 @@
+use alloc::borrow::{Cow, ToOwned};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "uuid")]
+use crate::decode_uuid;
+use crate::{
+    decode_array, decode_bool, decode_char, decode_f32, decode_f64, decode_i128, decode_i16,
+    decode_i32, decode_i64, decode_i8, decode_ip, decode_socket_addr, decode_str, decode_string,
+    decode_u128, decode_u16, decode_u32, decode_u64, decode_u8, Endianness, FrameBuilderLike,
+    FrameParser, Result,
+};
+
+/// ToFrame is implemented by types that can be written as the fields of a frame.
+///
+/// With the `derive` feature, it can be derived for structs with named fields
+/// where each field has a `#[yatlv(tag = ...)]` attribute:
+///
+/// * numbers, `bool`, `char`, `String`, `Vec<u8>` and `uuid::Uuid` are written
+///   using the matching `add_*` method.
+/// * other types are written as child frames, so must also implement `ToFrame`.
+/// * `Option<T>` fields are only written when they are `Some`.
+/// * `Vec<T>` fields (other than `Vec<u8>`) are written as one field per element.
+///
+/// ```
+/// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser, FromFrame, Result, ToFrame};
+///
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// impl ToFrame for Point {
+///     fn to_frame<B: FrameBuilderLike>(&self, bld: &mut B) {
+///         bld.add_u32(1, self.x);
+///         bld.add_u32(2, self.y);
+///     }
+/// }
+///
+/// impl FromFrame for Point {
+///     fn from_frame(parser: &FrameParser<'_>) -> Result<Self> {
+///         Ok(Point {
+///             x: parser.get_u32_required(1)?,
+///             y: parser.get_u32_required(2)?,
+///         })
+///     }
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let mut frame_data = Vec::new();
+/// Point { x: 3, y: 4 }.to_frame(&mut FrameBuilder::new(&mut frame_data));
+///
+/// let point = Point::from_frame(&FrameParser::new(&frame_data)?)?;
+/// assert_eq!((3, 4), (point.x, point.y));
+/// # Ok(()) }
+/// ```
+pub trait ToFrame {
+    /// Add the fields of this value to a frame.
+    fn to_frame<B: FrameBuilderLike>(&self, bld: &mut B);
+}
+
+/// FromFrame is implemented by types that can be read from the fields of a frame.
+///
+/// With the `derive` feature, it can be derived using the same attributes as
+/// [ToFrame].  Missing fields are reported as [crate::Error::MissingField],
+/// unless the field is an `Option` or a `Vec`.
+pub trait FromFrame: Sized {
+    /// Read a value from the fields of a frame.
+    fn from_frame(parser: &FrameParser<'_>) -> Result<Self>;
+}
+
+/// ToField is implemented by types that can be written as a single field-value.
+///
+/// It is used by [FrameBuilderLike::add], so that generic code can add fields without
+/// naming the `add_*` method for each type.  Values are encoded in the same way as
+/// their `add_*` methods, and can be read back with [FromField].
+///
+/// ```
+/// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser, Result, ToField};
+///
+/// fn add_all<B: FrameBuilderLike, T: ToField>(bld: &mut B, tag: u16, values: Vec<T>) {
+///     for value in values {
+///         bld.add(tag, value);
+///     }
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let mut frame_data = Vec::new();
+/// add_all(&mut FrameBuilder::new(&mut frame_data), 1, vec![1u16, 2]);
+///
+/// let parser = FrameParser::new(&frame_data)?;
+/// assert_eq!(vec![1, 2], parser.get_u16s(1).collect::<Result<Vec<_>>>()?);
+/// assert_eq!(vec![0, 7], 7u16.to_field_bytes());
+/// # Ok(()) }
+/// ```
+pub trait ToField {
+    /// Encode this value as a field-value, with numbers encoded using `endianness`,
+    /// and pass it to `f`.
+    ///
+    /// Types that are not numbers ignore `endianness`.
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, endianness: Endianness, f: F) -> R;
+
+    /// Encode this value as a field-value, with numbers encoded as big-endian.
+    fn to_field_bytes(&self) -> Vec<u8> {
+        self.encode_field(Endianness::Big, |value| value.to_vec())
+    }
+}
+
+impl<T: ToField + ?Sized> ToField for &T {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, endianness: Endianness, f: F) -> R {
+        (**self).encode_field(endianness, f)
+    }
+}
+
+/// A `Cow` is written in the same way as the value it holds, whether borrowed or owned.
+impl<T: ToField + ToOwned + ?Sized> ToField for Cow<'_, T> {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, endianness: Endianness, f: F) -> R {
+        (**self).encode_field(endianness, f)
+    }
+}
+
+macro_rules! number_to_field {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ToField for $ty {
+                fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, endianness: Endianness, f: F) -> R {
+                    f(&endianness.order(self.to_be_bytes()))
+                }
+            }
+        )*
+    };
+}
+
+number_to_field!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl ToField for bool {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, _: Endianness, f: F) -> R {
+        f(&[if *self { 0xFF } else { 0x00 }])
+    }
+}
+
+impl ToField for char {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, _: Endianness, f: F) -> R {
+        f(self.encode_utf8(&mut [0; 4]).as_bytes())
+    }
+}
+
+impl ToField for str {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, _: Endianness, f: F) -> R {
+        f(self.as_bytes())
+    }
+}
+
+impl ToField for String {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, _: Endianness, f: F) -> R {
+        f(self.as_bytes())
+    }
+}
+
+impl ToField for [u8] {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, _: Endianness, f: F) -> R {
+        f(self)
+    }
+}
+
+impl ToField for Vec<u8> {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, _: Endianness, f: F) -> R {
+        f(self)
+    }
+}
+
+impl<const N: usize> ToField for [u8; N] {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, _: Endianness, f: F) -> R {
+        f(self)
+    }
+}
+
+impl ToField for core::net::Ipv4Addr {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, _: Endianness, f: F) -> R {
+        f(&self.octets())
+    }
+}
+
+impl ToField for core::net::Ipv6Addr {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, _: Endianness, f: F) -> R {
+        f(&self.octets())
+    }
+}
+
+impl ToField for core::net::IpAddr {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, endianness: Endianness, f: F) -> R {
+        match self {
+            core::net::IpAddr::V4(ip) => ip.encode_field(endianness, f),
+            core::net::IpAddr::V6(ip) => ip.encode_field(endianness, f),
+        }
+    }
+}
+
+/// The address is followed by the port, which is always big-endian.
+impl ToField for core::net::SocketAddr {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, _: Endianness, f: F) -> R {
+        let mut bytes = [0; 18];
+        let len = match self.ip() {
+            core::net::IpAddr::V4(ip) => {
+                bytes[..4].copy_from_slice(&ip.octets());
+                4
+            }
+            core::net::IpAddr::V6(ip) => {
+                bytes[..16].copy_from_slice(&ip.octets());
+                16
+            }
+        };
+        bytes[len..len + 2].copy_from_slice(&self.port().to_be_bytes());
+        f(&bytes[..len + 2])
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl ToField for uuid::Uuid {
+    fn encode_field<R, F: FnOnce(&[u8]) -> R>(&self, _: Endianness, f: F) -> R {
+        f(self.as_bytes())
+    }
+}
+
+/// FromField is implemented by types that can be read from a single field-value.
+///
+/// It is used by [FrameParser::get], so that generic code can read fields without
+/// naming the `get_*` method for each type.  Numbers accept the same field-lengths
+/// as their `get_*` methods.
+///
+/// ```
+/// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser, FromField, Result};
+///
+/// fn get_or_default<'a, T: FromField<'a> + Default>(
+///     parser: &FrameParser<'a>,
+///     tag: u16,
+/// ) -> Result<T> {
+///     Ok(parser.get(tag)?.unwrap_or_default())
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let mut frame_data = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new(&mut frame_data);
+///     bld.add_u16(1, 7);
+///     bld.add_str(2, "hello");
+/// }
+///
+/// let parser = FrameParser::new(&frame_data)?;
+/// let x: u32 = get_or_default(&parser, 1)?;
+/// let s: &str = get_or_default(&parser, 2)?;
+/// let b: bool = get_or_default(&parser, 3)?;
+/// assert_eq!((7, "hello", false), (x, s, b));
+/// # Ok(()) }
+/// ```
+pub trait FromField<'a>: Sized {
+    /// Decode a field-value, with numbers encoded as big-endian.
+    fn from_field(value: &'a [u8]) -> Result<Self>;
+
+    /// Decode a field-value, with numbers encoded using `endianness`.
+    ///
+    /// Types that are not numbers ignore `endianness`.
+    fn from_field_with_endianness(value: &'a [u8], endianness: Endianness) -> Result<Self> {
+        let _ = endianness;
+        Self::from_field(value)
+    }
+}
+
+macro_rules! number_from_field {
+    ($($ty:ty => $decoder:ident),* $(,)?) => {
+        $(
+            impl<'a> FromField<'a> for $ty {
+                fn from_field(value: &'a [u8]) -> Result<Self> {
+                    $decoder(value)
+                }
+
+                fn from_field_with_endianness(
+                    value: &'a [u8],
+                    endianness: Endianness,
+                ) -> Result<Self> {
+                    endianness.decode(value, $decoder)
+                }
+            }
+        )*
+    };
+}
+
+number_from_field! {
+    u8 => decode_u8,
+    u16 => decode_u16,
+    u32 => decode_u32,
+    u64 => decode_u64,
+    u128 => decode_u128,
+    i8 => decode_i8,
+    i16 => decode_i16,
+    i32 => decode_i32,
+    i64 => decode_i64,
+    i128 => decode_i128,
+    f32 => decode_f32,
+    f64 => decode_f64,
+}
+
+impl<'a> FromField<'a> for bool {
+    fn from_field(value: &'a [u8]) -> Result<Self> {
+        decode_bool(value)
+    }
+}
+
+impl<'a> FromField<'a> for char {
+    fn from_field(value: &'a [u8]) -> Result<Self> {
+        decode_char(value)
+    }
+}
+
+impl<'a> FromField<'a> for &'a str {
+    fn from_field(value: &'a [u8]) -> Result<Self> {
+        decode_str(value)
+    }
+}
+
+impl<'a> FromField<'a> for String {
+    fn from_field(value: &'a [u8]) -> Result<Self> {
+        decode_string(value)
+    }
+}
+
+/// Always borrowed; use [FrameParser::get_str_lossy] to replace invalid UTF-8 instead.
+impl<'a> FromField<'a> for Cow<'a, str> {
+    fn from_field(value: &'a [u8]) -> Result<Self> {
+        decode_str(value).map(Cow::Borrowed)
+    }
+}
+
+impl<'a> FromField<'a> for &'a [u8] {
+    fn from_field(value: &'a [u8]) -> Result<Self> {
+        Ok(value)
+    }
+}
+
+impl<'a, const N: usize> FromField<'a> for [u8; N] {
+    fn from_field(value: &'a [u8]) -> Result<Self> {
+        decode_array(value)
+    }
+}
+
+/// Always borrowed.
+impl<'a> FromField<'a> for Cow<'a, [u8]> {
+    fn from_field(value: &'a [u8]) -> Result<Self> {
+        Ok(Cow::Borrowed(value))
+    }
+}
+
+impl<'a> FromField<'a> for Vec<u8> {
+    fn from_field(value: &'a [u8]) -> Result<Self> {
+        Ok(value.to_vec())
+    }
+}
+
+impl<'a> FromField<'a> for core::net::IpAddr {
+    fn from_field(value: &'a [u8]) -> Result<Self> {
+        decode_ip(value)
+    }
+}
+
+impl<'a> FromField<'a> for core::net::SocketAddr {
+    fn from_field(value: &'a [u8]) -> Result<Self> {
+        decode_socket_addr(value)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl<'a> FromField<'a> for uuid::Uuid {
+    fn from_field(value: &'a [u8]) -> Result<Self> {
+        decode_uuid(value)
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use crate::{Error, FrameBuilder, FromFrame, ToFrame};
+
+    const NAME: u16 = 1;
+
+    #[derive(Debug, PartialEq, ToFrame, FromFrame)]
+    struct LineItem {
+        #[yatlv(tag = NAME)]
+        name: String,
+        #[yatlv(tag = 2)]
+        quantity: u32,
+        #[yatlv(tag = 3)]
+        price: f64,
+    }
+
+    #[derive(Debug, PartialEq, ToFrame, FromFrame)]
+    struct Order {
+        #[yatlv(tag = 1)]
+        id: u64,
+        #[yatlv(tag = 2)]
+        express: bool,
+        #[yatlv(tag = 3)]
+        note: Option<String>,
+        #[yatlv(tag = 4)]
+        signature: Vec<u8>,
+        #[yatlv(tag = 5)]
+        items: Vec<LineItem>,
+        #[yatlv(tag = 6)]
+        gift: Option<LineItem>,
+        #[yatlv(tag = 7)]
+        discounts: Vec<i16>,
+        #[yatlv(tag = 9)]
+        currency: char,
+        #[cfg(feature = "uuid")]
+        #[yatlv(tag = 8)]
+        customer: uuid::Uuid,
+    }
+
+    fn order() -> Order {
+        Order {
+            id: 12,
+            express: true,
+            note: None,
+            signature: vec![1, 2, 3],
+            items: vec![
+                LineItem {
+                    name: "cog".to_owned(),
+                    quantity: 3,
+                    price: 1.5,
+                },
+                LineItem {
+                    name: "sprocket".to_owned(),
+                    quantity: 1,
+                    price: 10.0,
+                },
+            ],
+            gift: Some(LineItem {
+                name: "card".to_owned(),
+                quantity: 1,
+                price: 0.0,
+            }),
+            discounts: vec![-5, 10],
+            currency: '€',
+            #[cfg(feature = "uuid")]
+            customer: uuid::Uuid::from_u128(99),
+        }
+    }
+
+    #[test]
+    fn can_round_trip_a_derived_struct() {
+        let mut data = Vec::new();
+        order().to_frame(&mut FrameBuilder::new(&mut data));
+
+        let parser = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(12), parser.get_u64(1).unwrap());
+        assert_eq!(None, parser.get_data(3));
+        assert_eq!(2, parser.get_frames(5).count());
+        let item = parser.get_frame_required(5).unwrap();
+        assert_eq!(Some("cog"), item.get_str(NAME).unwrap());
+
+        assert_eq!(order(), Order::from_frame(&parser).unwrap());
+    }
+
+    #[test]
+    fn can_not_read_a_derived_struct_with_a_missing_field() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_str(NAME, "cog");
+            bld.add_u32(2, 3);
+        }
+        let parser = FrameParser::new(&data).unwrap();
+        assert_eq!(
+            Some(Error::MissingField(3)),
+            LineItem::from_frame(&parser).err()
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use crate::{encode_hex, FrameParser};
+
+/// Number of bytes of each field-value shown before it is truncated.
+const PREVIEW_BYTES: usize = 16;
+
+/// How deeply child frames are shown; deeper field-values are shown as hex.
+const MAX_DUMP_DEPTH: usize = 16;
+
+/// Show the fields of the frame as a tree.
+///
+/// A field-value that can be parsed as a frame is shown as a child frame, otherwise
+/// its length and (the start of) its bytes are shown in hex.
+impl<'a> fmt::Debug for FrameParser<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_frame(f, self, 0)
+    }
+}
+
+/// Parse and describe a frame, for troubleshooting.
+///
+/// This gives the same tree as the `Debug` implementation of [FrameParser], or
+/// a description of why the frame could not be parsed.
+///
+/// ```
+/// # use yatlv::{dump_frame, FrameBuilder, FrameBuilderLike};
+/// let mut data = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new(&mut data);
+///     bld.add_str(1, "hello");
+///     bld.add_frame(2).add_u16(3, 4);
+/// }
+/// assert_eq!(
+///     "frame (Format1, field-count 2)\n  \
+///        tag 1, len 5: 68 65 6c 6c 6f\n  \
+///        tag 2, len 13: frame (Format1, field-count 1)\n    \
+///          tag 3, len 2: 00 04",
+///     dump_frame(&data)
+/// );
+/// assert_eq!(
+///     "invalid frame (incomplete field-count): 01 00",
+///     dump_frame(&data[..2])
+/// );
+/// ```
+pub fn dump_frame(data: &[u8]) -> String {
+    let mut out = String::new();
+    let _ = match FrameParser::new(data) {
+        Ok(parser) => write_frame(&mut out, &parser, 0),
+        Err(e) => write!(out, "invalid frame ({}):", e).and_then(|_| write_hex(&mut out, data)),
+    };
+    out
+}
+
+fn write_frame<W: Write>(out: &mut W, parser: &FrameParser<'_>, depth: usize) -> fmt::Result {
+    write!(
+        out,
+        "frame ({:?}, field-count {})",
+        parser.format(),
+        parser.len()
+    )?;
+    let indent = 2 * (depth + 1);
+    for (tag, value) in parser.fields() {
+        write!(
+            out,
+            "\n{:indent$}tag {}, len {}:",
+            "",
+            tag,
+            value.len(),
+            indent = indent
+        )?;
+        let child = if depth < MAX_DUMP_DEPTH {
+            parser.parse_child(value).ok()
+        } else {
+            None
+        };
+        match child {
+            Some(child) => {
+                out.write_char(' ')?;
+                write_frame(out, &child, depth + 1)?;
+            }
+            None => write_hex(out, value)?,
+        }
+    }
+    Ok(())
+}
+
+fn write_hex<W: Write>(out: &mut W, value: &[u8]) -> fmt::Result {
+    for b in value.iter().take(PREVIEW_BYTES) {
+        write!(out, " {:02x}", b)?;
+    }
+    if value.len() > PREVIEW_BYTES {
+        out.write_str(" ...")?;
+    }
+    Ok(())
+}
+
+/// How [FrameParser::to_debug_map] renders field-values as text.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ValueEncoding {
+    /// Lowercase hex, two digits per byte.
+    Hex,
+    /// Standard base64 (RFC 4648) with padding.
+    Base64,
+}
+
+/// A field-value rendered by [FrameParser::to_debug_map].
+///
+/// With the `serde` feature this serializes as either a string or a nested map.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum DebugValue {
+    /// A field-value rendered using the chosen [ValueEncoding].
+    Text(String),
+    /// A field-value that was parsed as a child frame.
+    Frame(DebugMap),
+}
+
+/// The field-values of a frame by tag, in the order they appear in the frame.
+pub type DebugMap = BTreeMap<u16, Vec<DebugValue>>;
+
+impl<'a> FrameParser<'a> {
+    /// Render the fields of the frame as text, for logs that can not carry raw bytes.
+    ///
+    /// As with the `Debug` implementation, a field-value that can be parsed as a frame
+    /// is shown as a child frame (a nested map); other field-values are rendered using
+    /// `encoding`.  Each tag maps to all of its field-values, so repeated fields are kept.
+    ///
+    /// ```
+    /// # use yatlv::{DebugValue, FrameBuilder, FrameBuilderLike, FrameParser, ValueEncoding};
+    /// let mut data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_str(1, "hello");
+    ///     bld.add_frame(2).add_u16(3, 4);
+    /// }
+    /// let map = FrameParser::new(&data).unwrap().to_debug_map(ValueEncoding::Base64);
+    /// assert_eq!(vec![DebugValue::Text("aGVsbG8=".into())], map[&1]);
+    /// match &map[&2][0] {
+    ///     DebugValue::Frame(child) => {
+    ///         assert_eq!(vec![DebugValue::Text("AAQ=".into())], child[&3]);
+    ///     }
+    ///     other => panic!("expected a child frame, found {:?}", other),
+    /// }
+    /// ```
+    pub fn to_debug_map(&self, encoding: ValueEncoding) -> DebugMap {
+        debug_map(self, encoding, 0)
+    }
+}
+
+fn debug_map(parser: &FrameParser<'_>, encoding: ValueEncoding, depth: usize) -> DebugMap {
+    let mut map = DebugMap::new();
+    for (tag, value) in parser.fields() {
+        let child = if depth < MAX_DUMP_DEPTH {
+            parser.parse_child(value).ok()
+        } else {
+            None
+        };
+        let value = match child {
+            Some(child) => DebugValue::Frame(debug_map(&child, encoding, depth + 1)),
+            None => DebugValue::Text(encode_text(value, encoding)),
+        };
+        map.entry(tag).or_default().push(value);
+    }
+    map
+}
+
+fn encode_text(value: &[u8], encoding: ValueEncoding) -> String {
+    const BASE64_DIGITS: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    match encoding {
+        ValueEncoding::Hex => encode_hex(value),
+        ValueEncoding::Base64 => {
+            let mut text = String::with_capacity(value.len().div_ceil(3) * 4);
+            for chunk in value.chunks(3) {
+                let bits = chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0u32, |acc, (i, b)| acc | (*b as u32) << (16 - 8 * i));
+                for i in 0..4 {
+                    if i <= chunk.len() {
+                        let digit = (bits >> (18 - 6 * i)) & 0x3F;
+                        text.push(BASE64_DIGITS[digit as usize] as char);
+                    } else {
+                        text.push('=');
+                    }
+                }
+            }
+            text
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FrameBuilder, FrameBuilderLike};
+    use alloc::format;
+
+    #[test]
+    fn long_values_are_truncated() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new_checked(&mut data);
+            bld.add_data(1, &[0xAB; 17]);
+            bld.add_data(2, &[]);
+        }
+        let parser = FrameParser::new(&data).unwrap();
+        assert_eq!(
+            "frame (Format2, field-count 2)\n  \
+               tag 1, len 17: ab ab ab ab ab ab ab ab ab ab ab ab ab ab ab ab ...\n  \
+               tag 2, len 0:",
+            format!("{:?}", parser)
+        );
+    }
+
+    #[test]
+    fn deeply_nested_frames_are_shown_as_hex() {
+        fn nest<B: FrameBuilderLike>(bld: &mut B, levels: usize) {
+            if levels > 0 {
+                nest(&mut bld.add_frame(1), levels - 1);
+            }
+        }
+        let mut data = Vec::new();
+        nest(&mut FrameBuilder::new(&mut data), MAX_DUMP_DEPTH + 1);
+        let dump = dump_frame(&data);
+        assert_eq!(MAX_DUMP_DEPTH + 1, dump.matches("frame").count());
+        assert!(dump.ends_with(": 01 00 00 00 00"));
+    }
+
+    #[test]
+    fn base64_matches_the_rfc_4648_test_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (plain, encoded) in &vectors {
+            assert_eq!(
+                *encoded,
+                encode_text(plain.as_bytes(), ValueEncoding::Base64)
+            );
+        }
+        assert_eq!("00ff7f", encode_text(&[0, 255, 127], ValueEncoding::Hex));
+    }
+
+    #[test]
+    fn debug_maps_keep_repeated_fields() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(2, 1);
+            bld.add_u8(1, 2);
+            bld.add_u8(2, 3);
+        }
+        let map = FrameParser::new(&data)
+            .unwrap()
+            .to_debug_map(ValueEncoding::Hex);
+        let text = |s: &str| DebugValue::Text(s.into());
+        assert_eq!(vec![text("02")], map[&1]);
+        assert_eq!(vec![text("01"), text("03")], map[&2]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn debug_maps_serialize_as_nested_maps() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(1, 1);
+            bld.add_frame(2).add_u8(3, 3);
+        }
+        let map = FrameParser::new(&data)
+            .unwrap()
+            .to_debug_map(ValueEncoding::Hex);
+        assert_eq!(
+            serde_json::json!({"1": ["01"], "2": [{"3": ["03"]}]}),
+            serde_json::to_value(&map).unwrap()
+        );
+    }
+}
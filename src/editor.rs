@@ -0,0 +1,262 @@
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use crate::{
+    crc32, read_field_tag_and_length, Error, FrameFormat, FrameParser, Result, FIELD_HEADER_BYTES,
+    FRAME_HEADER_BYTES, SIZE_BYTES,
+};
+
+/// FrameEditor changes the fields of an encoded frame in place.
+///
+/// Only the fields of the frame itself can be changed; child frames are treated as
+/// field-values.  The field-count, field-lengths and (for frame-format `0x02`) the
+/// checksum are updated after each change, so the buffer always holds a valid frame.
+///
+/// Changing the length of a field-value moves the fields that follow it, so each
+/// change costs time proportional to the size of the frame, but this is still much
+/// cheaper than parsing and building the whole frame again.
+///
+/// ```
+/// # use yatlv::{FrameBuilder, FrameBuilderLike, FrameEditor, Result};
+/// # fn main() -> Result<()> {
+/// const HOPS: u16 = 1;
+/// const ROUTE: u16 = 2;
+///
+/// let mut frame_data = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new(&mut frame_data);
+///     bld.add_u8(HOPS, 1);
+///     bld.add_str(ROUTE, "a");
+/// }
+///
+/// let mut editor = FrameEditor::new(frame_data)?;
+/// let hops = editor.parser().get_u8_required(HOPS)?;
+/// editor.set_data(HOPS, &[hops + 1]);
+/// editor.set_data(ROUTE, b"a,b");
+///
+/// let parser = editor.parser();
+/// assert_eq!(Some(2), parser.get_u8(HOPS)?);
+/// assert_eq!(Some("a,b"), parser.get_str(ROUTE)?);
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameEditor {
+    data: Vec<u8>,
+}
+
+/// Where a field is held in the buffer.
+struct FieldPosition {
+    tag: u16,
+    /// Offset of the field-tag.
+    start: usize,
+    /// Offset after the field-value.
+    end: usize,
+}
+
+impl FrameEditor {
+    /// Take ownership of `frame_data`, checking that it contains a valid frame.
+    ///
+    /// Frames with 64 bit field-lengths (frame-format `0x05`) can not be edited and
+    /// are rejected with [Error::InvalidFrameFormat].
+    pub fn new(frame_data: Vec<u8>) -> Result<FrameEditor> {
+        if FrameParser::new(&frame_data)?.format() == FrameFormat::Format5 {
+            return Err(Error::InvalidFrameFormat(5));
+        }
+        Ok(FrameEditor { data: frame_data })
+    }
+
+    /// Create a [FrameParser] that borrows from the edited frame.
+    pub fn parser(&self) -> FrameParser<'_> {
+        FrameParser::new(&self.data).expect("frame is kept valid while editing")
+    }
+
+    /// Replace the value of the first field with `tag`, or add a field with
+    /// `tag` to the end of the frame if there is none.
+    ///
+    /// ```
+    /// # use yatlv::{FrameBuilder, FrameBuilderLike, FrameEditor, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut frame_data = Vec::new();
+    /// FrameBuilder::new(&mut frame_data).add_data(1, &[1]);
+    ///
+    /// let mut editor = FrameEditor::new(frame_data)?;
+    /// editor.set_data(1, &[2, 2]);
+    /// editor.set_data(3, &[3]);
+    ///
+    /// let mut expected = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut expected);
+    ///     bld.add_data(1, &[2, 2]);
+    ///     bld.add_data(3, &[3]);
+    /// }
+    /// assert_eq!(&expected, editor.as_bytes());
+    /// # Ok(()) }
+    /// ```
+    pub fn set_data(&mut self, tag: u16, value: &[u8]) {
+        let positions = self.field_positions();
+        match positions.iter().find(|p| p.tag == tag) {
+            Some(field) => {
+                let value_start = field.start + FIELD_HEADER_BYTES;
+                self.data[field.start + 2..value_start]
+                    .copy_from_slice(&(value.len() as u32).to_be_bytes());
+                self.data
+                    .splice(value_start..field.end, value.iter().copied());
+            }
+            None => {
+                let fields_end = positions.last().map_or(FRAME_HEADER_BYTES, |p| p.end);
+                let mut field = Vec::with_capacity(FIELD_HEADER_BYTES + value.len());
+                field.extend_from_slice(&tag.to_be_bytes());
+                field.extend_from_slice(&(value.len() as u32).to_be_bytes());
+                field.extend_from_slice(value);
+                self.data.splice(fields_end..fields_end, field);
+                self.set_field_count(positions.len() + 1);
+            }
+        }
+        self.update_checksum();
+    }
+
+    /// Remove every field with `tag`, returning the number of fields removed.
+    ///
+    /// ```
+    /// # use yatlv::{FrameBuilder, FrameBuilderLike, FrameEditor, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut frame_data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut frame_data);
+    ///     bld.add_u8(1, 1);
+    ///     bld.add_u8(2, 2);
+    ///     bld.add_u8(1, 3);
+    /// }
+    ///
+    /// let mut editor = FrameEditor::new(frame_data)?;
+    /// assert_eq!(2, editor.remove(1));
+    /// assert_eq!(0, editor.remove(1));
+    /// assert_eq!(vec![2], editor.parser().tags().collect::<Vec<_>>());
+    /// # Ok(()) }
+    /// ```
+    pub fn remove(&mut self, tag: u16) -> usize {
+        let positions = self.field_positions();
+        let mut removed = 0;
+        for field in positions.iter().rev().filter(|p| p.tag == tag) {
+            self.data.drain(field.start..field.end);
+            removed += 1;
+        }
+        if removed > 0 {
+            self.set_field_count(positions.len() - removed);
+            self.update_checksum();
+        }
+        removed
+    }
+
+    /// Access the edited frame.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Release the edited frame.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+
+    fn field_positions(&self) -> Vec<FieldPosition> {
+        let mut positions = Vec::new();
+        let mut start = FRAME_HEADER_BYTES;
+        for _ in 0..self.field_count() {
+            let (tag, length, _) = read_field_tag_and_length(&self.data[start..])
+                .expect("frame is kept valid while editing");
+            let end = start + FIELD_HEADER_BYTES + length;
+            positions.push(FieldPosition { tag, start, end });
+            start = end;
+        }
+        positions
+    }
+
+    fn field_count(&self) -> u32 {
+        u32::from_be_bytes(self.data[1..1 + SIZE_BYTES].try_into().unwrap())
+    }
+
+    fn set_field_count(&mut self, field_count: usize) {
+        self.data[1..1 + SIZE_BYTES].copy_from_slice(&(field_count as u32).to_be_bytes());
+    }
+
+    /// Recalculate the checksum of a frame with frame-format `0x02`.
+    fn update_checksum(&mut self) {
+        if self.data[0] == 2 {
+            let checksum_pos = self.data.len() - SIZE_BYTES;
+            let checksum = crc32(&self.data[..checksum_pos]);
+            self.data[checksum_pos..].copy_from_slice(&checksum.to_be_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FrameBuilder, FrameBuilderLike};
+
+    fn frame(checked: bool) -> Vec<u8> {
+        let mut data = Vec::new();
+        {
+            let mut bld = if checked {
+                FrameBuilder::new_checked(&mut data)
+            } else {
+                FrameBuilder::new(&mut data)
+            };
+            bld.add_str(1, "one");
+            bld.add_frame(2).add_str(3, "child");
+            bld.add_str(1, "another one");
+        }
+        data
+    }
+
+    #[test]
+    fn large_frames_can_not_be_edited() {
+        let mut data = Vec::new();
+        FrameBuilder::new_large(&mut data).add_u8(1, 1);
+        assert_eq!(
+            Some(Error::InvalidFrameFormat(5)),
+            FrameEditor::new(data).err()
+        );
+    }
+
+    #[test]
+    fn edits_keep_the_frame_valid() {
+        for &checked in &[false, true] {
+            let mut editor = FrameEditor::new(frame(checked)).unwrap();
+            editor.set_data(1, b"1");
+            editor.set_data(4, b"four");
+            editor.set_data(1, b"uno");
+            assert_eq!(1, editor.remove(2));
+
+            let data = editor.into_bytes();
+            let parser = FrameParser::new(&data).unwrap();
+            assert_eq!(checked, parser.format() == FrameFormat::Format2);
+            assert_eq!(
+                vec![(1, &b"uno"[..]), (1, b"another one"), (4, b"four")],
+                parser.fields().collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn child_frames_are_moved_intact() {
+        let mut editor = FrameEditor::new(frame(false)).unwrap();
+        editor.set_data(1, &[]);
+        let parser = editor.parser();
+        let child = parser.get_frame_required(2).unwrap();
+        assert_eq!(Some("child"), child.get_str(3).unwrap());
+
+        assert_eq!(2, editor.remove(1));
+        assert_eq!(vec![2], editor.parser().tags().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn can_not_edit_an_invalid_frame() {
+        let mut data = frame(true);
+        data.pop();
+        assert_eq!(
+            Some(Error::IncompleteFrameChecksum),
+            FrameEditor::new(data).err()
+        );
+    }
+}
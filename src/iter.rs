@@ -0,0 +1,265 @@
+use alloc::vec::Vec;
+
+use crate::{read_packet_frame, Error, FrameParser, ParseOptions, Result};
+
+/// FrameIter reads a sequence of frames stored back-to-back in a buffer.
+///
+/// Each frame is parsed with [FrameParser::parse_prefix], so the iterator moves on
+/// by the encoded length of each frame.  Iteration stops cleanly at the end of the
+/// buffer; if the last frame is incomplete its error is returned instead, after
+/// which the iterator is finished.
+///
+/// ```
+/// # use yatlv::{FrameBuilder, FrameBuilderLike, FrameIter, Error};
+/// let mut data = Vec::new();
+/// FrameBuilder::new(&mut data).add_u8(12, 1);
+/// FrameBuilder::new(&mut data).add_u8(12, 2);
+///
+/// let values: Vec<_> = FrameIter::new(&data)
+///     .map(|frame| frame.unwrap().get_u8(12).unwrap())
+///     .collect();
+/// assert_eq!(vec![Some(1), Some(2)], values);
+///
+/// let mut frames = FrameIter::new(&data[..data.len() - 1]);
+/// assert!(frames.next().unwrap().is_ok());
+/// assert_eq!(Some(Err(Error::IncompleteFieldValue(1, 0))), frames.next().map(|f| f.map(|_| ())));
+/// assert!(frames.next().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameIter<'a> {
+    data: &'a [u8],
+    options: ParseOptions,
+}
+
+impl<'a> FrameIter<'a> {
+    /// Iterate over the frames in `data`, parsed with the default [ParseOptions].
+    pub fn new(data: &'a [u8]) -> FrameIter<'a> {
+        FrameIter::new_with_options(data, ParseOptions::default())
+    }
+
+    /// Iterate over the frames in `data`, parsing each with `options`.
+    pub fn new_with_options(data: &'a [u8], options: ParseOptions) -> FrameIter<'a> {
+        FrameIter { data, options }
+    }
+
+    /// The data that has not been read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+impl<'a> Iterator for FrameIter<'a> {
+    type Item = Result<FrameParser<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        match FrameParser::parse_prefix_with(self.data, self.options, 0) {
+            Ok((parser, tail)) => {
+                self.data = tail;
+                Some(Ok(parser))
+            }
+            Err(e) => {
+                self.data = &[];
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// PacketFrameIter reads a sequence of packet-frames stored back-to-back in a buffer.
+///
+/// Each packet-frame's frame-size is used to find the next one.  Iteration stops
+/// cleanly at the end of the buffer; an incomplete last packet-frame is reported
+/// as [Error::IncompleteFrameSize] or [Error::IncompletePacketFrame], after which the
+/// iterator is finished.  A frame that is not valid is reported without stopping,
+/// as the frame-size still gives the start of the next packet-frame.  A frame-size
+/// larger than [ParseOptions::max_frame_size] is reported as [Error::FrameTooLarge]
+/// and also finishes the iterator.
+///
+/// ```
+/// # use yatlv::{FrameBuilderLike, PacketFrameBuilder, PacketFrameIter, Error};
+/// let mut data = Vec::new();
+/// PacketFrameBuilder::new(&mut data).add_u8(12, 1);
+/// PacketFrameBuilder::new(&mut data).add_u8(12, 2);
+///
+/// let values: Vec<_> = PacketFrameIter::new(&data)
+///     .map(|frame| frame.unwrap().get_u8(12).unwrap())
+///     .collect();
+/// assert_eq!(vec![Some(1), Some(2)], values);
+///
+/// let mut frames = PacketFrameIter::new(&data[..data.len() - 1]);
+/// assert!(frames.next().unwrap().is_ok());
+/// assert_eq!(Some(Err(Error::IncompletePacketFrame(12, 11))), frames.next().map(|f| f.map(|_| ())));
+/// assert!(frames.next().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PacketFrameIter<'a> {
+    data: &'a [u8],
+    options: ParseOptions,
+}
+
+impl<'a> PacketFrameIter<'a> {
+    /// Iterate over the packet-frames in `data`, parsed with the default [ParseOptions].
+    pub fn new(data: &'a [u8]) -> PacketFrameIter<'a> {
+        PacketFrameIter::new_with_options(data, ParseOptions::default())
+    }
+
+    /// Iterate over the packet-frames in `data`, parsing each with `options`.
+    pub fn new_with_options(data: &'a [u8], options: ParseOptions) -> PacketFrameIter<'a> {
+        PacketFrameIter { data, options }
+    }
+
+    /// The data that has not been read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.data
+    }
+
+    fn next_frame_data(&mut self) -> Result<&'a [u8]> {
+        let (frame_data, tail) = read_packet_frame(self.data, self.options.max_frame_size)?;
+        self.data = tail;
+        Ok(frame_data)
+    }
+}
+
+impl<'a> Iterator for PacketFrameIter<'a> {
+    type Item = Result<FrameParser<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        match self.next_frame_data() {
+            Ok(frame_data) => Some(FrameParser::parse(frame_data, self.options, 0)),
+            Err(e) => {
+                self.data = &[];
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Split the complete packet-frames from the start of `data`, such as a socket read
+/// buffer, returning them with the number of bytes they take.
+///
+/// An incomplete packet-frame at the end of `data` is not an error; it is left for
+/// the caller to keep until more data arrives.  A complete packet-frame that does not
+/// hold a valid frame is an error.
+///
+/// ```
+/// # use yatlv::{split_packet_frames, FrameBuilderLike, PacketFrameBuilder};
+/// let mut data = Vec::new();
+/// PacketFrameBuilder::new(&mut data).add_u8(12, 1);
+/// PacketFrameBuilder::new(&mut data).add_u8(12, 2);
+/// let complete_len = data.len();
+/// PacketFrameBuilder::new(&mut data).add_u8(12, 3);
+///
+/// // only part of the last packet-frame has arrived
+/// let (frames, consumed) = split_packet_frames(&data[..data.len() - 2]).unwrap();
+/// assert_eq!(2, frames.len());
+/// assert_eq!(Some(2), frames[1].get_u8(12).unwrap());
+/// assert_eq!(complete_len, consumed);
+/// ```
+pub fn split_packet_frames(data: &[u8]) -> Result<(Vec<FrameParser<'_>>, usize)> {
+    let mut frames = Vec::new();
+    let mut iter = PacketFrameIter::new(data);
+    let mut consumed = 0;
+    while let Some(frame) = iter.next() {
+        match frame {
+            Ok(frame) => {
+                frames.push(frame);
+                consumed = data.len() - iter.remaining().len();
+            }
+            Err(Error::IncompleteFrameSize) | Err(Error::IncompletePacketFrame(_, _)) => break,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok((frames, consumed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FrameBuilder, FrameBuilderLike, PacketFrameBuilder};
+
+    #[test]
+    fn invalid_frames_stop_frame_iteration() {
+        let mut data = Vec::new();
+        FrameBuilder::new(&mut data).add_u8(1, 1);
+        data.push(9);
+        FrameBuilder::new(&mut data).add_u8(1, 2);
+
+        let mut frames = FrameIter::new(&data);
+        assert!(frames.next().unwrap().is_ok());
+        assert_eq!(
+            Some(Error::InvalidFrameFormat(9)),
+            frames.next().unwrap().err()
+        );
+        assert!(frames.next().is_none());
+        assert!(frames.remaining().is_empty());
+    }
+
+    #[test]
+    fn invalid_packet_frames_do_not_stop_packet_frame_iteration() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&[0, 0, 0, 1, 9]);
+        PacketFrameBuilder::new(&mut data).add_u8(1, 2);
+        data.extend_from_slice(&[0, 0]);
+
+        let mut frames = PacketFrameIter::new(&data);
+        assert_eq!(
+            Some(Error::InvalidFrameFormat(9)),
+            frames.next().unwrap().err()
+        );
+        let frame = frames.next().unwrap().unwrap();
+        assert_eq!(Some(2), frame.get_u8(1).unwrap());
+        assert_eq!(&[0, 0], frames.remaining());
+        assert_eq!(
+            Some(Error::IncompleteFrameSize),
+            frames.next().unwrap().err()
+        );
+        assert!(frames.next().is_none());
+    }
+
+    #[test]
+    fn empty_buffers_have_no_frames() {
+        assert!(FrameIter::new(&[]).next().is_none());
+        assert!(PacketFrameIter::new(&[]).next().is_none());
+    }
+
+    #[test]
+    fn packet_frames_larger_than_the_limit_are_rejected() {
+        let mut data = Vec::new();
+        PacketFrameBuilder::new(&mut data).add_u8(1, 2);
+        PacketFrameBuilder::new(&mut data);
+        let options = ParseOptions {
+            max_frame_size: 11,
+            ..ParseOptions::default()
+        };
+        let mut frames = PacketFrameIter::new_with_options(&data, options);
+        assert_eq!(
+            Some(Error::FrameTooLarge {
+                declared: 12,
+                limit: 11
+            }),
+            frames.next().unwrap().err()
+        );
+        assert!(frames.next().is_none());
+    }
+
+    #[test]
+    fn split_packet_frames_reports_invalid_frames() {
+        let mut data = Vec::new();
+        PacketFrameBuilder::new(&mut data).add_u8(1, 2);
+        data.extend_from_slice(&[0, 0, 0, 1, 9]);
+        assert_eq!(
+            Some(Error::InvalidFrameFormat(9)),
+            split_packet_frames(&data).err()
+        );
+
+        let (frames, consumed) = split_packet_frames(&data[..3]).unwrap();
+        assert!(frames.is_empty());
+        assert_eq!(0, consumed);
+    }
+}
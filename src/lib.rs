@@ -16,23 +16,39 @@
 //! ```abnf
 //! packet-frame = frame-size frame
 //! frame-size   = unsigned32
-//! frame        = frame-format field-count *field
-//! frame-format = 0x01
+//! frame        = (0x01 field-count *field) / (0x02 field-count *field checksum)
+//!              / (0x03 field-count *wide-field)
+//!              / (0x04 compressed-length compressed-frame)
+//!              / (0x05 field-count *large-field)
+//! checksum     = unsigned32
 //! field-count  = unsigned32
 //! field        = field-tag field-length field-value
 //! field-tag    = unsigned16
+//! wide-field   = wide-tag field-length field-value
+//! wide-tag     = unsigned32
+//! large-field  = field-tag large-length field-value
+//! large-length = unsigned64
+//! compressed-length = unsigned32
+//! compressed-frame  = octet-array
 //! field-length = unsigned32
 //! field-value  = octet-array
 //! unsigned16   = 0x0000-0xFFFF
 //! unsigned32   = 0x00000000-0xFFFFFFFF
+//! unsigned64   = 0x0000000000000000-0xFFFFFFFFFFFFFFFF
 //! octet-array  = *0x00-0xFF
 //! ```
 //! Where:
 //!
-//! * frame-format is always 0x01, but alternative formats may be added later
+//! * the first byte of a frame is its frame-format, currently 0x01 to 0x05
+//! * frame-format 0x02 adds a CRC-32 `checksum` of the preceding bytes of the frame
+//!   (from the frame-format to the end of the last field)
+//! * frame-format 0x03 uses 32 bit field-tags, and is read with `WideFrameParser`
+//! * frame-format 0x04 holds a zlib compressed frame with frame-format 0x01, which is
+//!   `compressed-length` bytes long (see the `compression` feature)
+//! * frame-format 0x05 uses 64 bit field-lengths, for field-values of 4GiB or more
 //! * the number `field`s must match `field-count`
 //! * the length of `field-value` must match `field-length`.
-//! * `unsigned-16` and `unsigned-32` are encoded using big-endian.
+//! * `unsigned-16`, `unsigned-32` and `unsigned-64` are encoded using big-endian.
 //!
 //! The root frame can either be encoded as a `frame` or as a `packet-frame`.  Encoding
 //! as a `packet-frame` is useful when sending `frame`s across a stream.
@@ -44,18 +60,34 @@
 //! * boolean values are encoded using a single byte (`0x00`=`false`, `0xFF`=`true`)
 //! * text is encoded as UTF-8
 //!
+//! When talking to a peer that encodes numbers as little-endian, builders and parsers
+//! can be created with `Endianness::Little` (e.g., `FrameBuilder::new_with_endianness`).
+//! This only changes how number field-values are encoded, the rest of the frame is
+//! always big-endian.
+//!
 //! # Reading and Writing
 //!
 //! This library tries to make reading and writing reliable and not dependant on
 //! the values being written.  To that end, the `add_*` methods for numbers always
-//! use the same number of bytes, irrespective of the actual values being written.
-//! Currently only `add_data` and `add_str` can add a variable number of bytes to the frame.
+//! use the same number of bytes, irrespective of the actual values being written
+//! (except for the `add_u*_compact` and `add_i64_zigzag` methods, which use the fewest bytes that hold the value).
+//! Currently only `add_data`, `add_bytes`, `add_str`, `add_char`, `add_ip`, `add_socket_addr` and the
+//! `add_*_slice` methods can add a variable number of bytes to the frame.
 //!
 //! Reading attempts to be forward compatible, with the following guarantees:
 //!
 //! * Any number written by a smaller `add_u*` method can always be be safely read by a larger one.
-//! (e.g., a number written using `add_u16` can be safely read using`get_u32`).
+//!   (e.g., a number written using `add_u16` can be safely read using`get_u32`).
 //! * Any number written by a larger `add_u*` method can not be read by a smaller one.
+//! * The same holds for the signed `add_i*` and `get_i*` methods.  Smaller numbers are
+//!   sign-extended when read by a larger method, so a negative number keeps its value
+//!   (e.g., `-1` written using `add_i16` is read as `-1` by `get_i64`).
+//! * Signed and unsigned numbers are not interchangeable: a negative number read by a
+//!   `get_u*` method is not sign-extended (e.g., `-1` written using `add_i16` is read as
+//!   `65535` by `get_u32`).
+//! * The `get_u*` methods also read unsigned numbers from field-values of any length
+//!   up to their size, as written by encoders that use the fewest bytes that hold the
+//!   value (e.g., a 3 byte field-value can be read using `get_u32`).
 //!
 //! This means that when upgrading a program it should always be safe to increase the range
 //! of a field.  If you want to decrease the range of a number field, the upgraded version
@@ -64,9 +96,22 @@
 //!
 //! # Create Features
 //!
-//! Yatlv has one optional feature:
+//! Yatlv has the following optional features:
 //!
+//! * `std` (enabled by default) supports `std::error::Error`, `write_to`, `FrameReader`
+//!   and `StreamingPacketWriter`.
+//!   Without it yatlv is `no_std`, but still requires `alloc`; `SliceFrameBuilder`
+//!   can be used to build frames without allocating.
 //! * `uuid` supports reading and writing uuids.
+//! * `serde` supports converting frames to and from serde data formats (see `SerdeFrame`).
+//! * `derive` supports deriving `ToFrame` and `FromFrame` for structs.
+//! * `time` supports reading and writing durations and system times as nanoseconds.
+//! * `bytes` supports reading frames held in `bytes::Bytes` without copying (see `BytesFrameParser`).
+//! * `tokio-codec` supports reading and writing packet-frames with `tokio_util::codec` (see `YatlvCodec`).
+//! * `compression` supports writing and reading compressed frames (frame-format 0x04), using
+//!   `FrameBuilder::new_compressed` and `OwnedFrameParser`.
+//! * `prost` supports adding and reading protobuf messages (`prost::Message`) as
+//!   field-values (see `FrameBuilderLike::add_message`).
 //!
 //! # Example Usage
 //!
@@ -122,14 +167,237 @@
 //! # Ok(())}
 //! ```
 
-use std::convert::TryInto;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::borrow::{Cow, ToOwned};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::{TryFrom, TryInto};
+#[cfg(feature = "std")]
+use std::io::Write;
+
+#[cfg(feature = "bytes")]
+mod bytes_frame;
+mod canonical;
+#[cfg(feature = "tokio-codec")]
+mod codec;
+mod convert;
+mod dump;
+mod editor;
+mod iter;
+mod owned;
+mod packet;
+#[cfg(feature = "std")]
+mod reader;
+mod relay;
+mod schema;
+#[cfg(feature = "serde")]
+mod serde_frame;
+mod slice_builder;
+#[cfg(feature = "std")]
+mod stream;
+mod tag;
+mod unique;
+mod wide;
+
+#[cfg(feature = "bytes")]
+pub use bytes_frame::BytesFrameParser;
+pub use canonical::{CanonicalFrame, CanonicalFrameBuilder};
+#[cfg(feature = "tokio-codec")]
+pub use codec::YatlvCodec;
+pub use convert::{FromField, FromFrame, ToField, ToFrame};
+pub use dump::{dump_frame, DebugMap, DebugValue, ValueEncoding};
+pub use editor::FrameEditor;
+pub use iter::{split_packet_frames, FrameIter, PacketFrameIter};
+pub use owned::OwnedFrameParser;
+pub use packet::PacketFrameParser;
+#[cfg(feature = "std")]
+pub use reader::FrameReader;
+pub use relay::RelayFrame;
+pub use schema::{FieldType, Schema, SchemaError};
+#[cfg(feature = "serde")]
+pub use serde_frame::{SerdeField, SerdeFrame, SerdeValue};
+pub use slice_builder::SliceFrameBuilder;
+#[cfg(feature = "std")]
+pub use stream::StreamingPacketWriter;
+pub use tag::Tag;
+pub use unique::UniqueFrameBuilder;
+pub use wide::{WideFrameBuilder, WideFrameParser};
+#[cfg(feature = "derive")]
+pub use yatlv_derive::{FromFrame, ToFrame};
+
+// lets the derive macros refer to `::yatlv` in this crate's own tests
+#[cfg(all(test, feature = "derive"))]
+extern crate self as yatlv;
 
 const SIZE_BYTES: usize = 4;
 
+/// Number of bytes taken by a frame-format and field-count.
+const FRAME_HEADER_BYTES: usize = 1 + SIZE_BYTES;
+
+/// Number of bytes taken by a field-tag and field-length.
+const FIELD_HEADER_BYTES: usize = 2 + SIZE_BYTES;
+
+/// Number of bytes before the field-value of a field in frame-format `0x03`.
+const WIDE_FIELD_HEADER_BYTES: usize = 4 + SIZE_BYTES;
+
+/// Number of bytes taken by a field-tag and field-length in a frame with
+/// frame-format `0x05`.
+const LARGE_FIELD_HEADER_BYTES: usize = 2 + 8;
+
+/// The field-tag reserved for the schema id of a frame, written as a u32 field.
+///
+/// See [FrameBuilder::new_with_schema] and [FrameParser::schema_id].  Frames using
+/// this convention must not use this tag for anything else.
+pub const SCHEMA_ID_TAG: u16 = 0;
+
+/// Calculate the encoded size of a frame.
+///
+/// `total_value_bytes` is the sum of the lengths of all the field-values.  A child
+/// frame is a field-value, so its own size should be included in the total.
+///
+/// ```
+/// use yatlv::{estimate_frame_size, FrameBuilder, FrameBuilderLike};
+/// let mut data = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new(&mut data);
+///     bld.add_u32(45, 7);
+///     bld.add_str(46, "hello");
+/// }
+/// assert_eq!(estimate_frame_size(2, 4 + 5), data.len());
+/// ```
+pub fn estimate_frame_size(field_count: usize, total_value_bytes: usize) -> usize {
+    FRAME_HEADER_BYTES + field_count * FIELD_HEADER_BYTES + total_value_bytes
+}
+
+/// The byte order used to encode number field-values.
+///
+/// This only affects the field-values written by the numeric `add_*` methods and
+/// read by the numeric `get_*` methods.  The frame-format, field-count, field-tag
+/// and field-length are always big-endian.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Endianness {
+    /// Most significant byte first (the default).
+    #[default]
+    Big,
+    /// Least significant byte first.
+    Little,
+}
+
+impl Endianness {
+    /// Convert big-endian bytes into this byte order.
+    fn order<B: AsMut<[u8]>>(self, mut bytes: B) -> B {
+        if self == Endianness::Little {
+            bytes.as_mut().reverse();
+        }
+        bytes
+    }
+
+    /// Apply a big-endian `decoder` to a value stored in this byte order.
+    fn decode<T>(self, value: &[u8], decoder: fn(&[u8]) -> Result<T>) -> Result<T> {
+        match self {
+            Endianness::Big => decoder(value),
+            Endianness::Little if value.len() <= 16 => {
+                let mut buf = [0; 16];
+                let buf = &mut buf[..value.len()];
+                buf.copy_from_slice(value);
+                buf.reverse();
+                decoder(buf)
+            }
+            // too long to be any supported number, let the decoder report it
+            Endianness::Little => decoder(value),
+        }
+    }
+}
+
+/// Settings that control how a [FrameParser] reads a frame.
+///
+/// Child frames are parsed with the same options as their parent.
+///
+/// ```
+/// use yatlv::{Endianness, ParseOptions};
+/// let options = ParseOptions {
+///     max_field_count: 100,
+///     ..ParseOptions::default()
+/// };
+/// assert_eq!(Endianness::Big, options.endianness);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ParseOptions {
+    /// The byte order used to decode numbers.
+    pub endianness: Endianness,
+
+    /// The largest field-count a frame may declare; larger counts are rejected
+    /// with [Error::FieldCountTooLarge].  Defaults to `u32::MAX` (no limit).
+    pub max_field_count: u32,
+
+    /// The deepest a child frame may be nested below the root frame; reading a
+    /// deeper frame fails with [Error::MaxDepthExceeded].  Defaults to 64.
+    pub max_depth: u32,
+
+    /// Build an index of field positions by tag when parsing, so that reading a
+    /// field does not need to scan the whole frame.  This costs an allocation per
+    /// frame, so it is only worthwhile for frames with many fields that are read
+    /// many times.  Defaults to `false`.
+    ///
+    /// With the `std` feature the index is a hash map, so finding a field takes
+    /// constant time; without it the index is a BTreeMap, taking time logarithmic in
+    /// the number of distinct tags.  `benches/lookup.rs` compares reading an indexed
+    /// frame with scanning one.
+    pub indexed: bool,
+
+    /// Reject frames where a tag appears more than once with
+    /// [Error::DuplicateTag].  Defaults to `false`, so repeated fields can be read
+    /// with the plural `get_*s` methods.
+    pub reject_duplicate_tags: bool,
+
+    /// The largest frame-size a packet-frame may declare; larger sizes are rejected
+    /// with [Error::FrameTooLarge] before any of the frame is read.  This is used by
+    /// the readers of packet-frames (`FrameReader` and [PacketFrameIter]), so a
+    /// hostile peer can not make them buffer a huge frame.  Defaults to `u32::MAX`
+    /// (no limit).
+    pub max_frame_size: u32,
+
+    /// The largest frame a compressed frame (frame-format `0x04`) may decompress to;
+    /// decompression stops once this is exceeded, and the frame is rejected with
+    /// [Error::DecompressedFrameTooLarge].  This stops a small compressed frame from
+    /// exhausting memory, so it defaults to 8MiB rather than no limit.
+    pub max_decompressed_size: u32,
+}
+
+/// The default for [ParseOptions::max_decompressed_size].
+const DEFAULT_MAX_DECOMPRESSED_SIZE: u32 = 8 * 1024 * 1024;
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            endianness: Endianness::Big,
+            max_field_count: u32::MAX,
+            max_depth: 64,
+            indexed: false,
+            reject_duplicate_tags: false,
+            max_frame_size: u32::MAX,
+            max_decompressed_size: DEFAULT_MAX_DECOMPRESSED_SIZE,
+        }
+    }
+}
+
 /// FrameBuilderLike defines the methods common to [FrameBuilder] and [PacketFrameBuilder].
 pub trait FrameBuilderLike {
     /// Add a slice of data as a field to the frame.
     ///
+    /// Returns the position of the field-value as `(start, len)`, where `start` is
+    /// the offset from the beginning of the frame being built.  The `add_*` methods
+    /// for other types return the same information.  Offsets are used rather than
+    /// references so that they stay valid as the underlying `Vec` grows.
+    ///
+    /// A field-length can not be more than `u32::MAX`; use [FrameBuilderLike::try_add_data]
+    /// if `value` might be longer than that.
+    ///
     /// ```
     /// use yatlv::{FrameBuilder, FrameBuilderLike};
     /// let mut data = Vec::with_capacity(100);
@@ -137,7 +405,7 @@ pub trait FrameBuilderLike {
     ///     let mut bld = FrameBuilder::new(&mut data);
     ///     let tag = 45;
     ///     let data = &[90, 9];
-    ///     bld.add_data(tag, data);
+    ///     assert_eq!((11, 2), bld.add_data(tag, data));
     /// }
     /// assert_eq!(&[
     ///     1,          // frame-format
@@ -146,11 +414,119 @@ pub trait FrameBuilderLike {
     ///     0, 0, 0, 2, // field-length
     ///     90, 9       // field-value
     /// ], &data[..]);
+    /// assert_eq!(&[90, 9], &data[11..11 + 2]);
+    /// ```
+    fn add_data(&mut self, tag: u16, value: &[u8]) -> (usize, usize);
+
+    /// Add any byte container (such as a `Vec<u8>`, `[u8; N]` or `Box<[u8]>`) as a
+    /// field to the frame.
+    ///
+    /// This behaves like [FrameBuilderLike::add_data], but saves converting the value
+    /// to a slice first, in the same way as [FrameBuilderLike::add_str].
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_bytes(45, vec![90, 9]);
+    ///     bld.add_bytes(46, [1, 2, 3]);
+    ///     bld.add_bytes(47, &b"abc"[..]);
+    /// }
+    /// let parser = FrameParser::new(&data).unwrap();
+    /// assert_eq!(Some(&[90, 9][..]), parser.get_data(45));
+    /// assert_eq!(Some(&[1, 2, 3][..]), parser.get_data(46));
+    /// assert_eq!(Some(&b"abc"[..]), parser.get_data(47));
+    /// ```
+    fn add_bytes<B>(&mut self, tag: u16, value: B) -> (usize, usize)
+    where
+        B: AsRef<[u8]>,
+    {
+        self.add_data(tag, value.as_ref())
+    }
+
+    /// Add a field whose value is written by `write`, which appends the value to
+    /// the `Vec<u8>` it is given.
+    ///
+    /// This is for values with their own encoding, such as the output of another
+    /// serializer, whose length is not known until they are written.  [FrameBuilder]
+    /// and [PacketFrameBuilder] let `write` append directly to their buffer and
+    /// fill in the field-length afterwards; other builders collect the value first.
+    ///
+    /// The buffer given to `write` may already hold the frame so far, so `write` must
+    /// only append to it.  Builders that pass their own buffer panic if `write` removes
+    /// bytes from it or changes the field-tag and field-length before the value.
+    ///
+    /// ```
+    /// use std::io::Write;
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_with(45, |value| write!(value, "{}-{}", 12, 34).unwrap());
+    /// }
+    /// let parser = FrameParser::new(&data).unwrap();
+    /// assert_eq!(Some("12-34"), parser.get_str(45).unwrap());
+    /// ```
+    fn add_with<F>(&mut self, tag: u16, write: F) -> (usize, usize)
+    where
+        F: FnOnce(&mut Vec<u8>),
+    {
+        let mut value = Vec::new();
+        write(&mut value);
+        self.add_data(tag, &value)
+    }
+
+    /// Add a slice of data as a field to the frame, failing with [Error::FieldTooLarge]
+    /// (and leaving the frame unchanged) if it is too long to be encoded.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     assert_eq!(Ok((11, 2)), bld.try_add_data(45, &[90, 9]));
+    /// }
+    /// assert_eq!(&[90, 9], &data[11..]);
+    /// ```
+    fn try_add_data(&mut self, tag: u16, value: &[u8]) -> Result<(usize, usize)> {
+        check_field_length(value.len())?;
+        Ok(self.add_data(tag, value))
+    }
+
+    /// Add a field to the frame using any type that implements [ToField].
+    ///
+    /// Numbers are encoded using the byte order of this builder.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add(45, 7u16);
+    ///     bld.add(46, "hi");
+    /// }
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 2, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 2, // field-length
+    ///     0, 7,       // field-value
+    ///     0, 46,      // field-tag
+    ///     0, 0, 0, 2, // field-length
+    ///     104, 105    // field-value
+    /// ], &data[..]);
     /// ```
-    fn add_data(&mut self, tag: u16, value: &[u8]);
+    fn add<T: ToField>(&mut self, tag: u16, value: T) -> (usize, usize) {
+        value.encode_field(self.endianness(), |value| self.add_data(tag, value))
+    }
 
     /// Create a new child frame builder.
     ///
+    /// The child is written as a packet-frame straight after the field-tag, so its
+    /// frame-size is also the field-length and the field-value is a plain frame.  It
+    /// is read back by [FrameParser::get_frame].
+    ///
     /// ```
     /// use yatlv::{FrameBuilder, FrameBuilderLike};
     /// let mut data = Vec::with_capacity(100);
@@ -174,11 +550,221 @@ pub trait FrameBuilderLike {
     ///     90, 9        // child field-value
     /// ], &data[..]);
     /// ```
-    fn add_frame(&mut self, tag: u16) -> PacketFrameBuilder;
+    fn add_frame(&mut self, tag: u16) -> PacketFrameBuilder<'_>;
+
+    /// Add an already encoded child frame (such as one read by [FrameParser::get_frame_raw])
+    /// as a field to the frame.
+    ///
+    /// The bytes are copied as they are, without checking that they hold a valid frame,
+    /// so this suits relaying child frames that do not need to be inspected.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut received = Vec::new();
+    /// FrameBuilder::new(&mut received).add_frame(1).add_str(2, "payload");
+    ///
+    /// let mut forwarded = Vec::new();
+    /// {
+    ///     let parser = FrameParser::new(&received).unwrap();
+    ///     let mut bld = FrameBuilder::new(&mut forwarded);
+    ///     bld.add_u8(3, 1);
+    ///     bld.add_frame_raw(1, parser.get_frame_raw(1).unwrap());
+    /// }
+    /// let parser = FrameParser::new(&forwarded).unwrap();
+    /// let child = parser.get_frame_required(1).unwrap();
+    /// assert_eq!(Some("payload"), child.get_str(2).unwrap());
+    /// ```
+    fn add_frame_raw(&mut self, tag: u16, frame: &[u8]) -> (usize, usize) {
+        self.add_data(tag, frame)
+    }
+
+    /// Add a child frame that was built separately (for example in its own `Vec<u8>`,
+    /// or cached across messages) as a field to the frame.
+    ///
+    /// Unlike [FrameBuilderLike::add_frame_raw], `frame` is first checked with
+    /// [validate_frame], so a frame that is not valid is rejected (leaving this frame
+    /// unchanged) rather than being embedded.
+    ///
+    /// ```
+    /// use yatlv::{Error, FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut child = Vec::new();
+    /// FrameBuilder::new(&mut child).add_u8(2, 7);
+    ///
+    /// let mut data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     assert!(bld.try_add_frame_raw(1, &child).is_ok());
+    ///     assert_eq!(
+    ///         Err(Error::IncompleteFieldValue(1, 0)),
+    ///         bld.try_add_frame_raw(3, &child[..child.len() - 1])
+    ///     );
+    /// }
+    /// let parser = FrameParser::new(&data).unwrap();
+    /// assert_eq!(1, parser.len());
+    /// assert_eq!(Some(7), parser.get_frame_required(1).unwrap().get_u8(2).unwrap());
+    /// ```
+    fn try_add_frame_raw(&mut self, tag: u16, frame: &[u8]) -> Result<(usize, usize)> {
+        validate_frame(frame)?;
+        self.try_add_data(tag, frame)
+    }
+
+    /// Copy every field of a parsed frame into this frame, returning the number of
+    /// fields added.
+    ///
+    /// Tags and field-values are copied as they are, so child frames are copied as
+    /// opaque bytes and numbers keep the byte order they were encoded with.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut base_data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut base_data);
+    ///     bld.add_u8(1, 1);
+    ///     bld.add_frame(2).add_str(3, "child");
+    /// }
+    /// let base = FrameParser::new(&base_data).unwrap();
+    ///
+    /// let mut data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_u8(4, 4);
+    ///     assert_eq!(2, bld.add_all_from(&base));
+    /// }
+    /// let frame = FrameParser::new(&data).unwrap();
+    /// assert_eq!(vec![4, 1, 2], frame.tags().collect::<Vec<_>>());
+    /// assert_eq!(Some("child"), frame.get_frame_required(2).unwrap().get_str(3).unwrap());
+    /// ```
+    fn add_all_from(&mut self, frame: &FrameParser<'_>) -> usize {
+        let mut count = 0;
+        for (tag, value) in frame.fields() {
+            self.add_data(tag, value);
+            count += 1;
+        }
+        count
+    }
+
+    /// Copy the fields of `base` whose tags are not used by `overrides`, followed by
+    /// every field of `overrides`, returning the number of fields added.
+    ///
+    /// This composes a frame from a template and per-message changes: a tag used in
+    /// `overrides` replaces all fields of `base` with that tag.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut base_data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut base_data);
+    ///     bld.add_str(1, "template");
+    ///     bld.add_u8(2, 1);
+    /// }
+    /// let mut override_data = Vec::new();
+    /// FrameBuilder::new(&mut override_data).add_u8(2, 7);
+    ///
+    /// let base = FrameParser::new(&base_data).unwrap();
+    /// let overrides = FrameParser::new(&override_data).unwrap();
+    /// let mut data = Vec::new();
+    /// assert_eq!(2, FrameBuilder::new(&mut data).add_merged(&base, &overrides));
+    ///
+    /// let frame = FrameParser::new(&data).unwrap();
+    /// assert_eq!(Some("template"), frame.get_str(1).unwrap());
+    /// assert_eq!(vec![7], frame.get_u8s(2).collect::<Result<Vec<_>, _>>().unwrap());
+    /// ```
+    fn add_merged(&mut self, base: &FrameParser<'_>, overrides: &FrameParser<'_>) -> usize {
+        let mut count = 0;
+        for (tag, value) in base.fields() {
+            if overrides.get_data(tag).is_none() {
+                self.add_data(tag, value);
+                count += 1;
+            }
+        }
+        count + self.add_all_from(overrides)
+    }
+
+    /// Add one field with tag `tag` for each value in `values`, returning the number of
+    /// fields added.
+    ///
+    /// This writes a repeated field, as read by the plural getters such as
+    /// [FrameParser::get_datas].  [FrameBuilder] and [PacketFrameBuilder] reserve space
+    /// for the field-headers up front, using the size hint of `values`.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let values: Vec<&[u8]> = vec![b"one", b"two", b"three"];
+    /// let mut data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     assert_eq!(3, bld.add_data_iter(45, values.iter()));
+    /// }
+    /// let parser = FrameParser::new(&data).unwrap();
+    /// assert_eq!(values, parser.get_datas(45).collect::<Vec<_>>());
+    /// ```
+    fn add_data_iter<I>(&mut self, tag: u16, values: I) -> usize
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let mut count = 0;
+        for value in values {
+            self.add_data(tag, value.as_ref());
+            count += 1;
+        }
+        count
+    }
+
+    /// Add a field that has already been encoded (field-tag, field-length and
+    /// field-value), such as one returned by [FrameParser::field_raw].
+    ///
+    /// [FrameBuilder] and [PacketFrameBuilder] copy `field` verbatim, so a cached
+    /// field can be reused in many frames without encoding it again.  `field` is
+    /// assumed to be a single valid field; this is only checked in debug builds.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let cached: &[u8] = &[0, 12, 0, 0, 0, 2, 4, 5];
+    /// let mut data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_u8(11, 1);
+    ///     assert_eq!((18, 2), bld.add_raw_field(cached));
+    /// }
+    /// let parser = FrameParser::new(&data).unwrap();
+    /// assert_eq!(Some(&[4, 5][..]), parser.get_data(12));
+    /// assert_eq!(Some(cached), parser.field_raw(12));
+    /// ```
+    fn add_raw_field(&mut self, field: &[u8]) -> (usize, usize) {
+        debug_assert_raw_field(field);
+        let tag = u16::from_be_bytes([field[0], field[1]]);
+        self.add_data(tag, &field[FIELD_HEADER_BYTES..])
+    }
+
+    /// The byte order used by the `add_*` methods for numbers.
+    ///
+    /// ```
+    /// use yatlv::{Endianness, FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new_with_endianness(&mut data, Endianness::Little);
+    ///     assert_eq!(Endianness::Little, bld.endianness());
+    ///     bld.add_u16(45, 7);
+    /// }
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 2, // field-length
+    ///     7, 0        // field-value
+    /// ], &data[..]);
+    /// ```
+    fn endianness(&self) -> Endianness {
+        Endianness::Big
+    }
 
     #[deprecated]
     /// Use [FrameBuilderLike::add_frame] instead.
-    fn add_child(&mut self, tag: u16) -> PacketFrameBuilder {
+    ///
+    /// This is the same as [FrameBuilderLike::add_frame], so the child frame is read
+    /// back by [FrameParser::get_frame].
+    fn add_child(&mut self, tag: u16) -> PacketFrameBuilder<'_> {
         self.add_frame(tag)
     }
 
@@ -202,8 +788,8 @@ pub trait FrameBuilderLike {
     ///     0           // field value
     /// ], &data[..]);
     /// ```
-    fn add_bool(&mut self, tag: u16, value: bool) {
-        self.add_u8(tag, if value { 0xFF } else { 0x00 })
+    fn add_bool(&mut self, tag: u16, value: bool) -> (usize, usize) {
+        self.add(tag, value)
     }
 
     /// Add a u8 field to the frame.
@@ -225,8 +811,8 @@ pub trait FrameBuilderLike {
     ///     7           // field-value
     /// ], &data[..]);
     /// ```
-    fn add_u8(&mut self, tag: u16, value: u8) {
-        self.add_data(tag, &value.to_be_bytes())
+    fn add_u8(&mut self, tag: u16, value: u8) -> (usize, usize) {
+        self.add(tag, value)
     }
 
     /// Add a u16 field to the frame.
@@ -251,8 +837,8 @@ pub trait FrameBuilderLike {
     ///     0, 7        // field-value
     /// ], &data[..]);
     /// ```
-    fn add_u16(&mut self, tag: u16, value: u16) {
-        self.add_data(tag, &value.to_be_bytes())
+    fn add_u16(&mut self, tag: u16, value: u16) -> (usize, usize) {
+        self.add(tag, value)
     }
 
     /// Add a u32 field to the frame.
@@ -274,8 +860,8 @@ pub trait FrameBuilderLike {
     ///     0, 0, 0, 7  // field-value
     /// ], &data[..]);
     /// ```
-    fn add_u32(&mut self, tag: u16, value: u32) {
-        self.add_data(tag, &value.to_be_bytes())
+    fn add_u32(&mut self, tag: u16, value: u32) -> (usize, usize) {
+        self.add(tag, value)
     }
 
     /// Add a u64 field to the frame.
@@ -297,11 +883,11 @@ pub trait FrameBuilderLike {
     ///     0, 0, 0, 0, 0, 0, 0, 7 // field-value
     /// ], &data[..]);
     /// ```
-    fn add_u64(&mut self, tag: u16, value: u64) {
-        self.add_data(tag, &value.to_be_bytes())
+    fn add_u64(&mut self, tag: u16, value: u64) -> (usize, usize) {
+        self.add(tag, value)
     }
 
-    /// Add a str field to the frame.
+    /// Add an i8 field to the frame.
     ///
     /// ```
     /// use yatlv::{FrameBuilder, FrameBuilderLike};
@@ -309,1619 +895,10536 @@ pub trait FrameBuilderLike {
     /// {
     ///     let mut bld = FrameBuilder::new(&mut data);
     ///     let tag = 45;
-    ///     let data = "hello";
-    ///     bld.add_str(tag, data);
+    ///     let data = -7;
+    ///     bld.add_i8(tag, data);
     /// }
     /// assert_eq!(&[
-    ///     1,                      // frame-format
-    ///     0, 0, 0, 1,             // field count
-    ///     0, 45,                  // field-tag
-    ///     0, 0, 0, 5,             // field-length
-    ///     104, 101, 108, 108, 111 // field-value
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 1, // field-length
+    ///     249         // field-value
     /// ], &data[..]);
     /// ```
-    fn add_str<S>(&mut self, tag: u16, value: S)
-    where
-        S: AsRef<str>,
-    {
-        self.add_data(tag, value.as_ref().as_bytes())
+    fn add_i8(&mut self, tag: u16, value: i8) -> (usize, usize) {
+        self.add(tag, value)
     }
 
-    /// Add a uuid field to the frame.
+    /// Add an i16 field to the frame.
+    ///
+    /// This method will always use a two byte encoding
+    /// for the value.
     ///
     /// ```
-    /// use uuid::Uuid;
     /// use yatlv::{FrameBuilder, FrameBuilderLike};
     /// let mut data = Vec::with_capacity(100);
     /// {
     ///     let mut bld = FrameBuilder::new(&mut data);
     ///     let tag = 45;
-    ///     let data = Uuid::parse_str("3011712a-d972-4a5d-bbf9-ec5fb7525c97").unwrap();
-    ///     bld.add_uuid(tag, &data);
+    ///     let data = -7;
+    ///     bld.add_i16(tag, data);
     /// }
     /// assert_eq!(&[
-    ///     1,                      // frame-format
-    ///     0, 0, 0, 1,             // field count
-    ///     0, 45,                  // field-tag
-    ///     0, 0, 0, 16,             // field-length
-    ///     48, 17, 113, 42, 217, 114, 74, 93, 187, 249, 236, 95, 183, 82, 92, 151 // field-value
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 2, // field-length
+    ///     255, 249    // field-value
     /// ], &data[..]);
     /// ```
-    #[cfg(feature = "uuid")]
-    fn add_uuid(&mut self, tag: u16, value: &uuid::Uuid) {
-        self.add_data(tag, value.as_bytes())
+    fn add_i16(&mut self, tag: u16, value: i16) -> (usize, usize) {
+        self.add(tag, value)
     }
-}
 
-/// FrameBuilder can be used to push a frame into a mutable `Vec<u8>`
-///
-/// For usage details see [FrameBuilderLike].
-///
-/// ```
-/// use yatlv::FrameBuilder;
-/// let mut data = Vec::with_capacity(100);
-/// {
-///     FrameBuilder::new(&mut data);
-/// }
-/// assert_eq!(&[
-///     1,         // frame-format
-///     0, 0, 0, 0 // field-count
-/// ], &data[..]);
-/// ```
-pub struct FrameBuilder<'a> {
-    field_count: u32,
-    field_start: usize,
-    data: &'a mut Vec<u8>,
-}
+    /// Add an i32 field to the frame.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     let data = -7;
+    ///     bld.add_i32(tag, data);
+    /// }
+    /// assert_eq!(&[
+    ///     1,                 // frame-format
+    ///     0, 0, 0, 1,        // field count
+    ///     0, 45,             // field-tag
+    ///     0, 0, 0, 4,        // field-length
+    ///     255, 255, 255, 249 // field-value
+    /// ], &data[..]);
+    /// ```
+    fn add_i32(&mut self, tag: u16, value: i32) -> (usize, usize) {
+        self.add(tag, value)
+    }
 
-impl<'a> Drop for FrameBuilder<'a> {
-    fn drop(&mut self) {
-        let field_count_pos = self.field_start + 1;
-        self.data[field_count_pos..field_count_pos + SIZE_BYTES]
-            .copy_from_slice(&self.field_count.to_be_bytes())
+    /// Add an i64 field to the frame.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     let data = 7;
+    ///     bld.add_i64(tag, data);
+    /// }
+    /// assert_eq!(&[
+    ///     1,                     // frame-format
+    ///     0, 0, 0, 1,            // field count
+    ///     0, 45,                 // field-tag
+    ///     0, 0, 0, 8,            // field-length
+    ///     0, 0, 0, 0, 0, 0, 0, 7 // field-value
+    /// ], &data[..]);
+    /// ```
+    fn add_i64(&mut self, tag: u16, value: i64) -> (usize, usize) {
+        self.add(tag, value)
     }
-}
 
-impl<'a> FrameBuilder<'a> {
-    pub fn new(data: &mut Vec<u8>) -> FrameBuilder {
-        let field_start = data.len();
-        data.extend_from_slice(&[1, 0, 0, 0, 0]);
-
-        FrameBuilder {
-            field_count: 0,
-            field_start,
-            data,
-        }
+    /// Add a u128 field to the frame.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     let data = 7;
+    ///     bld.add_u128(tag, data);
+    /// }
+    /// assert_eq!(&[
+    ///     1,                                             // frame-format
+    ///     0, 0, 0, 1,                                    // field count
+    ///     0, 45,                                         // field-tag
+    ///     0, 0, 0, 16,                                   // field-length
+    ///     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7 // field-value
+    /// ], &data[..]);
+    /// ```
+    fn add_u128(&mut self, tag: u16, value: u128) -> (usize, usize) {
+        self.add(tag, value)
     }
-}
 
-impl<'a> FrameBuilderLike for FrameBuilder<'a> {
-    fn add_data(&mut self, tag: u16, value: &[u8]) {
-        self.field_count += 1;
-        self.data.reserve(6 + value.len());
-        self.data.extend_from_slice(&tag.to_be_bytes());
-        self.data
-            .extend_from_slice(&(value.len() as u32).to_be_bytes());
-        self.data.extend_from_slice(value);
+    /// Add an i128 field to the frame.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     let data = 7;
+    ///     bld.add_i128(tag, data);
+    /// }
+    /// assert_eq!(&[
+    ///     1,                                             // frame-format
+    ///     0, 0, 0, 1,                                    // field count
+    ///     0, 45,                                         // field-tag
+    ///     0, 0, 0, 16,                                   // field-length
+    ///     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7 // field-value
+    /// ], &data[..]);
+    /// ```
+    fn add_i128(&mut self, tag: u16, value: i128) -> (usize, usize) {
+        self.add(tag, value)
     }
 
-    fn add_frame(&mut self, tag: u16) -> PacketFrameBuilder {
-        self.field_count += 1;
-        self.data.reserve(6);
-        self.data.extend_from_slice(&tag.to_be_bytes());
-        PacketFrameBuilder::new(self.data)
+    /// Add an f32 field to the frame.
+    ///
+    /// This method will always use a four byte encoding
+    /// for the value.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     let data = 1.5;
+    ///     bld.add_f32(tag, data);
+    /// }
+    /// assert_eq!(&[
+    ///     1,            // frame-format
+    ///     0, 0, 0, 1,   // field count
+    ///     0, 45,        // field-tag
+    ///     0, 0, 0, 4,   // field-length
+    ///     63, 192, 0, 0 // field-value
+    /// ], &data[..]);
+    /// ```
+    fn add_f32(&mut self, tag: u16, value: f32) -> (usize, usize) {
+        self.add(tag, value)
     }
-}
-
-/// PacketBuilder can be used to push a packet-frame into a mutable `Vec<u8>`
-///
-/// For usage details see [FrameBuilderLike].
-/// ```
-/// use yatlv::PacketFrameBuilder;
-/// let mut data = Vec::with_capacity(100);
-/// {
-///     PacketFrameBuilder::new(&mut data);
-/// }
-/// assert_eq!(&[
-///     0, 0, 0, 5, // packet-size
-///     1,  // frame-format
-///     0, 0, 0, 0 // field-count
-/// ], &data[..]);
-/// ```
-pub struct PacketFrameBuilder<'a> {
-    field_count: u32,
-    packet_start: usize,
-    data: &'a mut Vec<u8>,
-}
-
-impl<'a> Drop for PacketFrameBuilder<'a> {
-    fn drop(&mut self) {
-        let packet_length = (self.data.len() - self.packet_start - SIZE_BYTES) as u32;
 
-        self.data[self.packet_start..self.packet_start + SIZE_BYTES]
-            .copy_from_slice(&packet_length.to_be_bytes());
+    /// Add an f64 field to the frame.
+    ///
+    /// This method will always use an eight byte encoding
+    /// for the value.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     let data = 1.5;
+    ///     bld.add_f64(tag, data);
+    /// }
+    /// assert_eq!(&[
+    ///     1,                        // frame-format
+    ///     0, 0, 0, 1,               // field count
+    ///     0, 45,                    // field-tag
+    ///     0, 0, 0, 8,               // field-length
+    ///     63, 248, 0, 0, 0, 0, 0, 0 // field-value
+    /// ], &data[..]);
+    /// ```
+    fn add_f64(&mut self, tag: u16, value: f64) -> (usize, usize) {
+        self.add(tag, value)
+    }
 
-        let field_count_pos = self.packet_start + 5;
-        self.data[field_count_pos..field_count_pos + SIZE_BYTES]
-            .copy_from_slice(&self.field_count.to_be_bytes())
+    /// Add a C-style enum to the frame, as a u32 field holding its discriminant.
+    ///
+    /// The integer each variant converts to is part of the wire format, so existing
+    /// variants must keep their values once frames using them have been shared.
+    /// Read the field back with [FrameParser::get_enum].
+    ///
+    /// ```
+    /// use core::convert::TryFrom;
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    ///
+    /// #[derive(Debug, PartialEq, Clone, Copy)]
+    /// enum Colour {
+    ///     Red = 1,
+    ///     Green = 2,
+    /// }
+    ///
+    /// impl From<Colour> for u32 {
+    ///     fn from(colour: Colour) -> u32 {
+    ///         colour as u32
+    ///     }
+    /// }
+    ///
+    /// impl TryFrom<u32> for Colour {
+    ///     type Error = ();
+    ///     fn try_from(value: u32) -> Result<Colour, ()> {
+    ///         match value {
+    ///             1 => Ok(Colour::Red),
+    ///             2 => Ok(Colour::Green),
+    ///             _ => Err(()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut data = Vec::new();
+    /// FrameBuilder::new(&mut data).add_enum(45, Colour::Green);
+    /// let parser = FrameParser::new(&data).unwrap();
+    /// assert_eq!(Some(2), parser.get_u32(45).unwrap());
+    /// assert_eq!(Some(Colour::Green), parser.get_enum(45).unwrap());
+    /// ```
+    fn add_enum<E: Into<u32>>(&mut self, tag: u16, value: E) -> (usize, usize) {
+        self.add_u32(tag, value.into())
     }
-}
 
-impl<'a> PacketFrameBuilder<'a> {
-    pub fn new(data: &mut Vec<u8>) -> PacketFrameBuilder {
-        let packet_start = data.len();
-        data.extend_from_slice(&[0, 0, 0, 0, 1, 0, 0, 0, 0]);
+    /// Add a u16 field to the frame, using the fewest bytes that hold the value
+    /// (at least one).
+    ///
+    /// The field can be read by [FrameParser::get_u16], which accepts field-values
+    /// of any length up to the size of a u16.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_u16_compact(45, 0x1ff);
+    /// }
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 2, // field-length
+    ///     1, 255      // field-value
+    /// ], &data[..]);
+    /// assert_eq!(Some(0x1ff), FrameParser::new(&data).unwrap().get_u16(45).unwrap());
+    /// ```
+    fn add_u16_compact(&mut self, tag: u16, value: u16) -> (usize, usize) {
+        let (bytes, len) = encode_compact(value.to_be_bytes(), self.endianness());
+        self.add_data(tag, &bytes[..len])
+    }
 
-        PacketFrameBuilder {
-            field_count: 0,
-            packet_start,
-            data,
-        }
+    /// Add a u32 field to the frame, using the fewest bytes that hold the value
+    /// (at least one).
+    ///
+    /// The field can be read by [FrameParser::get_u32], which accepts field-values
+    /// of any length up to the size of a u32.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_u32_compact(45, 0x1ff);
+    /// }
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 2, // field-length
+    ///     1, 255      // field-value
+    /// ], &data[..]);
+    /// assert_eq!(Some(0x1ff), FrameParser::new(&data).unwrap().get_u32(45).unwrap());
+    /// ```
+    fn add_u32_compact(&mut self, tag: u16, value: u32) -> (usize, usize) {
+        let (bytes, len) = encode_compact(value.to_be_bytes(), self.endianness());
+        self.add_data(tag, &bytes[..len])
     }
-}
 
-impl<'a> FrameBuilderLike for PacketFrameBuilder<'a> {
-    fn add_data(&mut self, tag: u16, value: &[u8]) {
-        self.field_count += 1;
-        self.data.reserve(6 + value.len());
-        self.data.extend_from_slice(&tag.to_be_bytes());
-        self.data
-            .extend_from_slice(&(value.len() as u32).to_be_bytes());
-        self.data.extend_from_slice(value);
+    /// Add a u64 field to the frame, using the fewest bytes that hold the value
+    /// (at least one).
+    ///
+    /// The field can be read by [FrameParser::get_u64], which accepts field-values
+    /// of any length up to the size of a u64.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_u64_compact(45, 0x1ff);
+    /// }
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 2, // field-length
+    ///     1, 255      // field-value
+    /// ], &data[..]);
+    /// assert_eq!(Some(0x1ff), FrameParser::new(&data).unwrap().get_u64(45).unwrap());
+    /// ```
+    fn add_u64_compact(&mut self, tag: u16, value: u64) -> (usize, usize) {
+        let (bytes, len) = encode_compact(value.to_be_bytes(), self.endianness());
+        self.add_data(tag, &bytes[..len])
     }
 
-    fn add_frame(&mut self, tag: u16) -> PacketFrameBuilder {
-        self.field_count += 1;
-        self.data.reserve(6);
-        self.data.extend_from_slice(&tag.to_be_bytes());
-        PacketFrameBuilder::new(self.data)
+    /// Add a u128 field to the frame, using the fewest bytes that hold the value
+    /// (at least one).
+    ///
+    /// The field can be read by [FrameParser::get_u128], which accepts field-values
+    /// of any length up to the size of a u128.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_u128_compact(45, 0x1ff);
+    /// }
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 2, // field-length
+    ///     1, 255      // field-value
+    /// ], &data[..]);
+    /// assert_eq!(Some(0x1ff), FrameParser::new(&data).unwrap().get_u128(45).unwrap());
+    /// ```
+    fn add_u128_compact(&mut self, tag: u16, value: u128) -> (usize, usize) {
+        let (bytes, len) = encode_compact(value.to_be_bytes(), self.endianness());
+        self.add_data(tag, &bytes[..len])
     }
-}
 
-/// Library Error Type
-#[derive(Debug, Eq, PartialEq)]
-pub enum Error {
-    /// The frame must start with a single format byte.
-    IncompleteFrameFormat,
+    /// Add an i64 field to the frame using zigzag encoding, so that numbers close to
+    /// zero (positive or negative) use the fewest bytes.
+    ///
+    /// The value is mapped to a u64 with `(n << 1) ^ (n >> 63)` (0, -1, 1, -2, ...
+    /// become 0, 1, 2, 3, ...) and written like [FrameBuilderLike::add_u64_compact].
+    /// The field must be read by [FrameParser::get_i64_zigzag], not [FrameParser::get_i64].
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_i64_zigzag(45, -200);
+    /// }
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 2, // field-length
+    ///     1, 143      // field-value
+    /// ], &data[..]);
+    /// assert_eq!(Some(-200), FrameParser::new(&data).unwrap().get_i64_zigzag(45).unwrap());
+    /// ```
+    fn add_i64_zigzag(&mut self, tag: u16, value: i64) -> (usize, usize) {
+        self.add_u64_compact(tag, ((value << 1) ^ (value >> 63)) as u64)
+    }
 
-    /// The frame format must be one of the recognized formats (currently only, `0x01`).
-    InvalidFrameFormat(u32),
+    /// Add a slice of u16 values as a single field to the frame.
+    ///
+    /// Each value is encoded using 2 bytes, so the field-length is 2 times the
+    /// number of values.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_u16_slice(45, &[7, 256]);
+    /// }
+    /// assert_eq!(&[0, 7, 1, 0], &data[11..]);
+    /// ```
+    fn add_u16_slice(&mut self, tag: u16, values: &[u16]) -> (usize, usize) {
+        self.add_data(
+            tag,
+            &encode_numbers(self.endianness(), values, u16::to_be_bytes),
+        )
+    }
 
-    /// The frame must have a four byte field-count that indicates the number fields
-    /// in the frame (encoded as big-endian u32)
-    IncompleteFrameFieldCount,
+    /// Add a slice of u32 values as a single field to the frame.
+    ///
+    /// Each value is encoded using 4 bytes, so the field-length is 4 times the
+    /// number of values.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_u32_slice(45, &[7, 256]);
+    /// }
+    /// assert_eq!(&[0, 0, 0, 7, 0, 0, 1, 0], &data[11..]);
+    /// ```
+    fn add_u32_slice(&mut self, tag: u16, values: &[u32]) -> (usize, usize) {
+        self.add_data(
+            tag,
+            &encode_numbers(self.endianness(), values, u32::to_be_bytes),
+        )
+    }
 
-    /// Each field must start with a two byte tag (big-endian u16) and a
-    /// four byte length (big-endian u32).
-    IncompleteFieldTagOrLength,
+    /// Add a slice of u64 values as a single field to the frame.
+    ///
+    /// Each value is encoded using 8 bytes, so the field-length is 8 times the
+    /// number of values.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_u64_slice(45, &[7]);
+    /// }
+    /// assert_eq!(&[0, 0, 0, 0, 0, 0, 0, 7], &data[11..]);
+    /// ```
+    fn add_u64_slice(&mut self, tag: u16, values: &[u64]) -> (usize, usize) {
+        self.add_data(
+            tag,
+            &encode_numbers(self.endianness(), values, u64::to_be_bytes),
+        )
+    }
 
-    /// Each field must have a value that is field-length long
-    /// This error as expected and actual lengths.
-    IncompleteFieldValue(usize, usize),
+    /// Add a slice of f32 values as a single field to the frame.
+    ///
+    /// Each value is encoded using 4 bytes, so the field-length is 4 times the
+    /// number of values.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.add_f32_slice(45, &[1.5, -2.0]);
+    /// }
+    /// assert_eq!(&[63, 192, 0, 0, 192, 0, 0, 0], &data[11..]);
+    /// ```
+    fn add_f32_slice(&mut self, tag: u16, values: &[f32]) -> (usize, usize) {
+        self.add_data(
+            tag,
+            &encode_numbers(self.endianness(), values, f32::to_be_bytes),
+        )
+    }
 
-    /// When converting a field to an expected type of value the field length
-    /// must be compatible with the expected value type.
-    IncompatibleFieldLength(usize),
-
-    /// When converting a field into an expected type the value of the field
-    /// must be compatible with the expected type.
-    IncompatibleFieldValue,
-
-    /// Once all the fields have been read there should be no more data in the
-    /// frame.
-    UnexpectedData,
-}
-
-/// Library Result Type
-pub type Result<T> = std::result::Result<T, Error>;
-
-struct FrameParserField<'a> {
-    tag: u16,
-    value: &'a [u8],
-}
-
-/// FrameParser can be used to access field encoded as a frame.
-pub struct FrameParser<'a> {
-    fields: Vec<FrameParserField<'a>>,
-}
-
-enum FrameFormat {
-    Format1,
-}
-
-fn read_frame_format(data: &[u8]) -> Result<(FrameFormat, &[u8])> {
-    if !data.is_empty() {
-        let (field_count_bytes, tail) = data.split_at(1);
-        let raw_format = field_count_bytes[0];
-        let format = match raw_format {
-            0x01 => Ok(FrameFormat::Format1),
-            _ => Err(Error::InvalidFrameFormat(raw_format as u32)),
-        }?;
-        Ok((format, tail))
-    } else {
-        Err(Error::IncompleteFrameFormat)
-    }
-}
-
-fn read_frame_field_count(data: &[u8]) -> Result<(u32, &[u8])> {
-    if data.len() >= 4 {
-        let (field_count_bytes, tail) = data.split_at(4);
-        let field_count = u32::from_be_bytes(field_count_bytes.try_into().unwrap());
-        Ok((field_count, tail))
-    } else {
-        Err(Error::IncompleteFrameFieldCount)
-    }
-}
-
-fn read_field_tag_and_length(data: &[u8]) -> Result<(u16, usize, &[u8])> {
-    if data.len() >= 6 {
-        let (tag_bytes, tail) = data.split_at(2);
-        let tag = u16::from_be_bytes(tag_bytes.try_into().unwrap());
-        let (length_bytes, tail) = tail.split_at(4);
-        let length = u32::from_be_bytes(length_bytes.try_into().unwrap()) as usize;
-        Ok((tag, length, tail))
-    } else {
-        Err(Error::IncompleteFieldTagOrLength)
-    }
-}
-
-fn read_field_value(data: &[u8], field_length: usize) -> Result<(&[u8], &[u8])> {
-    if data.len() >= field_length {
-        Ok(data.split_at(field_length))
-    } else {
-        Err(Error::IncompleteFieldValue(field_length, data.len()))
-    }
-}
-
-impl<'a> FrameParser<'a> {
-    /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_data(12, &[4, 5]);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=[4, 5])
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let expected: &[u8] = &[4, 5];
-    /// assert_eq!(Some(expected), parser.get_data(12));
-    /// # Ok(()) }
-    ///  ```
-    pub fn new(frame_data: &[u8]) -> Result<FrameParser> {
-        let (_, body) = read_frame_format(frame_data)?;
-        let (field_count, mut body) = read_frame_field_count(body)?;
-        let mut fields = Vec::with_capacity(field_count as usize);
-        for _ in 0..field_count {
-            let (tag, length, tail) = read_field_tag_and_length(body)?;
-            let (value, tail) = read_field_value(tail, length)?;
-            fields.push(FrameParserField { tag, value });
-            body = tail
-        }
-        if body.is_empty() {
-            Ok(FrameParser { fields })
-        } else {
-            Err(Error::UnexpectedData)
-        }
-    }
-
-    /// Read field from frame.
+    /// Add a str field to the frame.
+    ///
+    /// `value` can be borrowed or owned, including a `Cow<str>`, so a caller that
+    /// only sometimes owns its text does not need to copy it.
+    ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_data(12, &[4, 5]);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=[4, 5])
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let expected: &[u8] = &[4, 5];
-    /// assert_eq!(Some(expected), parser.get_data(12));
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_data(&self, search_tag: u16) -> Option<&'a [u8]> {
-        for field in &self.fields {
-            if field.tag == search_tag {
-                return Some(field.value);
-            }
-        }
-        None
-    }
-
-    /// Read fields from frame.
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     let data = "hello";
+    ///     bld.add_str(tag, data);
+    /// }
+    /// assert_eq!(&[
+    ///     1,                      // frame-format
+    ///     0, 0, 0, 1,             // field count
+    ///     0, 45,                  // field-tag
+    ///     0, 0, 0, 5,             // field-length
+    ///     104, 101, 108, 108, 111 // field-value
+    /// ], &data[..]);
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_data(12, &[4, 5]);
-    /// #     bld.add_data(12, &[3, 5]);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a two fields
-    /// // (tag=12, value1=[4, 5], value2=[3, 5])
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let expected = vec![&[4, 5], &[3, 5]];
-    /// let actual: Vec<&[u8]> =  parser.get_datas(12).collect();
-    /// assert_eq!(expected, actual);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_datas<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = &'a [u8]>
+    fn add_str<S>(&mut self, tag: u16, value: S) -> (usize, usize)
     where
-        'b: 'a,
+        S: AsRef<str>,
     {
-        self.fields
-            .iter()
-            .filter(move |f| f.tag == search_tag)
-            .map(|f| f.value)
+        self.add(tag, value.as_ref())
     }
 
-    /// Read u8 field from frame
+    /// Add a str field to the frame, holding at most `max_bytes` of `value`.
     ///
-    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
-    /// is small enough to be returned in a `u8`.
+    /// A longer `value` is cut at the last char boundary that fits, so the field-value
+    /// is always valid UTF-8 (and may be shorter than `max_bytes`).  As well as the
+    /// offset and length of the field-value, this returns whether `value` was cut.
     ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_u8(12, 9);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=9)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// assert_eq!(Some(9), parser.get_u8(12)?);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_u8(&self, search_tag: u16) -> Result<Option<u8>> {
-        self.decode_value(search_tag, decode_u8)
-    }
-
-    /// Read u8 fields from frame
-    ///
-    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
-    /// is small enough to be returned in a `u8`.
-    ///
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     assert_eq!((11, 3, false), bld.add_str_truncated(45, "abc", 4));
+    ///     assert_eq!((20, 2, true), bld.add_str_truncated(46, "ab€", 4));
+    /// }
+    /// let parser = FrameParser::new(&data).unwrap();
+    /// assert_eq!(Some("ab"), parser.get_str(46).unwrap());
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_u8(12, 6);
-    /// #     bld.add_u8(12, 9);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a two fields
-    /// // (tag=12, value1=6, value2=9)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let expected : Vec<Result<u8>> = vec![Ok(6), Ok(9)];
-    /// let actual: Vec<Result<u8>> = parser.get_u8s(12).collect();
-    /// assert_eq!(expected, actual);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_u8s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<u8>> + 'b
+    fn add_str_truncated<S>(&mut self, tag: u16, value: S, max_bytes: usize) -> (usize, usize, bool)
     where
-        'b: 'a,
+        S: AsRef<str>,
     {
-        self.get_datas(search_tag).map(decode_u8)
+        let value = value.as_ref();
+        let mut end = value.len().min(max_bytes);
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (offset, len) = self.add_str(tag, &value[..end]);
+        (offset, len, end < value.len())
     }
 
-    /// Read u16 field from frame
+    /// Add a char field to the frame.
     ///
-    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
-    /// is small enough to be returned in a `u16`.
+    /// The char is encoded as UTF-8, so takes between 1 and 4 bytes.
     ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_u16(12, 1024);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=1024)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// assert_eq!(Some(1024), parser.get_u16(12)?);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_u16(&self, search_tag: u16) -> Result<Option<u16>> {
-        self.decode_value(search_tag, decode_u16)
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     bld.add_char(tag, '€');
+    /// }
+    /// assert_eq!(&[
+    ///     1,            // frame-format
+    ///     0, 0, 0, 1,   // field count
+    ///     0, 45,        // field-tag
+    ///     0, 0, 0, 3,   // field-length
+    ///     226, 130, 172 // field-value
+    /// ], &data[..]);
+    /// ```
+    fn add_char(&mut self, tag: u16, value: char) -> (usize, usize) {
+        self.add(tag, value)
     }
 
-    /// Read u16 fields from frame
+    /// Add an IPv4 address field to the frame.
     ///
-    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
-    /// is small enough to be returned in a `u16`.
+    /// ```
+    /// use core::net::Ipv4Addr;
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     bld.add_ipv4(tag, Ipv4Addr::new(192, 168, 0, 1));
+    /// }
+    /// assert_eq!(&[
+    ///     1,            // frame-format
+    ///     0, 0, 0, 1,   // field count
+    ///     0, 45,        // field-tag
+    ///     0, 0, 0, 4,   // field-length
+    ///     192, 168, 0, 1 // field-value
+    /// ], &data[..]);
+    /// ```
+    fn add_ipv4(&mut self, tag: u16, value: core::net::Ipv4Addr) -> (usize, usize) {
+        self.add(tag, value)
+    }
+
+    /// Add an IPv6 address field to the frame.
     ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_u16(12, 1024);
-    /// #     bld.add_u16(12, 1025);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a two fields
-    /// // (tag=12, value1=1024, value2=1025)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let expected : Vec<Result<u16>> = vec![Ok(1024), Ok(1025)];
-    /// let actual: Vec<Result<u16>> = parser.get_u16s(12).collect();
-    /// assert_eq!(expected, actual);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_u16s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<u16>> + 'b
-    where
-        'b: 'a,
-    {
-        self.get_datas(search_tag).map(decode_u16)
+    /// use core::net::Ipv6Addr;
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     bld.add_ipv6(tag, Ipv6Addr::LOCALHOST);
+    /// }
+    /// assert_eq!(&[
+    ///     1,                                              // frame-format
+    ///     0, 0, 0, 1,                                     // field count
+    ///     0, 45,                                          // field-tag
+    ///     0, 0, 0, 16,                                    // field-length
+    ///     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1  // field-value
+    /// ], &data[..]);
+    /// ```
+    fn add_ipv6(&mut self, tag: u16, value: core::net::Ipv6Addr) -> (usize, usize) {
+        self.add(tag, value)
     }
 
-    /// Read u32 field from frame
-    ///
-    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
-    /// is small enough to be returned in a `u32`.
+    /// Add an IP address field to the frame, using 4 bytes for an IPv4 address
+    /// and 16 bytes for an IPv6 address.
     ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_u32(12, 1744964616);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a
-    /// // single data field (tag=12, value=1744964616)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// assert_eq!(Some(1744964616), parser.get_u32(12)?);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_u32(&self, search_tag: u16) -> Result<Option<u32>> {
-        self.decode_value(search_tag, decode_u32)
+    /// use core::net::{IpAddr, Ipv4Addr};
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     bld.add_ip(tag, IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)));
+    /// }
+    /// assert_eq!(&[
+    ///     1,            // frame-format
+    ///     0, 0, 0, 1,   // field count
+    ///     0, 45,        // field-tag
+    ///     0, 0, 0, 4,   // field-length
+    ///     192, 168, 0, 1 // field-value
+    /// ], &data[..]);
+    /// ```
+    fn add_ip(&mut self, tag: u16, value: core::net::IpAddr) -> (usize, usize) {
+        self.add(tag, value)
     }
 
-    /// Read u32 fields from frame
+    /// Add a socket address field to the frame.
     ///
-    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
-    /// is small enough to be returned in a `u32`.
+    /// The field-value is the IP address (as written by [FrameBuilderLike::add_ip])
+    /// followed by the port as a big-endian u16, irrespective of the builder's
+    /// [Endianness].  IPv6 flow information and scope ids are not written.
     ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_u32(12, 1744964616);
-    /// #     bld.add_u32(12, 1744964617);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a two fields
-    /// // (tag=12, value1=1744964616, value2=1744964617)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let expected : Vec<Result<u32>> = vec![Ok(1744964616), Ok(1744964617)];
-    /// let actual: Vec<Result<u32>> = parser.get_u32s(12).collect();
-    /// assert_eq!(expected, actual);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_u32s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<u32>> + 'b
-    where
-        'b: 'a,
-    {
-        self.get_datas(search_tag).map(decode_u32)
+    /// use core::net::{Ipv4Addr, SocketAddr};
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     bld.add_socket_addr(tag, SocketAddr::from((Ipv4Addr::new(192, 168, 0, 1), 8080)));
+    /// }
+    /// assert_eq!(&[
+    ///     1,                      // frame-format
+    ///     0, 0, 0, 1,             // field count
+    ///     0, 45,                  // field-tag
+    ///     0, 0, 0, 6,             // field-length
+    ///     192, 168, 0, 1, 31, 144 // field-value
+    /// ], &data[..]);
+    /// ```
+    fn add_socket_addr(&mut self, tag: u16, value: core::net::SocketAddr) -> (usize, usize) {
+        self.add(tag, value)
     }
 
-    /// Read u64 field from frame
+    /// Add a uuid field to the frame.
     ///
-    /// Can handle data stored a 1, 2, 4 or 8 bytes.
+    /// ```
+    /// use uuid::Uuid;
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     let data = Uuid::parse_str("3011712a-d972-4a5d-bbf9-ec5fb7525c97").unwrap();
+    ///     bld.add_uuid(tag, &data);
+    /// }
+    /// assert_eq!(&[
+    ///     1,                      // frame-format
+    ///     0, 0, 0, 1,             // field count
+    ///     0, 45,                  // field-tag
+    ///     0, 0, 0, 16,             // field-length
+    ///     48, 17, 113, 42, 217, 114, 74, 93, 187, 249, 236, 95, 183, 82, 92, 151 // field-value
+    /// ], &data[..]);
+    /// ```
+    #[cfg(feature = "uuid")]
+    fn add_uuid(&mut self, tag: u16, value: &uuid::Uuid) -> (usize, usize) {
+        self.add(tag, value)
+    }
+
+    /// Add a protobuf message field to the frame.
+    ///
+    /// The field-value is the encoded message without a length prefix, as the
+    /// field-length already gives its length, so it can be read back with
+    /// [FrameParser::get_message] or by any protobuf decoder.
     ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_u64(12, 150626523450313736);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a
-    /// // single data field (tag=12, value=150626523450313736)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// assert_eq!(Some(150626523450313736), parser.get_u64(12)?);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_u64(&self, search_tag: u16) -> Result<Option<u64>> {
-        self.decode_value(search_tag, decode_u64)
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     // a google.protobuf.StringValue
+    ///     bld.add_message(tag, &String::from("hi"));
+    /// }
+    /// assert_eq!(&[
+    ///     1,           // frame-format
+    ///     0, 0, 0, 1,  // field count
+    ///     0, 45,       // field-tag
+    ///     0, 0, 0, 4,  // field-length
+    ///     10, 2, 104, 105 // field-value
+    /// ], &data[..]);
+    /// ```
+    #[cfg(feature = "prost")]
+    fn add_message<M: prost::Message>(&mut self, tag: u16, message: &M) -> (usize, usize) {
+        self.add_with(tag, |value| {
+            value.reserve(message.encoded_len());
+            message
+                .encode(value)
+                .expect("a Vec has room for any message")
+        })
     }
 
-    /// Read u64 fields from frame
+    /// Add a duration field to the frame, as a u64 number of nanoseconds.
     ///
-    /// Can handle data stored a 1, 2, 4 or 8 bytes.
+    /// Durations longer than `u64::MAX` nanoseconds (about 584 years) can not be
+    /// represented and are reported as [Error::IncompatibleFieldValue].
     ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_u64(12, 150626523450313736);
-    /// #     bld.add_u64(12, 150626523450313737);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a two fields
-    /// // (tag=12, value1=150626523450313736, value2=150626523450313737)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let expected : Vec<Result<u64>> = vec![Ok(150626523450313736), Ok(150626523450313737)];
-    /// let actual: Vec<Result<u64>> = parser.get_u64s(12).collect();
-    /// assert_eq!(expected, actual);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_u64s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<u64>> + 'b
-    where
-        'b: 'a,
-    {
-        self.get_datas(search_tag).map(decode_u64)
+    /// use core::time::Duration;
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     bld.add_duration_nanos(tag, Duration::from_nanos(258)).unwrap();
+    /// }
+    /// assert_eq!(&[
+    ///     1,                     // frame-format
+    ///     0, 0, 0, 1,            // field count
+    ///     0, 45,                 // field-tag
+    ///     0, 0, 0, 8,            // field-length
+    ///     0, 0, 0, 0, 0, 0, 1, 2 // field-value
+    /// ], &data[..]);
+    /// ```
+    #[cfg(feature = "time")]
+    fn add_duration_nanos(
+        &mut self,
+        tag: u16,
+        value: core::time::Duration,
+    ) -> Result<(usize, usize)> {
+        let nanos = value
+            .as_nanos()
+            .try_into()
+            .map_err(|_| Error::IncompatibleFieldValue)?;
+        Ok(self.add_u64(tag, nanos))
     }
 
-    /// Read bool field from frame
+    /// Add a system time field to the frame, as a u64 number of nanoseconds since
+    /// the unix epoch.
+    ///
+    /// Times before 1970 or after 2554 can not be represented and are reported as
+    /// [Error::IncompatibleFieldValue].
     ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_bool(12, true);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a
-    /// // single data field (tag=12, value=true)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// assert_eq!(Some(true), parser.get_bool(12)?);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_bool(&self, search_tag: u16) -> Result<Option<bool>> {
-        self.decode_value(search_tag, decode_bool)
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use yatlv::{Error, FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     let tag = 45;
+    ///     bld.add_system_time(tag, UNIX_EPOCH + Duration::from_nanos(258)).unwrap();
+    ///     let before_epoch = UNIX_EPOCH - Duration::from_secs(1);
+    ///     assert_eq!(Err(Error::IncompatibleFieldValue), bld.add_system_time(tag, before_epoch));
+    /// }
+    /// assert_eq!(&[
+    ///     1,                     // frame-format
+    ///     0, 0, 0, 1,            // field count
+    ///     0, 45,                 // field-tag
+    ///     0, 0, 0, 8,            // field-length
+    ///     0, 0, 0, 0, 0, 0, 1, 2 // field-value
+    /// ], &data[..]);
+    /// ```
+    #[cfg(feature = "time")]
+    fn add_system_time(
+        &mut self,
+        tag: u16,
+        value: std::time::SystemTime,
+    ) -> Result<(usize, usize)> {
+        let since_epoch = value
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|_| Error::IncompatibleFieldValue)?;
+        self.add_duration_nanos(tag, since_epoch)
     }
 
-    /// Read bool fields from frame
+    /// Add a slice of data as a field to the frame if `value` is `Some`, otherwise
+    /// do nothing.
+    ///
+    /// This matches the optional `get_*` methods, which read an absent field as
+    /// `None`.  Returns the position of the field-value if a field was added.
+    /// Each `add_*` method that can not fail has a matching `add_optional_*` method.
     ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_bool(12, false);
-    /// #     bld.add_bool(12, true);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a two fields
-    /// // (tag=12, value1=false, value2=true)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let expected : Vec<Result<bool>> = vec![Ok(false), Ok(true)];
-    /// let actual: Vec<Result<bool>> = parser.get_bools(12).collect();
-    /// assert_eq!(expected, actual);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_bools<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<bool>> + 'b
-    where
-        'b: 'a,
-    {
-        self.get_datas(search_tag).map(decode_bool)
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     assert_eq!(Some((11, 2)), bld.add_optional_data(45, Some(&[90, 9])));
+    ///     assert_eq!(None, bld.add_optional_u32(46, None));
+    /// }
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 2, // field-length
+    ///     90, 9       // field-value
+    /// ], &data[..]);
+    /// ```
+    fn add_optional_data(&mut self, tag: u16, value: Option<&[u8]>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_data(tag, value))
     }
 
-    /// Attempt to find field-value of field that has the search_tag and then
-    /// attempts to convert it to the required type using the supplied `decoder` function.
-    fn decode_value<T, F>(&self, search_tag: u16, decoder: F) -> Result<Option<T>>
-    where
-        F: FnOnce(&[u8]) -> Result<T>,
-    {
-        self.get_data(search_tag).map(decoder).transpose()
+    /// Optional version of [FrameBuilderLike::add_bool].
+    fn add_optional_bool(&mut self, tag: u16, value: Option<bool>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_bool(tag, value))
     }
 
-    /// Read str field from frame
-    ///
-    /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_str(12, "test_str");
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a
-    /// // single data field (tag=12, value="test_str" in UTF-8)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// assert_eq!(Some("test_str"), parser.get_str(12)?);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_str(&self, search_tag: u16) -> Result<Option<&str>> {
-        self.decode_ref(search_tag, decode_str)
+    /// Optional version of [FrameBuilderLike::add_u8].
+    fn add_optional_u8(&mut self, tag: u16, value: Option<u8>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_u8(tag, value))
     }
 
-    /// Read str fields from frame
-    ///
-    /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     bld.add_str(12, "hello");
-    /// #     bld.add_str(12, "goodbye");
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a two fields
-    /// // (tag=12, value1="hello", value2="goodbye")
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let expected : Vec<Result<&str>> = vec![Ok("hello"), Ok("goodbye")];
-    /// let actual: Vec<Result<&str>> = parser.get_strs(12).collect();
-    /// assert_eq!(expected, actual);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_strs<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<&'a str>> + 'b
+    /// Optional version of [FrameBuilderLike::add_u16].
+    fn add_optional_u16(&mut self, tag: u16, value: Option<u16>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_u16(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_u32].
+    fn add_optional_u32(&mut self, tag: u16, value: Option<u32>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_u32(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_u64].
+    fn add_optional_u64(&mut self, tag: u16, value: Option<u64>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_u64(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_i8].
+    fn add_optional_i8(&mut self, tag: u16, value: Option<i8>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_i8(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_i16].
+    fn add_optional_i16(&mut self, tag: u16, value: Option<i16>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_i16(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_i32].
+    fn add_optional_i32(&mut self, tag: u16, value: Option<i32>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_i32(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_i64].
+    fn add_optional_i64(&mut self, tag: u16, value: Option<i64>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_i64(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_u128].
+    fn add_optional_u128(&mut self, tag: u16, value: Option<u128>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_u128(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_i128].
+    fn add_optional_i128(&mut self, tag: u16, value: Option<i128>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_i128(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_f32].
+    fn add_optional_f32(&mut self, tag: u16, value: Option<f32>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_f32(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_f64].
+    fn add_optional_f64(&mut self, tag: u16, value: Option<f64>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_f64(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_char].
+    fn add_optional_char(&mut self, tag: u16, value: Option<char>) -> Option<(usize, usize)> {
+        value.map(|value| self.add_char(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_str].
+    fn add_optional_str<S>(&mut self, tag: u16, value: Option<S>) -> Option<(usize, usize)>
     where
-        'b: 'a,
+        S: AsRef<str>,
     {
-        self.get_datas(search_tag).map(decode_str)
+        value.map(|value| self.add_str(tag, value))
     }
 
-    ///Read uuid field from frame
-    ///
-    /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     let uuid = uuid::Uuid::parse_str("3011712a-d972-4a5d-bbf9-ec5fb7525c97").unwrap();
-    /// #     bld.add_uuid(12, &uuid);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a
-    /// // single data field (tag=12, value=3011712a-d972-4a5d-bbf9-ec5fb7525c97)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let expected = uuid::Uuid::parse_str("3011712a-d972-4a5d-bbf9-ec5fb7525c97").unwrap();
-    /// assert_eq!(Some(expected), parser.get_uuid(12)?);
-    /// # Ok(()) }
-    ///  ```
+    /// Optional version of [FrameBuilderLike::add_ipv4].
+    fn add_optional_ipv4(
+        &mut self,
+        tag: u16,
+        value: Option<core::net::Ipv4Addr>,
+    ) -> Option<(usize, usize)> {
+        value.map(|value| self.add_ipv4(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_ipv6].
+    fn add_optional_ipv6(
+        &mut self,
+        tag: u16,
+        value: Option<core::net::Ipv6Addr>,
+    ) -> Option<(usize, usize)> {
+        value.map(|value| self.add_ipv6(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_ip].
+    fn add_optional_ip(
+        &mut self,
+        tag: u16,
+        value: Option<core::net::IpAddr>,
+    ) -> Option<(usize, usize)> {
+        value.map(|value| self.add_ip(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_socket_addr].
+    fn add_optional_socket_addr(
+        &mut self,
+        tag: u16,
+        value: Option<core::net::SocketAddr>,
+    ) -> Option<(usize, usize)> {
+        value.map(|value| self.add_socket_addr(tag, value))
+    }
+
+    /// Optional version of [FrameBuilderLike::add_uuid].
     #[cfg(feature = "uuid")]
-    pub fn get_uuid(&self, search_tag: u16) -> Result<Option<uuid::Uuid>> {
-        self.decode_value(search_tag, decode_uuid)
+    fn add_optional_uuid(
+        &mut self,
+        tag: u16,
+        value: Option<&uuid::Uuid>,
+    ) -> Option<(usize, usize)> {
+        value.map(|value| self.add_uuid(tag, value))
     }
 
-    ///Read uuid fields from frame
+    /// Add a slice of data as a field to the frame, returning the builder so that
+    /// calls can be chained.
+    ///
+    /// Each `add_*` method that can not fail has a matching `with_*` method.
     ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     let uuid = uuid::Uuid::parse_str("3011712a-d972-4a5d-bbf9-ec5fb7525c97").unwrap();
-    /// #     bld.add_uuid(12, &uuid);
-    /// #     let uuid = uuid::Uuid::parse_str("40b4e52c-1501-48d3-98eb-e2c66cb76cbf").unwrap();
-    /// #     bld.add_uuid(12, &uuid);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a two fields
-    /// // (tag=12, value1=3011712a-d972-4a5d-bbf9-ec5fb7525c97, value2=40b4e52c-1501-48d3-98eb-e2c66cb76cbf)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let expected = vec! [Ok(uuid::Uuid::parse_str("3011712a-d972-4a5d-bbf9-ec5fb7525c97").unwrap()),
-    ///                      Ok(uuid::Uuid::parse_str("40b4e52c-1501-48d3-98eb-e2c66cb76cbf").unwrap())];
-    /// let actual: Vec<Result<uuid::Uuid>> = parser.get_uuids(12).collect();
-    /// assert_eq!(expected, actual);
-    /// # Ok(()) }
-    ///  ```
-    #[cfg(feature = "uuid")]
-    pub fn get_uuids<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<uuid::Uuid>> + 'b
-    where
-        'b: 'a,
-    {
-        self.get_datas(search_tag).map(decode_uuid)
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data);
+    ///     bld.with_data(45, &[90, 9]).with_u8(46, 7).with_str(47, "x");
+    /// }
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 3, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 2, // field-length
+    ///     90, 9,      // field-value
+    ///     0, 46,      // field-tag
+    ///     0, 0, 0, 1, // field-length
+    ///     7,          // field-value
+    ///     0, 47,      // field-tag
+    ///     0, 0, 0, 1, // field-length
+    ///     120         // field-value
+    /// ], &data[..]);
+    /// ```
+    fn with_data(&mut self, tag: u16, value: &[u8]) -> &mut Self {
+        self.add_data(tag, value);
+        self
     }
 
-    /// Attempt to find field-value of field that has the search_tag and then
-    /// attempts to convert it to the required type using the supplied `decoder` function.
-    fn decode_ref<T, F>(&self, search_tag: u16, decoder: F) -> Result<Option<&T>>
+    /// Chaining version of [FrameBuilderLike::add_bool].
+    fn with_bool(&mut self, tag: u16, value: bool) -> &mut Self {
+        self.add_bool(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_u8].
+    fn with_u8(&mut self, tag: u16, value: u8) -> &mut Self {
+        self.add_u8(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_u16].
+    fn with_u16(&mut self, tag: u16, value: u16) -> &mut Self {
+        self.add_u16(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_u32].
+    fn with_u32(&mut self, tag: u16, value: u32) -> &mut Self {
+        self.add_u32(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_u64].
+    fn with_u64(&mut self, tag: u16, value: u64) -> &mut Self {
+        self.add_u64(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_i8].
+    fn with_i8(&mut self, tag: u16, value: i8) -> &mut Self {
+        self.add_i8(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_i16].
+    fn with_i16(&mut self, tag: u16, value: i16) -> &mut Self {
+        self.add_i16(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_i32].
+    fn with_i32(&mut self, tag: u16, value: i32) -> &mut Self {
+        self.add_i32(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_i64].
+    fn with_i64(&mut self, tag: u16, value: i64) -> &mut Self {
+        self.add_i64(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_u128].
+    fn with_u128(&mut self, tag: u16, value: u128) -> &mut Self {
+        self.add_u128(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_i128].
+    fn with_i128(&mut self, tag: u16, value: i128) -> &mut Self {
+        self.add_i128(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_f32].
+    fn with_f32(&mut self, tag: u16, value: f32) -> &mut Self {
+        self.add_f32(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_f64].
+    fn with_f64(&mut self, tag: u16, value: f64) -> &mut Self {
+        self.add_f64(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_str].
+    fn with_str<S>(&mut self, tag: u16, value: S) -> &mut Self
     where
-        F: FnOnce(&[u8]) -> Result<&T>,
-        T: ?Sized,
+        S: AsRef<str>,
     {
-        self.get_data(search_tag).map(decoder).transpose()
+        self.add_str(tag, value);
+        self
     }
 
-    /// Read a child frame from a frame.
+    /// Chaining version of [FrameBuilderLike::add_char].
+    fn with_char(&mut self, tag: u16, value: char) -> &mut Self {
+        self.add_char(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_ipv4].
+    fn with_ipv4(&mut self, tag: u16, value: core::net::Ipv4Addr) -> &mut Self {
+        self.add_ipv4(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_ipv6].
+    fn with_ipv6(&mut self, tag: u16, value: core::net::Ipv6Addr) -> &mut Self {
+        self.add_ipv6(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_ip].
+    fn with_ip(&mut self, tag: u16, value: core::net::IpAddr) -> &mut Self {
+        self.add_ip(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_socket_addr].
+    fn with_socket_addr(&mut self, tag: u16, value: core::net::SocketAddr) -> &mut Self {
+        self.add_socket_addr(tag, value);
+        self
+    }
+
+    /// Chaining version of [FrameBuilderLike::add_uuid].
+    #[cfg(feature = "uuid")]
+    fn with_uuid(&mut self, tag: u16, value: &uuid::Uuid) -> &mut Self {
+        self.add_uuid(tag, value);
+        self
+    }
+}
+
+/// FrameBuilder can be used to push a frame into a mutable `Vec<u8>`
+///
+/// For usage details see [FrameBuilderLike].
+///
+/// ```
+/// use yatlv::FrameBuilder;
+/// let mut data = Vec::with_capacity(100);
+/// {
+///     FrameBuilder::new(&mut data);
+/// }
+/// assert_eq!(&[
+///     1,         // frame-format
+///     0, 0, 0, 0 // field-count
+/// ], &data[..]);
+/// ```
+pub struct FrameBuilder<'a> {
+    field_count: u32,
+    field_start: usize,
+    finished: bool,
+    checked: bool,
+    large: bool,
+    #[cfg(feature = "compression")]
+    compressed: bool,
+    endianness: Endianness,
+    data: &'a mut Vec<u8>,
+}
+
+impl<'a> Drop for FrameBuilder<'a> {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.complete(DROP_CHECKS_FIELD_COUNT);
+        }
+    }
+}
+
+impl<'a> FrameBuilder<'a> {
+    pub fn new(data: &mut Vec<u8>) -> FrameBuilder<'_> {
+        FrameBuilder::new_with_endianness(data, Endianness::Big)
+    }
+
+    /// Create a FrameBuilder that encodes numbers using the given byte order.
+    ///
+    /// Child frames added to this builder use the same byte order.
     ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     let mut bld2 = bld.add_frame(12);
-    /// #     bld2.add_u8(13, 2);
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with a
-    /// // child frame (tag=12) which contains a single
-    /// // value (tag=13, value=2)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let child_parser = parser.get_frame(12)?.unwrap();
-    /// assert_eq!(Some(2), child_parser.get_u8(13)?);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_frame(&self, search_tag: u16) -> Result<Option<FrameParser>> {
-        self.get_data(search_tag).map(FrameParser::new).transpose()
+    /// use yatlv::{Endianness, FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new_with_endianness(&mut data, Endianness::Little);
+    ///     bld.add_u32(45, 7);
+    /// }
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 4, // field-length
+    ///     7, 0, 0, 0  // field-value
+    /// ], &data[..]);
+    /// ```
+    pub fn new_with_endianness(data: &mut Vec<u8>, endianness: Endianness) -> FrameBuilder<'_> {
+        let field_start = data.len();
+        data.extend_from_slice(&[1, 0, 0, 0, 0]);
+
+        FrameBuilder {
+            field_count: 0,
+            field_start,
+            finished: false,
+            checked: false,
+            large: false,
+            #[cfg(feature = "compression")]
+            compressed: false,
+            endianness,
+            data,
+        }
     }
 
-    /// Read child frames from a frame.
+    /// Create a FrameBuilder that writes frame-format `0x02`, which ends with a
+    /// CRC-32 checksum of the frame.
+    ///
+    /// The checksum is appended when the builder is finished.  Child frames are
+    /// written using frame-format `0x01`, as they are covered by the checksum of
+    /// this frame.
+    ///
     /// ```
-    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
-    /// # fn main() -> Result<()> {
-    /// # let mut frame_data = Vec::new();
-    /// # {
-    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
-    /// #     for _ in 0..2 {
-    /// #         let mut child = bld.add_frame(12);
-    /// #         child.add_u8(13, 2)
-    /// #     }
-    /// # }
-    /// #
-    /// // Assuming frame_data contains a frame with two
-    /// // child frame (tag=12) which each contains a single
-    /// // value (tag=13, value=2)
-    /// let parser = FrameParser::new(&frame_data)?;
-    /// let children: Vec<FrameParser> = parser.get_frames(12).map(|v| v.unwrap()).collect();
-    /// assert_eq!(2, children.len());
-    /// assert_eq!(Some(2), children[0].get_u8(13)?);
-    /// assert_eq!(Some(2), children[1].get_u8(13)?);
-    /// # Ok(()) }
-    ///  ```
-    pub fn get_frames<'b>(
-        &'b self,
-        search_tag: u16,
-    ) -> impl Iterator<Item = Result<FrameParser<'a>>> + 'b
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new_checked(&mut data);
+    ///     bld.add_u8(45, 7);
+    /// }
+    /// assert_eq!(&[
+    ///     2,                      // frame-format
+    ///     0, 0, 0, 1,             // field count
+    ///     0, 45,                  // field-tag
+    ///     0, 0, 0, 1,             // field-length
+    ///     7,                      // field-value
+    ///     0x67, 0x09, 0xea, 0x64  // checksum
+    /// ], &data[..]);
+    /// assert_eq!(Some(7), FrameParser::new(&data).unwrap().get_u8(45).unwrap());
+    /// ```
+    pub fn new_checked(data: &mut Vec<u8>) -> FrameBuilder<'_> {
+        let mut bld = FrameBuilder::new(data);
+        bld.data[bld.field_start] = 2;
+        bld.checked = true;
+        bld
+    }
+
+    /// Create a FrameBuilder that writes frame-format `0x05`, where the field-lengths
+    /// are 64 bit rather than 32 bit.
+    ///
+    /// This is for frames with field-values of 4GiB or more; the field-count is still
+    /// 32 bit.  Child frames are written using frame-format `0x01`, and their whole
+    /// 64 bit field-length is filled in when the child is finished, so a child frame
+    /// may also be 4GiB or more.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameFormat, FrameParser};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new_large(&mut data);
+    ///     bld.add_u8(45, 7);
+    /// }
+    /// assert_eq!(&[
+    ///     5,                      // frame-format
+    ///     0, 0, 0, 1,             // field count
+    ///     0, 45,                  // field-tag
+    ///     0, 0, 0, 0, 0, 0, 0, 1, // field-length
+    ///     7,                      // field-value
+    /// ], &data[..]);
+    /// let parser = FrameParser::new(&data).unwrap();
+    /// assert_eq!(FrameFormat::Format5, parser.format());
+    /// assert_eq!(Some(7), parser.get_u8(45).unwrap());
+    /// ```
+    pub fn new_large(data: &mut Vec<u8>) -> FrameBuilder<'_> {
+        let mut bld = FrameBuilder::new(data);
+        bld.data[bld.field_start] = 5;
+        bld.large = true;
+        bld
+    }
+
+    /// Create a FrameBuilder that writes frame-format `0x04`, a compressed frame.
+    ///
+    /// The fields are written as a frame with frame-format `0x01`, which is compressed
+    /// (using zlib) when the builder is finished.  This suits frames holding large
+    /// values that compress well, such as JSON text.  As the fields are moved by the
+    /// compression, the positions returned by the `add_*` methods are positions in
+    /// the uncompressed frame.
+    ///
+    /// Compressed frames are read with [OwnedFrameParser], which holds the
+    /// decompressed frame; [FrameParser] rejects them with [Error::InvalidFrameFormat].
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, OwnedFrameParser};
+    /// let text = "{\"key\": \"value\"}".repeat(100);
+    /// let mut data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new_compressed(&mut data);
+    ///     bld.add_str(1, &text);
+    /// }
+    /// assert_eq!(4, data[0]);
+    /// assert!(data.len() < text.len());
+    ///
+    /// let owned = OwnedFrameParser::new(data).unwrap();
+    /// assert_eq!(Some(text.as_str()), owned.parser().get_str(1).unwrap());
+    /// ```
+    #[cfg(feature = "compression")]
+    pub fn new_compressed(data: &mut Vec<u8>) -> FrameBuilder<'_> {
+        let mut bld = FrameBuilder::new(data);
+        bld.compressed = true;
+        bld
+    }
+
+    /// Create a [WideFrameBuilder], which writes frame-format `0x03` where the
+    /// field-tags are 32 bit rather than 16 bit.
+    ///
+    /// Wide frames must be read with [WideFrameParser]; [FrameParser] rejects
+    /// them with [Error::InvalidFrameFormat].
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, WideFrameParser};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new_wide_tags(&mut data);
+    ///     bld.add(0x0001_0045, 7u8);
+    /// }
+    /// assert_eq!(&[
+    ///     3,             // frame-format
+    ///     0, 0, 0, 1,    // field count
+    ///     0, 1, 0, 0x45, // field-tag
+    ///     0, 0, 0, 1,    // field-length
+    ///     7,             // field-value
+    /// ], &data[..]);
+    /// let parser = WideFrameParser::new(&data).unwrap();
+    /// assert_eq!(Some(7u8), parser.get(0x0001_0045).unwrap());
+    /// ```
+    pub fn new_wide_tags(data: &mut Vec<u8>) -> WideFrameBuilder<'_> {
+        WideFrameBuilder::new(data)
+    }
+
+    /// Create a FrameBuilder, first reserving enough space in `data` for a frame
+    /// with the expected number of fields and total field-value bytes
+    /// (see [estimate_frame_size]).
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::with_capacity(&mut data, 2, 6);
+    ///     bld.add_u32(45, 7);
+    ///     bld.add_u16(46, 8);
+    /// }
+    /// assert_eq!(23, data.len());
+    /// assert!(data.capacity() >= 23);
+    /// ```
+    pub fn with_capacity(
+        data: &mut Vec<u8>,
+        expected_fields: usize,
+        expected_value_bytes: usize,
+    ) -> FrameBuilder<'_> {
+        data.reserve(estimate_frame_size(expected_fields, expected_value_bytes));
+        FrameBuilder::new(data)
+    }
+
+    /// Create a FrameBuilder whose first field is `schema_id`, using the reserved
+    /// [SCHEMA_ID_TAG].
+    ///
+    /// This lets a frame identify the version of the message schema it was written
+    /// with, so that it can be routed by [FrameParser::schema_id] without knowing
+    /// the other fields.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = FrameBuilder::new_with_schema(&mut data, 3);
+    ///     bld.add_u8(45, 7);
+    /// }
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 2, // field count
+    ///     0, 0,       // field-tag (SCHEMA_ID_TAG)
+    ///     0, 0, 0, 4, // field-length
+    ///     0, 0, 0, 3, // field-value (schema id)
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 1, // field-length
+    ///     7           // field-value
+    /// ], &data[..]);
+    /// ```
+    pub fn new_with_schema(data: &mut Vec<u8>, schema_id: u32) -> FrameBuilder<'_> {
+        let mut bld = FrameBuilder::new(data);
+        bld.add_u32(SCHEMA_ID_TAG, schema_id);
+        bld
+    }
+
+    /// Complete the frame and return the number of bytes it occupies in the buffer.
+    ///
+    /// The field-count is written when the builder is finished.  If `finish` is
+    /// not called, the same work is done when the builder is dropped; finishing is
+    /// idempotent, so dropping a finished builder is a no-op.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// let mut bld = FrameBuilder::new(&mut data);
+    /// bld.add_u8(45, 7);
+    /// assert_eq!(12, bld.finish());
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 1, // field-length
+    ///     7           // field-value
+    /// ], &data[..]);
+    /// ```
+    pub fn finish(mut self) -> usize {
+        self.complete(true)
+    }
+
+    /// Complete the frame and write it to `writer`.
+    ///
+    /// The frame is still left in the buffer; this returns the number of bytes written.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// let mut out = Vec::new();
+    /// let mut bld = FrameBuilder::new(&mut data);
+    /// bld.add_u8(45, 7);
+    /// assert_eq!(12, bld.write_to(&mut out).unwrap());
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 1, // field-length
+    ///     7           // field-value
+    /// ], &out[..]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(mut self, writer: &mut W) -> std::io::Result<usize> {
+        let len = self.complete(true);
+        writer.write_all(&self.data[self.field_start..])?;
+        Ok(len)
+    }
+
+    /// Complete the frame and return it, without giving up the builder.
+    ///
+    /// Together with [FrameBuilder::reset] this lets one builder write a series of
+    /// frames into the same buffer, such as a pooled buffer in an encoding loop.
+    /// Once the frame is finished, `reset` must be called before adding more fields.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser};
+    /// let mut data = Vec::with_capacity(100);
+    /// let mut bld = FrameBuilder::new(&mut data);
+    /// for value in 0..3 {
+    ///     bld.reset();
+    ///     bld.add_u8(45, value);
+    ///     let frame = bld.finish_frame();
+    ///     assert_eq!(Some(value), FrameParser::new(frame).unwrap().get_u8(45).unwrap());
+    /// }
+    /// ```
+    pub fn finish_frame(&mut self) -> &[u8] {
+        self.complete(true);
+        &self.data[self.field_start..]
+    }
+
+    /// Discard the frame being built (or already finished) and start a new, empty,
+    /// frame in its place, with the same frame-format and byte order.
+    ///
+    /// Data in the buffer before the frame is kept.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, FrameBuilderLike};
+    /// let mut data = vec![9];
+    /// {
+    ///     let mut bld = FrameBuilder::new_checked(&mut data);
+    ///     bld.add_u8(45, 7);
+    ///     bld.reset();
+    ///     bld.add_u8(46, 8);
+    /// }
+    /// assert_eq!(&[
+    ///     9,                      // earlier data
+    ///     2,                      // frame-format
+    ///     0, 0, 0, 1,             // field count
+    ///     0, 46,                  // field-tag
+    ///     0, 0, 0, 1,             // field-length
+    ///     8,                      // field-value
+    ///     0x71, 0x22, 0x85, 0x5b  // checksum
+    /// ], &data[..]);
+    /// ```
+    pub fn reset(&mut self) {
+        let format = if self.checked {
+            2
+        } else if self.large {
+            5
+        } else {
+            1
+        };
+        self.data.truncate(self.field_start);
+        self.data.extend_from_slice(&[format, 0, 0, 0, 0]);
+        self.field_count = 0;
+        self.finished = false;
+    }
+
+    /// Write the field-tag and field-length of a field, returning the position of
+    /// its field-value.
+    fn push_field_header(&mut self, tag: u16, length: usize) -> usize {
+        self.field_count += 1;
+        self.data.extend_from_slice(&tag.to_be_bytes());
+        if self.large {
+            self.data.extend_from_slice(&(length as u64).to_be_bytes());
+        } else {
+            self.data.extend_from_slice(&(length as u32).to_be_bytes());
+        }
+        self.data.len() - self.field_start
+    }
+
+    /// Write the field-count (and checksum or compression) of the frame, checking
+    /// the field-count first if `check_field_count` is set.
+    fn complete(&mut self, check_field_count: bool) -> usize {
+        if !self.finished {
+            // set first, so that a failed check does not run again when dropped
+            self.finished = true;
+            if check_field_count {
+                let format = if self.large {
+                    FrameFormat::Format5
+                } else {
+                    FrameFormat::Format1
+                };
+                debug_assert_field_count(
+                    format,
+                    &self.data[self.field_start + FRAME_HEADER_BYTES..],
+                    self.field_count,
+                );
+            }
+            let field_count_pos = self.field_start + 1;
+            self.data[field_count_pos..field_count_pos + SIZE_BYTES]
+                .copy_from_slice(&self.field_count.to_be_bytes());
+            if self.checked {
+                let checksum = crc32(&self.data[self.field_start..]);
+                self.data.extend_from_slice(&checksum.to_be_bytes());
+            }
+            #[cfg(feature = "compression")]
+            if self.compressed {
+                let compressed = compress(&self.data[self.field_start..]);
+                self.data.truncate(self.field_start);
+                self.data.push(4);
+                self.data
+                    .extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+                self.data.extend_from_slice(&compressed);
+            }
+        }
+        self.data.len() - self.field_start
+    }
+}
+
+impl<'a> FrameBuilderLike for FrameBuilder<'a> {
+    fn add_data(&mut self, tag: u16, value: &[u8]) -> (usize, usize) {
+        self.data.reserve(LARGE_FIELD_HEADER_BYTES + value.len());
+        let value_start = self.push_field_header(tag, value.len());
+        self.data.extend_from_slice(value);
+        (value_start, value.len())
+    }
+
+    fn try_add_data(&mut self, tag: u16, value: &[u8]) -> Result<(usize, usize)> {
+        if !self.large {
+            check_field_length(value.len())?;
+        }
+        Ok(self.add_data(tag, value))
+    }
+
+    fn add_u8(&mut self, tag: u16, value: u8) -> (usize, usize) {
+        if self.large {
+            return self.add_data(tag, &[value]);
+        }
+        // the whole field is written at once, as this is the most common field
+        self.field_count += 1;
+        let [tag_hi, tag_lo] = tag.to_be_bytes();
+        self.data
+            .extend_from_slice(&[tag_hi, tag_lo, 0, 0, 0, 1, value]);
+        (self.data.len() - 1 - self.field_start, 1)
+    }
+
+    fn add_i8(&mut self, tag: u16, value: i8) -> (usize, usize) {
+        self.add_u8(tag, value as u8)
+    }
+
+    fn add_with<F>(&mut self, tag: u16, write: F) -> (usize, usize)
     where
-        'b: 'a,
+        F: FnOnce(&mut Vec<u8>),
+    {
+        let value_start = self.field_start + self.push_field_header(tag, 0);
+        let header_bytes = if self.large {
+            LARGE_FIELD_HEADER_BYTES
+        } else {
+            FIELD_HEADER_BYTES
+        };
+        let length = write_value_in_place(
+            self.data,
+            &mut self.finished,
+            value_start,
+            header_bytes,
+            write,
+        );
+        if self.large {
+            self.data[value_start - 8..value_start].copy_from_slice(&(length as u64).to_be_bytes());
+        } else {
+            self.data[value_start - SIZE_BYTES..value_start]
+                .copy_from_slice(&(length as u32).to_be_bytes());
+        }
+        (value_start - self.field_start, length)
+    }
+
+    fn add_data_iter<I>(&mut self, tag: u16, values: I) -> usize
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
     {
-        self.get_datas(search_tag).map(FrameParser::new)
+        let values = values.into_iter();
+        self.data.reserve(values.size_hint().0 * FIELD_HEADER_BYTES);
+        let mut count = 0;
+        for value in values {
+            self.add_data(tag, value.as_ref());
+            count += 1;
+        }
+        count
+    }
+
+    fn add_raw_field(&mut self, field: &[u8]) -> (usize, usize) {
+        debug_assert_raw_field(field);
+        if self.large {
+            let tag = u16::from_be_bytes([field[0], field[1]]);
+            return self.add_data(tag, &field[FIELD_HEADER_BYTES..]);
+        }
+        self.field_count += 1;
+        let value_start = self.data.len() + FIELD_HEADER_BYTES - self.field_start;
+        self.data.extend_from_slice(field);
+        (value_start, field.len() - FIELD_HEADER_BYTES)
+    }
+
+    fn add_frame(&mut self, tag: u16) -> PacketFrameBuilder<'_> {
+        self.field_count += 1;
+        self.data.reserve(LARGE_FIELD_HEADER_BYTES);
+        self.data.extend_from_slice(&tag.to_be_bytes());
+        if self.large {
+            // the frame-size of the child is the low half of the 64 bit field-length
+            self.data.extend_from_slice(&[0; 4]);
+        }
+        let mut bld = PacketFrameBuilder::new_with_endianness(self.data, self.endianness);
+        bld.large = self.large;
+        bld
+    }
+
+    fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+}
+
+/// PacketBuilder can be used to push a packet-frame into a mutable `Vec<u8>`
+///
+/// For usage details see [FrameBuilderLike].
+/// ```
+/// use yatlv::PacketFrameBuilder;
+/// let mut data = Vec::with_capacity(100);
+/// {
+///     PacketFrameBuilder::new(&mut data);
+/// }
+/// assert_eq!(&[
+///     0, 0, 0, 5, // packet-size
+///     1,  // frame-format
+///     0, 0, 0, 0 // field-count
+/// ], &data[..]);
+/// ```
+pub struct PacketFrameBuilder<'a> {
+    field_count: u32,
+    packet_start: usize,
+    finished: bool,
+    /// Set for a child of a large frame, whose frame-size is preceded by the high
+    /// half of its 64 bit field-length.
+    large: bool,
+    endianness: Endianness,
+    data: &'a mut Vec<u8>,
+}
+
+impl<'a> Drop for PacketFrameBuilder<'a> {
+    fn drop(&mut self) {
+        if !self.finished {
+            let result = self.complete(DROP_CHECKS_FIELD_COUNT);
+            #[cfg(feature = "std")]
+            if !std::thread::panicking() {
+                result.expect("packet-frame is too large for its frame-size");
+            }
+            #[cfg(not(feature = "std"))]
+            let _ = result;
+        }
+    }
+}
+
+impl<'a> PacketFrameBuilder<'a> {
+    pub fn new(data: &mut Vec<u8>) -> PacketFrameBuilder<'_> {
+        PacketFrameBuilder::new_with_endianness(data, Endianness::Big)
+    }
+
+    /// Create a PacketFrameBuilder that encodes numbers using the given byte order.
+    ///
+    /// Child frames added to this builder use the same byte order.
+    ///
+    /// ```
+    /// use yatlv::{Endianness, PacketFrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// {
+    ///     let mut bld = PacketFrameBuilder::new_with_endianness(&mut data, Endianness::Little);
+    ///     bld.add_u16(45, 7);
+    /// }
+    /// assert_eq!(&[
+    ///     0, 0, 0, 13, // packet-size
+    ///     1,           // frame-format
+    ///     0, 0, 0, 1,  // field count
+    ///     0, 45,       // field-tag
+    ///     0, 0, 0, 2,  // field-length
+    ///     7, 0         // field-value
+    /// ], &data[..]);
+    /// ```
+    pub fn new_with_endianness(
+        data: &mut Vec<u8>,
+        endianness: Endianness,
+    ) -> PacketFrameBuilder<'_> {
+        let packet_start = data.len();
+        data.extend_from_slice(&[0, 0, 0, 0, 1, 0, 0, 0, 0]);
+
+        PacketFrameBuilder {
+            field_count: 0,
+            packet_start,
+            finished: false,
+            large: false,
+            endianness,
+            data,
+        }
+    }
+
+    /// Complete the packet-frame and return the number of bytes it occupies in the
+    /// buffer (including the frame-size).
+    ///
+    /// The frame-size and field-count are written when the builder is finished.  If
+    /// `finish` is not called, the same work is done when the builder is dropped;
+    /// finishing is idempotent, so dropping a finished builder is a no-op.
+    ///
+    /// Panics if the frame is 4GiB or more, as its size would not fit in the frame-size;
+    /// use [PacketFrameBuilder::try_finish] to get an error instead.  A packet-frame
+    /// that is too large and is dropped without being finished is left with a
+    /// frame-size of 0, and panics unless the thread is already panicking.
+    ///
+    /// ```
+    /// use yatlv::{PacketFrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// let mut bld = PacketFrameBuilder::new(&mut data);
+    /// bld.add_u8(45, 7);
+    /// assert_eq!(16, bld.finish());
+    /// assert_eq!(&[
+    ///     0, 0, 0, 12, // packet-size
+    ///     1,           // frame-format
+    ///     0, 0, 0, 1,  // field count
+    ///     0, 45,       // field-tag
+    ///     0, 0, 0, 1,  // field-length
+    ///     7            // field-value
+    /// ], &data[..]);
+    /// ```
+    pub fn finish(mut self) -> usize {
+        self.complete(true)
+            .expect("packet-frame is too large for its frame-size")
+    }
+
+    /// Complete the packet-frame and return the number of bytes it occupies in the
+    /// buffer, or [Error::FieldTooLarge] if the frame is 4GiB or more.
+    ///
+    /// The fields and field-count are still written when the frame is too large, but
+    /// the frame-size is left as 0.
+    ///
+    /// ```
+    /// use yatlv::{PacketFrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// let mut bld = PacketFrameBuilder::new(&mut data);
+    /// bld.add_u8(45, 7);
+    /// assert_eq!(Ok(16), bld.try_finish());
+    /// ```
+    pub fn try_finish(mut self) -> Result<usize> {
+        self.complete(true)
+    }
+
+    /// Complete the packet-frame and write it to `writer`.
+    ///
+    /// The packet-frame is still left in the buffer; this returns the number of bytes
+    /// written.  A packet-frame of 4GiB or more fails with an error of kind
+    /// [std::io::ErrorKind::InvalidInput], without being written.
+    ///
+    /// ```
+    /// use yatlv::{PacketFrameBuilder, FrameBuilderLike};
+    /// let mut data = Vec::with_capacity(100);
+    /// let mut out = Vec::new();
+    /// let mut bld = PacketFrameBuilder::new(&mut data);
+    /// bld.add_u8(45, 7);
+    /// assert_eq!(16, bld.write_to(&mut out).unwrap());
+    /// assert_eq!(&[
+    ///     0, 0, 0, 12, // packet-size
+    ///     1,           // frame-format
+    ///     0, 0, 0, 1,  // field count
+    ///     0, 45,       // field-tag
+    ///     0, 0, 0, 1,  // field-length
+    ///     7            // field-value
+    /// ], &out[..]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(mut self, writer: &mut W) -> std::io::Result<usize> {
+        let len = self
+            .complete(true)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+        writer.write_all(&self.data[self.packet_start..])?;
+        Ok(len)
+    }
+
+    /// Write the frame-size and field-count of the packet-frame, checking the
+    /// field-count first if `check_field_count` is set.
+    ///
+    /// Fails with [Error::FieldTooLarge] if the frame-size does not fit in a u32 (and
+    /// this is not the child of a large frame).
+    fn complete(&mut self, check_field_count: bool) -> Result<usize> {
+        let frame_size = self.data.len() - self.packet_start - SIZE_BYTES;
+        if !self.finished {
+            // set first, so that a failed check does not run again when dropped
+            self.finished = true;
+            if check_field_count {
+                debug_assert_field_count(
+                    FrameFormat::Format1,
+                    &self.data[self.packet_start + SIZE_BYTES + FRAME_HEADER_BYTES..],
+                    self.field_count,
+                );
+            }
+            if self.large {
+                let length_pos = self.packet_start - SIZE_BYTES;
+                self.data[length_pos..self.packet_start + SIZE_BYTES]
+                    .copy_from_slice(&(frame_size as u64).to_be_bytes());
+            } else if let Ok(packet_length) = check_field_length(frame_size) {
+                self.data[self.packet_start..self.packet_start + SIZE_BYTES]
+                    .copy_from_slice(&packet_length.to_be_bytes());
+            }
+
+            let field_count_pos = self.packet_start + 5;
+            self.data[field_count_pos..field_count_pos + SIZE_BYTES]
+                .copy_from_slice(&self.field_count.to_be_bytes());
+        }
+        if !self.large {
+            check_field_length(frame_size)?;
+        }
+        Ok(frame_size + SIZE_BYTES)
+    }
+}
+
+impl<'a> FrameBuilderLike for PacketFrameBuilder<'a> {
+    fn add_data(&mut self, tag: u16, value: &[u8]) -> (usize, usize) {
+        self.field_count += 1;
+        self.data.reserve(6 + value.len());
+        self.data.extend_from_slice(&tag.to_be_bytes());
+        self.data
+            .extend_from_slice(&(value.len() as u32).to_be_bytes());
+        let value_start = self.data.len() - self.packet_start;
+        self.data.extend_from_slice(value);
+        (value_start, value.len())
+    }
+
+    fn add_u8(&mut self, tag: u16, value: u8) -> (usize, usize) {
+        // the whole field is written at once, as this is the most common field
+        self.field_count += 1;
+        let [tag_hi, tag_lo] = tag.to_be_bytes();
+        self.data
+            .extend_from_slice(&[tag_hi, tag_lo, 0, 0, 0, 1, value]);
+        (self.data.len() - 1 - self.packet_start, 1)
+    }
+
+    fn add_i8(&mut self, tag: u16, value: i8) -> (usize, usize) {
+        self.add_u8(tag, value as u8)
+    }
+
+    fn add_with<F>(&mut self, tag: u16, write: F) -> (usize, usize)
+    where
+        F: FnOnce(&mut Vec<u8>),
+    {
+        self.field_count += 1;
+        self.data.extend_from_slice(&tag.to_be_bytes());
+        let length_pos = self.data.len();
+        self.data.extend_from_slice(&[0; SIZE_BYTES]);
+        let value_start = self.data.len();
+        let length = write_value_in_place(
+            self.data,
+            &mut self.finished,
+            value_start,
+            FIELD_HEADER_BYTES,
+            write,
+        );
+        self.data[length_pos..value_start].copy_from_slice(&(length as u32).to_be_bytes());
+        (value_start - self.packet_start, length)
+    }
+
+    fn add_data_iter<I>(&mut self, tag: u16, values: I) -> usize
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let values = values.into_iter();
+        self.data.reserve(values.size_hint().0 * FIELD_HEADER_BYTES);
+        let mut count = 0;
+        for value in values {
+            self.add_data(tag, value.as_ref());
+            count += 1;
+        }
+        count
+    }
+
+    fn add_raw_field(&mut self, field: &[u8]) -> (usize, usize) {
+        debug_assert_raw_field(field);
+        self.field_count += 1;
+        let value_start = self.data.len() + FIELD_HEADER_BYTES - self.packet_start;
+        self.data.extend_from_slice(field);
+        (value_start, field.len() - FIELD_HEADER_BYTES)
+    }
+
+    fn add_frame(&mut self, tag: u16) -> PacketFrameBuilder<'_> {
+        self.field_count += 1;
+        self.data.reserve(6);
+        self.data.extend_from_slice(&tag.to_be_bytes());
+        PacketFrameBuilder::new_with_endianness(self.data, self.endianness)
+    }
+
+    fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+}
+
+/// Library Error Type
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The frame must start with a single format byte.
+    IncompleteFrameFormat,
+
+    /// The frame format must be one of the recognized formats (currently `0x01` to `0x05`).
+    InvalidFrameFormat(u32),
+
+    /// The frame must have a four byte field-count that indicates the number fields
+    /// in the frame (encoded as big-endian u32)
+    IncompleteFrameFieldCount,
+
+    /// Each field must start with a two byte tag (big-endian u16) and a
+    /// four byte length (big-endian u32).
+    IncompleteFieldTagOrLength,
+
+    /// Each field must have a value that is field-length long
+    /// This error as expected and actual lengths.
+    IncompleteFieldValue(usize, usize),
+
+    /// When converting a field to an expected type of value the field length
+    /// must be compatible with the expected value type.
+    IncompatibleFieldLength(usize),
+
+    /// When converting a field into an expected type the value of the field
+    /// must be compatible with the expected type.
+    IncompatibleFieldValue,
+
+    /// Once all the fields have been read there should be no more data in the
+    /// frame.
+    UnexpectedData,
+
+    /// A required field was not present in the frame.
+    /// This error has the tag of the missing field.
+    MissingField(u16),
+
+    /// The frame declared more fields than allowed by [ParseOptions::max_field_count].
+    /// This error has the declared field-count.
+    FieldCountTooLarge(u32),
+
+    /// A child frame was nested deeper than allowed by [ParseOptions::max_depth].
+    /// This error has the depth of the rejected frame.
+    MaxDepthExceeded(u32),
+
+    /// A tag appeared more than once in a frame parsed with
+    /// [ParseOptions::reject_duplicate_tags].  This error has the repeated tag.
+    DuplicateTag(u16),
+
+    /// A [SliceFrameBuilder] did not have room in its buffer for the field being added.
+    BufferFull,
+
+    /// A frame with frame-format `0x02` must end with a four byte checksum
+    /// (encoded as big-endian u32).
+    IncompleteFrameChecksum,
+
+    /// The checksum at the end of a frame with frame-format `0x02` did not match
+    /// its contents.  `expected` is the checksum stored in the frame and `actual`
+    /// is the checksum calculated from the frame.
+    ChecksumMismatch { expected: u32, actual: u32 },
+
+    /// A field-value was too long for its length to be encoded as a field-length, or
+    /// a packet-frame too long for its frame-size.  This error has the length of the
+    /// field-value or frame.
+    FieldTooLarge(usize),
+
+    /// A packet-frame must start with a four byte frame-size (encoded as big-endian u32).
+    IncompleteFrameSize,
+
+    /// A packet-frame must have a frame that is frame-size long.
+    /// This error has the expected and actual lengths.
+    IncompletePacketFrame(usize, usize),
+
+    /// A packet-frame declared a frame-size larger than allowed by
+    /// [ParseOptions::max_frame_size].
+    FrameTooLarge { declared: u32, limit: u32 },
+
+    /// A frame with frame-format `0x04` must have compressed-length bytes of
+    /// compressed frame.  This error has the expected and actual lengths.
+    IncompleteCompressedFrame(usize, usize),
+
+    /// The compressed frame of a frame with frame-format `0x04` could not be
+    /// decompressed.
+    InvalidCompressedFrame,
+
+    /// The compressed frame of a frame with frame-format `0x04` decompressed to more
+    /// than [ParseOptions::max_decompressed_size] bytes.  Decompression stops at the limit, so
+    /// the decompressed size is not known; this error has the limit.
+    DecompressedFrameTooLarge(u32),
+
+    /// The frame-size of a packet-frame read by [PacketFrameParser] did not match the
+    /// length of the data that followed it.  This error has the declared frame-size and
+    /// the actual length.
+    FrameSizeMismatch { declared: usize, actual: usize },
+
+    /// A [Tag] was created from a field-tag reserved by one of this crate's
+    /// conventions (such as [SCHEMA_ID_TAG]).  This error has the reserved tag.
+    ReservedTag(u16),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::IncompleteFrameFormat => write!(f, "incomplete frame-format"),
+            Error::InvalidFrameFormat(format) => write!(f, "invalid frame-format {}", format),
+            Error::IncompleteFrameFieldCount => write!(f, "incomplete field-count"),
+            Error::IncompleteFieldTagOrLength => write!(f, "incomplete field-tag or field-length"),
+            Error::IncompleteFieldValue(expected, actual) => write!(
+                f,
+                "incomplete field-value (expected {} bytes, found {})",
+                expected, actual
+            ),
+            Error::IncompatibleFieldLength(length) => {
+                write!(f, "incompatible field-length {}", length)
+            }
+            Error::IncompatibleFieldValue => write!(f, "incompatible field-value"),
+            Error::UnexpectedData => write!(f, "unexpected data after last field"),
+            Error::MissingField(tag) => write!(f, "missing field with tag {}", tag),
+            Error::FieldCountTooLarge(count) => write!(f, "field-count {} is too large", count),
+            Error::MaxDepthExceeded(depth) => write!(f, "frame depth {} is too deep", depth),
+            Error::DuplicateTag(tag) => write!(f, "duplicate field with tag {}", tag),
+            Error::BufferFull => write!(f, "buffer is full"),
+            Error::IncompleteFrameChecksum => write!(f, "incomplete checksum"),
+            Error::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch (expected {:08x}, found {:08x})",
+                expected, actual
+            ),
+            Error::FieldTooLarge(length) => {
+                write!(f, "field-value of {} bytes is too large", length)
+            }
+            Error::IncompleteFrameSize => write!(f, "incomplete frame-size"),
+            Error::IncompletePacketFrame(expected, actual) => write!(
+                f,
+                "incomplete packet-frame (expected {} bytes, found {})",
+                expected, actual
+            ),
+            Error::FrameTooLarge { declared, limit } => write!(
+                f,
+                "frame-size {} is larger than the limit of {} bytes",
+                declared, limit
+            ),
+            Error::IncompleteCompressedFrame(expected, actual) => write!(
+                f,
+                "incomplete compressed frame (expected {} bytes, found {})",
+                expected, actual
+            ),
+            Error::InvalidCompressedFrame => write!(f, "invalid compressed frame"),
+            Error::DecompressedFrameTooLarge(limit) => write!(
+                f,
+                "decompressed frame is larger than the limit of {} bytes",
+                limit
+            ),
+            Error::FrameSizeMismatch { declared, actual } => write!(
+                f,
+                "frame-size mismatch (declared {} bytes, found {})",
+                declared, actual
+            ),
+            Error::ReservedTag(tag) => write!(f, "field-tag {} is reserved", tag),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// An [Error] together with the offset, from the start of the frame, of the bytes
+/// that caused it.
+///
+/// This is returned by [validate_frame_with_offset], to help find the problem in a
+/// hex dump of a malformed frame.  The offset is the start of the part of the frame
+/// that could not be read: the frame-format, the field-count, a field (for a missing
+/// field-tag or field-length), a field-value, the checksum or the unexpected data.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ErrorAt {
+    /// The offset of the bytes that caused the error.
+    pub offset: usize,
+    /// The error.
+    pub error: Error,
+}
+
+impl core::fmt::Display for ErrorAt {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at offset {}", self.error, self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ErrorAt {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Library Result Type
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[derive(Clone)]
+struct FrameParserField<'a> {
+    tag: u16,
+    value: &'a [u8],
+}
+
+/// FrameParser can be used to access field encoded as a frame.
+#[derive(Clone)]
+pub struct FrameParser<'a> {
+    /// The encoded frame, used to find the offsets of field-values.
+    frame_data: &'a [u8],
+    fields: Vec<FrameParserField<'a>>,
+    format: FrameFormat,
+    options: ParseOptions,
+    depth: u32,
+    index: Option<FieldIndex>,
+}
+
+/// Map from a tag to a field position: a hash map where there is `std`, otherwise
+/// a BTreeMap.
+#[cfg(feature = "std")]
+type TagPositions = std::collections::HashMap<u16, usize>;
+#[cfg(not(feature = "std"))]
+type TagPositions = BTreeMap<u16, usize>;
+
+/// Positions of fields by tag, see [ParseOptions::indexed].
+#[derive(Clone)]
+struct FieldIndex {
+    /// Position of the first field with each tag.
+    first: TagPositions,
+    /// Position of the next field with the same tag as the field at each position.
+    next: Vec<Option<usize>>,
+}
+
+/// The frame-format of a frame, as read from its first byte.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FrameFormat {
+    /// Frame-format `0x01`, the fields without a checksum.
+    Format1,
+    /// Frame-format `0x02`, the fields followed by a checksum
+    /// (see [FrameBuilder::new_checked]).
+    Format2,
+    /// Frame-format `0x03`, the fields with 32 bit field-tags
+    /// (see [FrameBuilder::new_wide_tags]).
+    ///
+    /// These frames can only be read with [WideFrameParser].
+    Format3,
+    /// Frame-format `0x04`, a compressed frame with frame-format `0x01`.
+    ///
+    /// These frames are written by `FrameBuilder::new_compressed` and can only be
+    /// read by [OwnedFrameParser], which holds the decompressed frame (both need the
+    /// `compression` feature).
+    Format4,
+    /// Frame-format `0x05`, the fields with 64 bit field-lengths
+    /// (see [FrameBuilder::new_large]).
+    Format5,
+}
+
+fn read_frame_format(data: &[u8]) -> Result<(FrameFormat, &[u8])> {
+    if !data.is_empty() {
+        let (field_count_bytes, tail) = data.split_at(1);
+        let raw_format = field_count_bytes[0];
+        let format = match raw_format {
+            0x01 => Ok(FrameFormat::Format1),
+            0x02 => Ok(FrameFormat::Format2),
+            0x03 => Ok(FrameFormat::Format3),
+            0x04 => Ok(FrameFormat::Format4),
+            0x05 => Ok(FrameFormat::Format5),
+            _ => Err(Error::InvalidFrameFormat(raw_format as u32)),
+        }?;
+        Ok((format, tail))
+    } else {
+        Err(Error::IncompleteFrameFormat)
+    }
+}
+
+fn read_frame_field_count(data: &[u8]) -> Result<(u32, &[u8])> {
+    if data.len() >= 4 {
+        let (field_count_bytes, tail) = data.split_at(4);
+        let field_count = u32::from_be_bytes(field_count_bytes.try_into().unwrap());
+        Ok((field_count, tail))
+    } else {
+        Err(Error::IncompleteFrameFieldCount)
+    }
+}
+
+/// Check that a field-value of `length` bytes can be encoded.
+fn check_field_length(length: usize) -> Result<u32> {
+    u32::try_from(length).map_err(|_| Error::FieldTooLarge(length))
+}
+
+/// Check that `body` is long enough to hold `field_count` fields, before reading any.
+///
+/// Each field takes at least `header_bytes`, so a larger field-count can not be
+/// valid and is rejected without reserving space for the fields.
+fn check_field_count(field_count: u32, body: &[u8], header_bytes: usize) -> Result<()> {
+    if field_count as usize > body.len() / header_bytes {
+        Err(Error::IncompleteFieldTagOrLength)
+    } else {
+        Ok(())
+    }
+}
+
+/// Whether a builder checks its field-count when it is dropped without being finished.
+///
+/// A panic while adding a field can leave a partly written field behind, so the check
+/// must be skipped while unwinding.  That can only be detected with `std`; without it
+/// the field-count is only checked when a builder is finished explicitly.
+const DROP_CHECKS_FIELD_COUNT: bool = cfg!(feature = "std");
+
+/// Check (in debug builds) that `body` holds exactly `field_count` fields, before a
+/// builder writes the field-count into the frame.
+///
+/// The check is skipped while unwinding (see [DROP_CHECKS_FIELD_COUNT]).
+fn debug_assert_field_count(format: FrameFormat, body: &[u8], field_count: u32) {
+    #[cfg(feature = "std")]
+    if std::thread::panicking() {
+        return;
+    }
+    if cfg!(debug_assertions) {
+        let mut body = body;
+        let mut count = 0;
+        while let Ok((_, length, tail)) = read_field_header(format, body) {
+            match read_field_value(tail, length) {
+                Ok((_, tail)) => body = tail,
+                Err(_) => break,
+            }
+            count += 1;
+        }
+        assert!(
+            body.is_empty() && count == field_count,
+            "field-count {} does not match the fields written",
+            field_count
+        );
+    }
+}
+
+/// Let the `write` of an `add_with` append a field-value to the buffer of a builder,
+/// returning the length of the field-value.
+///
+/// Panics if `write` did not only append, by removing bytes or changing the
+/// `header_bytes` of field-tag and field-length before `value_start`.  The builder is
+/// marked as `finished` until `write` is known to have only appended, so that a
+/// builder whose buffer was broken is not completed when it is dropped.
+fn write_value_in_place<F>(
+    data: &mut Vec<u8>,
+    finished: &mut bool,
+    value_start: usize,
+    header_bytes: usize,
+    write: F,
+) -> usize
+where
+    F: FnOnce(&mut Vec<u8>),
+{
+    let mut header = [0; LARGE_FIELD_HEADER_BYTES];
+    let header = &mut header[..header_bytes];
+    header.copy_from_slice(&data[value_start - header_bytes..value_start]);
+    let was_finished = core::mem::replace(finished, true);
+    write(data);
+    assert!(
+        data.len() >= value_start && data[value_start - header_bytes..value_start] == *header,
+        "add_with must only append to the Vec it is given"
+    );
+    *finished = was_finished;
+    data.len() - value_start
+}
+
+/// Check (in debug builds) that `field` holds exactly one encoded field.
+fn debug_assert_raw_field(field: &[u8]) {
+    debug_assert!(
+        matches!(read_field_tag_and_length(field), Ok((_, length, value)) if length == value.len()),
+        "not a single encoded field"
+    );
+}
+
+fn read_field_tag_and_length(data: &[u8]) -> Result<(u16, usize, &[u8])> {
+    if data.len() >= FIELD_HEADER_BYTES {
+        let (tag_bytes, tail) = data.split_at(2);
+        let tag = u16::from_be_bytes(tag_bytes.try_into().unwrap());
+        let (length_bytes, tail) = tail.split_at(4);
+        let length = u32::from_be_bytes(length_bytes.try_into().unwrap()) as usize;
+        Ok((tag, length, tail))
+    } else {
+        Err(Error::IncompleteFieldTagOrLength)
+    }
+}
+
+fn read_large_field_tag_and_length(data: &[u8]) -> Result<(u16, usize, &[u8])> {
+    if data.len() >= LARGE_FIELD_HEADER_BYTES {
+        let (tag_bytes, tail) = data.split_at(2);
+        let tag = u16::from_be_bytes(tag_bytes.try_into().unwrap());
+        let (length_bytes, tail) = tail.split_at(8);
+        let length = u64::from_be_bytes(length_bytes.try_into().unwrap());
+        // a length that does not fit in a usize can not fit in the data either
+        let length = usize::try_from(length).unwrap_or(usize::MAX);
+        Ok((tag, length, tail))
+    } else {
+        Err(Error::IncompleteFieldTagOrLength)
+    }
+}
+
+/// Read the field-tag and field-length of a field in a frame with `format`, which
+/// must have 16 bit field-tags.
+fn read_field_header(format: FrameFormat, data: &[u8]) -> Result<(u16, usize, &[u8])> {
+    if format == FrameFormat::Format5 {
+        read_large_field_tag_and_length(data)
+    } else {
+        read_field_tag_and_length(data)
+    }
+}
+
+/// Number of bytes taken by a field-tag and field-length in a frame with `format`.
+fn field_header_bytes(format: FrameFormat) -> usize {
+    match format {
+        FrameFormat::Format3 => WIDE_FIELD_HEADER_BYTES,
+        FrameFormat::Format5 => LARGE_FIELD_HEADER_BYTES,
+        _ => FIELD_HEADER_BYTES,
+    }
+}
+
+fn read_wide_field_tag_and_length(data: &[u8]) -> Result<(u32, usize, &[u8])> {
+    if data.len() >= WIDE_FIELD_HEADER_BYTES {
+        let (tag_bytes, tail) = data.split_at(4);
+        let tag = u32::from_be_bytes(tag_bytes.try_into().unwrap());
+        let (length_bytes, tail) = tail.split_at(4);
+        let length = u32::from_be_bytes(length_bytes.try_into().unwrap()) as usize;
+        Ok((tag, length, tail))
+    } else {
+        Err(Error::IncompleteFieldTagOrLength)
+    }
+}
+
+/// Read the compressed-length and compressed frame of a frame with frame-format `0x04`.
+fn read_compressed_frame(data: &[u8]) -> Result<(&[u8], &[u8])> {
+    if data.len() < SIZE_BYTES {
+        return Err(Error::IncompleteCompressedFrame(SIZE_BYTES, data.len()));
+    }
+    let (length_bytes, tail) = data.split_at(SIZE_BYTES);
+    let length = u32::from_be_bytes(length_bytes.try_into().unwrap()) as usize;
+    if tail.len() < length {
+        return Err(Error::IncompleteCompressedFrame(length, tail.len()));
+    }
+    Ok(tail.split_at(length))
+}
+
+/// Read the frame-size and frame of a packet-frame, returning the frame and the data
+/// that follows the packet-frame.
+fn read_packet_frame(data: &[u8], max_frame_size: u32) -> Result<(&[u8], &[u8])> {
+    if data.len() < SIZE_BYTES {
+        return Err(Error::IncompleteFrameSize);
+    }
+    let (size_bytes, tail) = data.split_at(SIZE_BYTES);
+    let frame_size = u32::from_be_bytes(size_bytes.try_into().unwrap());
+    if frame_size > max_frame_size {
+        return Err(Error::FrameTooLarge {
+            declared: frame_size,
+            limit: max_frame_size,
+        });
+    }
+    let frame_size = frame_size as usize;
+    if tail.len() < frame_size {
+        return Err(Error::IncompletePacketFrame(frame_size, tail.len()));
+    }
+    Ok(tail.split_at(frame_size))
+}
+
+fn read_field_value(data: &[u8], field_length: usize) -> Result<(&[u8], &[u8])> {
+    if data.len() >= field_length {
+        Ok(data.split_at(field_length))
+    } else {
+        Err(Error::IncompleteFieldValue(field_length, data.len()))
+    }
+}
+
+/// Read the end of a frame that started at the beginning of `data`, where `body` is
+/// what follows its last field.
+fn read_frame_end<'d>(format: FrameFormat, data: &[u8], body: &'d [u8]) -> Result<&'d [u8]> {
+    match format {
+        FrameFormat::Format1
+        | FrameFormat::Format3
+        | FrameFormat::Format4
+        | FrameFormat::Format5 => Ok(body),
+        FrameFormat::Format2 if body.len() >= SIZE_BYTES => {
+            let (checksum_bytes, tail) = body.split_at(SIZE_BYTES);
+            let expected = u32::from_be_bytes(checksum_bytes.try_into().unwrap());
+            let actual = crc32(&data[..data.len() - body.len()]);
+            if expected == actual {
+                Ok(tail)
+            } else {
+                Err(Error::ChecksumMismatch { expected, actual })
+            }
+        }
+        FrameFormat::Format2 => Err(Error::IncompleteFrameChecksum),
+    }
+}
+
+/// Lookup table for [crc32], one entry per byte value.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Compress a frame with frame-format `0x01`, giving the compressed-frame of a frame
+/// with frame-format `0x04`.
+#[cfg(feature = "compression")]
+fn compress(frame: &[u8]) -> Vec<u8> {
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(frame)
+        .expect("writing to a Vec can not fail");
+    encoder.finish().expect("writing to a Vec can not fail")
+}
+
+/// Decompress the compressed-frame of a frame with frame-format `0x04`, failing with
+/// [Error::DecompressedFrameTooLarge] if the decompressed frame is larger than
+/// `max_size`.
+#[cfg(feature = "compression")]
+fn decompress(compressed: &[u8], max_size: u32) -> Result<Vec<u8>> {
+    use std::io::Read;
+
+    let mut frame = Vec::new();
+    flate2::read::ZlibDecoder::new(compressed)
+        .take(max_size as u64 + 1)
+        .read_to_end(&mut frame)
+        .map_err(|_| Error::InvalidCompressedFrame)?;
+    if frame.len() > max_size as usize {
+        return Err(Error::DecompressedFrameTooLarge(max_size));
+    }
+    Ok(frame)
+}
+
+/// Encode `value` as lowercase hex, two digits per byte.
+fn encode_hex(value: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut hex = String::with_capacity(value.len() * 2);
+    for b in value {
+        hex.push(DIGITS[(b >> 4) as usize] as char);
+        hex.push(DIGITS[(b & 0xF) as usize] as char);
+    }
+    hex
+}
+
+/// Calculate the CRC-32 (as used by zlib and ethernet) of `data`.
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |crc, b| {
+        (crc >> 8) ^ CRC32_TABLE[((crc ^ *b as u32) & 0xFF) as usize]
+    })
+}
+
+/// Find the number of bytes taken by the frame at the start of `data`.
+///
+/// This walks the field headers without collecting the fields, so is cheaper than
+/// [FrameParser::parse_prefix] when only the boundary of the frame is needed.
+/// The checksum of a frame with frame-format `0x02` is still verified.
+///
+/// ```
+/// # use yatlv::{frame_encoded_len, FrameBuilder, FrameBuilderLike, Error};
+/// let mut data = Vec::new();
+/// FrameBuilder::new(&mut data).add_u32(12, 1);
+/// let frame_len = data.len();
+/// data.extend_from_slice(&[1, 0]);
+///
+/// assert_eq!(Ok(frame_len), frame_encoded_len(&data));
+/// assert_eq!(Err(Error::IncompleteFrameFieldCount), frame_encoded_len(&data[frame_len..]));
+/// ```
+pub fn frame_encoded_len(data: &[u8]) -> Result<usize> {
+    let (format, body) = read_frame_format(data)?;
+    if format == FrameFormat::Format4 {
+        let (compressed, _) = read_compressed_frame(body)?;
+        return Ok(1 + SIZE_BYTES + compressed.len());
+    }
+    let (field_count, mut body) = read_frame_field_count(body)?;
+    check_field_count(field_count, body, field_header_bytes(format))?;
+    for _ in 0..field_count {
+        let (length, tail) = if format == FrameFormat::Format3 {
+            read_wide_field_tag_and_length(body).map(|(_, length, tail)| (length, tail))?
+        } else {
+            read_field_header(format, body).map(|(_, length, tail)| (length, tail))?
+        };
+        let (_, tail) = read_field_value(tail, length)?;
+        body = tail;
+    }
+    let body = read_frame_end(format, data, body)?;
+    Ok(data.len() - body.len())
+}
+
+/// Whether data holds a frame or a packet-frame, see [detect_frame_kind].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameKind {
+    /// A frame, starting with its frame-format.
+    Frame,
+    /// A packet-frame, starting with its frame-size.
+    PacketFrame,
+}
+
+/// Work out whether `data` holds exactly one frame or exactly one packet-frame.
+///
+/// `data` is a frame if it starts with a known frame-format and the encoded length of
+/// the frame is the length of `data`.  It is a packet-frame if its frame-size is the
+/// length of the rest of `data`, and the rest is such a frame.  The frame-size of a
+/// packet-frame would have to be at least 16MiB for it to start with a known
+/// frame-format, so data is rarely both; when it is, or when it is neither, `prefer`
+/// decides.  If it is neither, the error from reading it as `prefer` is returned.
+///
+/// ```
+/// # use yatlv::{detect_frame_kind, FrameBuilder, FrameBuilderLike, FrameKind, PacketFrameBuilder, Error};
+/// let mut frame = Vec::new();
+/// FrameBuilder::new(&mut frame).add_u8(12, 1);
+/// let mut packet_frame = Vec::new();
+/// PacketFrameBuilder::new(&mut packet_frame).add_u8(12, 1);
+///
+/// assert_eq!(Ok(FrameKind::Frame), detect_frame_kind(&frame, FrameKind::PacketFrame));
+/// assert_eq!(Ok(FrameKind::PacketFrame), detect_frame_kind(&packet_frame, FrameKind::Frame));
+/// assert_eq!(
+///     Err(Error::FrameSizeMismatch { declared: 12, actual: 11 }),
+///     detect_frame_kind(&packet_frame[..15], FrameKind::PacketFrame)
+/// );
+/// ```
+pub fn detect_frame_kind(data: &[u8], prefer: FrameKind) -> Result<FrameKind> {
+    let as_frame = || match frame_encoded_len(data) {
+        Ok(len) if len == data.len() => Ok(FrameKind::Frame),
+        Ok(_) => Err(Error::UnexpectedData),
+        Err(e) => Err(e),
+    };
+    let as_packet_frame = || {
+        if data.len() < SIZE_BYTES {
+            return Err(Error::IncompleteFrameSize);
+        }
+        let (size_bytes, frame_data) = data.split_at(SIZE_BYTES);
+        let frame_size = u32::from_be_bytes(size_bytes.try_into().unwrap()) as usize;
+        if frame_size != frame_data.len() {
+            return Err(Error::FrameSizeMismatch {
+                declared: frame_size,
+                actual: frame_data.len(),
+            });
+        }
+        match frame_encoded_len(frame_data)? {
+            len if len == frame_data.len() => Ok(FrameKind::PacketFrame),
+            _ => Err(Error::UnexpectedData),
+        }
+    };
+    match prefer {
+        FrameKind::Frame => as_frame().or_else(|e| as_packet_frame().map_err(|_| e)),
+        FrameKind::PacketFrame => as_packet_frame().or_else(|e| as_frame().map_err(|_| e)),
+    }
+}
+
+/// Check that `data` holds exactly one well-formed frame, without collecting its fields.
+///
+/// This performs the same structural checks as [FrameParser::new] (field-lengths,
+/// checksum and trailing data) but does not allocate, so it can be used as a cheap
+/// gate before handing data on.  Field-values are not checked; use
+/// [validate_frame_tree] to also check child frames.
+///
+/// ```
+/// # use yatlv::{validate_frame, FrameBuilder, FrameBuilderLike, Error};
+/// let mut data = Vec::new();
+/// FrameBuilder::new(&mut data).add_u32(12, 1);
+/// assert_eq!(Ok(()), validate_frame(&data));
+/// assert_eq!(Err(Error::IncompleteFieldValue(4, 3)), validate_frame(&data[..data.len() - 1]));
+///
+/// data.push(0);
+/// assert_eq!(Err(Error::UnexpectedData), validate_frame(&data));
+/// ```
+pub fn validate_frame(data: &[u8]) -> Result<()> {
+    parse_with_visitor(data, |_, _| Ok(()))
+}
+
+/// Check that `data` holds exactly one well-formed frame, in the same way as
+/// [validate_frame], reporting where in `data` the first error was found.
+///
+/// ```
+/// # use yatlv::{validate_frame_with_offset, ErrorAt, FrameBuilder, FrameBuilderLike, Error};
+/// let mut data = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new(&mut data);
+///     bld.add_u8(1, 10);
+///     bld.add_u32(2, 20);
+/// }
+/// assert_eq!(Ok(()), validate_frame_with_offset(&data));
+///
+/// // the second field-value starts after the frame header (5 bytes), the first
+/// // field (6 + 1 bytes) and the second field's header (6 bytes)
+/// let err = validate_frame_with_offset(&data[..data.len() - 1]).unwrap_err();
+/// assert_eq!(ErrorAt { offset: 18, error: Error::IncompleteFieldValue(4, 3) }, err);
+/// assert_eq!("incomplete field-value (expected 4 bytes, found 3) at offset 18", err.to_string());
+/// ```
+pub fn validate_frame_with_offset(data: &[u8]) -> core::result::Result<(), ErrorAt> {
+    let tail = walk_fields(data, |_, _| Ok(()))?;
+    if tail.is_empty() {
+        Ok(())
+    } else {
+        Err(ErrorAt {
+            offset: data.len() - tail.len(),
+            error: Error::UnexpectedData,
+        })
+    }
+}
+
+/// Check that `data` holds a well-formed frame, and that the field-values with any of
+/// `child_tags` are also well-formed frames, to at most `max_depth` levels below
+/// the root frame.
+///
+/// The same `child_tags` are used at every level.  The first error found is returned,
+/// with a child frame nested too deeply reported as [Error::MaxDepthExceeded].
+///
+/// ```
+/// # use yatlv::{validate_frame_tree, FrameBuilder, FrameBuilderLike, Error};
+/// const CHILD: u16 = 1;
+/// const NAME: u16 = 2;
+///
+/// let mut data = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new(&mut data);
+///     bld.add_str(NAME, "root");
+///     bld.add_frame(CHILD).add_frame(CHILD).add_str(NAME, "grandchild");
+/// }
+/// assert_eq!(Ok(()), validate_frame_tree(&data, &[CHILD], 2));
+/// assert_eq!(Err(Error::MaxDepthExceeded(2)), validate_frame_tree(&data, &[CHILD], 1));
+/// // "root" is not a frame
+/// assert_eq!(Err(Error::InvalidFrameFormat(b'r' as u32)), validate_frame_tree(&data, &[NAME], 2));
+/// ```
+pub fn validate_frame_tree(data: &[u8], child_tags: &[u16], max_depth: u32) -> Result<()> {
+    fn validate(data: &[u8], child_tags: &[u16], depth: u32, max_depth: u32) -> Result<()> {
+        parse_with_visitor(data, |tag, value| {
+            if !child_tags.contains(&tag) {
+                Ok(())
+            } else if depth < max_depth {
+                validate(value, child_tags, depth + 1, max_depth)
+            } else {
+                Err(Error::MaxDepthExceeded(depth + 1))
+            }
+        })
+    }
+    validate(data, child_tags, 0, max_depth)
+}
+
+/// Read a frame, calling `visitor` with the tag and value of each field in turn,
+/// without collecting the fields.
+///
+/// This is the allocation-free counterpart of [FrameParser::new], for callers that
+/// only need to scan the fields once, such as a push-style protocol state machine.
+/// The frame is checked in the same way as by [FrameParser::new]; the fields are
+/// visited as they are read, so some may be visited before an error later in the
+/// frame is found.  An error returned by `visitor` stops the walk and is returned.
+///
+/// ```
+/// # use yatlv::{parse_with_visitor, FrameBuilder, FrameBuilderLike, Error, Result};
+/// # fn main() -> Result<()> {
+/// let mut data = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new(&mut data);
+///     bld.add_u8(1, 10);
+///     bld.add_u8(2, 20);
+///     bld.add_u8(1, 30);
+/// }
+///
+/// let mut total = 0;
+/// parse_with_visitor(&data, |tag, value| {
+///     if tag == 1 {
+///         total += value[0] as u32;
+///     }
+///     Ok(())
+/// })?;
+/// assert_eq!(40, total);
+///
+/// data.push(0);
+/// assert_eq!(Err(Error::UnexpectedData), parse_with_visitor(&data, |_, _| Ok(())));
+/// # Ok(()) }
+/// ```
+pub fn parse_with_visitor<'d, F>(data: &'d [u8], visitor: F) -> Result<()>
+where
+    F: FnMut(u16, &'d [u8]) -> Result<()>,
+{
+    let tail = walk_fields(data, visitor).map_err(|e| e.error)?;
+    if tail.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::UnexpectedData)
+    }
+}
+
+/// Read the frame at the start of `data`, calling `visit` with the tag and value of
+/// each field, and return the data that follows the frame.
+///
+/// Errors from `visit` are given the offset of the field that was visited.
+fn walk_fields<'d, F>(data: &'d [u8], mut visit: F) -> core::result::Result<&'d [u8], ErrorAt>
+where
+    F: FnMut(u16, &'d [u8]) -> Result<()>,
+{
+    let at = |rest: &[u8]| {
+        let offset = data.len() - rest.len();
+        move |error| ErrorAt { offset, error }
+    };
+    let (format, body) = read_frame_format(data).map_err(at(data))?;
+    if format == FrameFormat::Format3 || format == FrameFormat::Format4 {
+        return Err(at(data)(Error::InvalidFrameFormat(data[0] as u32)));
+    }
+    let (field_count, mut body) = read_frame_field_count(body).map_err(at(body))?;
+    check_field_count(field_count, body, field_header_bytes(format)).map_err(at(body))?;
+    for _ in 0..field_count {
+        let (tag, length, tail) = read_field_header(format, body).map_err(at(body))?;
+        let (value, tail) = read_field_value(tail, length).map_err(at(tail))?;
+        visit(tag, value).map_err(at(body))?;
+        body = tail;
+    }
+    read_frame_end(format, data, body).map_err(at(body))
+}
+
+fn index_fields(fields: &[FrameParserField]) -> FieldIndex {
+    let mut first = TagPositions::new();
+    let mut next = vec![None; fields.len()];
+    for (pos, field) in fields.iter().enumerate().rev() {
+        next[pos] = first.insert(field.tag, pos);
+    }
+    FieldIndex { first, next }
+}
+
+impl<'a> FrameParser<'a> {
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=[4, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: &[u8] = &[4, 5];
+    /// assert_eq!(Some(expected), parser.get_data(12));
+    /// # Ok(()) }
+    ///  ```
+    pub fn new(frame_data: &[u8]) -> Result<FrameParser<'_>> {
+        FrameParser::new_with_endianness(frame_data, Endianness::Big)
+    }
+
+    /// Create a FrameParser that decodes numbers using the given byte order.
+    ///
+    /// Child frames read from this parser use the same byte order.
+    ///
+    /// ```
+    /// # use yatlv::{Endianness, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new_with_endianness(&mut frame_data, Endianness::Little);
+    /// #     bld.add_u16(12, 1024);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single
+    /// // little-endian data field (tag=12, value=1024)
+    /// let parser = FrameParser::new_with_endianness(&frame_data, Endianness::Little)?;
+    /// assert_eq!(Endianness::Little, parser.endianness());
+    /// assert_eq!(Some(1024), parser.get_u32(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn new_with_endianness(
+        frame_data: &[u8],
+        endianness: Endianness,
+    ) -> Result<FrameParser<'_>> {
+        FrameParser::new_with_options(
+            frame_data,
+            ParseOptions {
+                endianness,
+                ..ParseOptions::default()
+            },
+        )
+    }
+
+    /// Create a FrameParser using the given [ParseOptions].
+    ///
+    /// Child frames read from this parser use the same options.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, ParseOptions};
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u8(1, 1);
+    /// #     bld.add_u8(2, 2);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two fields
+    /// let options = ParseOptions {
+    ///     max_field_count: 1,
+    ///     ..ParseOptions::default()
+    /// };
+    /// let result = FrameParser::new_with_options(&frame_data, options);
+    /// assert_eq!(Some(Error::FieldCountTooLarge(2)), result.err());
+    ///  ```
+    pub fn new_with_options(frame_data: &[u8], options: ParseOptions) -> Result<FrameParser<'_>> {
+        FrameParser::parse(frame_data, options, 0)
+    }
+
+    /// Create a FrameParser that indexes its fields by tag (see [ParseOptions::indexed]).
+    ///
+    /// Child frames read from this parser are also indexed.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     for tag in 0..256 {
+    /// #         bld.add_u16(tag, tag);
+    /// #     }
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with 256 fields (tag=n, value=n)
+    /// let parser = FrameParser::new_indexed(&frame_data)?;
+    /// assert!(parser.options().indexed);
+    /// assert_eq!(Some(255), parser.get_u16(255)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn new_indexed(frame_data: &[u8]) -> Result<FrameParser<'_>> {
+        FrameParser::new_with_options(
+            frame_data,
+            ParseOptions {
+                indexed: true,
+                ..ParseOptions::default()
+            },
+        )
+    }
+
+    fn parse(frame_data: &[u8], options: ParseOptions, depth: u32) -> Result<FrameParser<'_>> {
+        let (parser, tail) = FrameParser::parse_prefix_with(frame_data, options, depth)?;
+        if tail.is_empty() {
+            Ok(parser)
+        } else {
+            Err(Error::UnexpectedData)
+        }
+    }
+
+    /// Parse the frame at the start of `data`, returning the parser and the data
+    /// that follows the frame.
+    ///
+    /// Unlike [FrameParser::new], trailing data is not an error, so this can be used
+    /// to read a sequence of frames from a single buffer.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut data = Vec::new();
+    /// # FrameBuilder::new(&mut data).add_u8(12, 1);
+    /// # FrameBuilder::new(&mut data).add_u8(12, 2);
+    /// #
+    /// // Assuming data contains two frames, each with a single data field (tag=12)
+    /// let (parser1, tail) = FrameParser::parse_prefix(&data)?;
+    /// let (parser2, tail) = FrameParser::parse_prefix(tail)?;
+    /// assert_eq!(Some(1), parser1.get_u8(12)?);
+    /// assert_eq!(Some(2), parser2.get_u8(12)?);
+    /// assert!(tail.is_empty());
+    /// # Ok(()) }
+    ///  ```
+    pub fn parse_prefix(data: &[u8]) -> Result<(FrameParser<'_>, &[u8])> {
+        FrameParser::parse_prefix_with(data, ParseOptions::default(), 0)
+    }
+
+    /// Parse the packet-frame at the start of `data`, returning the parser and the
+    /// number of bytes the packet-frame takes (its frame-size plus four).
+    ///
+    /// This reads the packet-frames written by [PacketFrameBuilder].  Data after the
+    /// packet-frame is ignored, so the returned length can be used to move on to the
+    /// next one.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilderLike, PacketFrameBuilder, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut data = Vec::new();
+    /// PacketFrameBuilder::new(&mut data).add_u8(12, 1);
+    /// PacketFrameBuilder::new(&mut data).add_u8(12, 2);
+    ///
+    /// let (parser1, len1) = FrameParser::from_packet(&data)?;
+    /// let (parser2, len2) = FrameParser::from_packet(&data[len1..])?;
+    /// assert_eq!(Some(1), parser1.get_u8(12)?);
+    /// assert_eq!(Some(2), parser2.get_u8(12)?);
+    /// assert_eq!(data.len(), len1 + len2);
+    ///
+    /// assert_eq!(Some(Error::IncompletePacketFrame(12, 11)), FrameParser::from_packet(&data[..15]).err());
+    /// # Ok(()) }
+    ///  ```
+    pub fn from_packet(data: &[u8]) -> Result<(FrameParser<'_>, usize)> {
+        let options = ParseOptions::default();
+        let (frame_data, tail) = read_packet_frame(data, options.max_frame_size)?;
+        let parser = FrameParser::parse(frame_data, options, 0)?;
+        Ok((parser, data.len() - tail.len()))
+    }
+
+    fn parse_prefix_with(
+        data: &[u8],
+        options: ParseOptions,
+        depth: u32,
+    ) -> Result<(FrameParser<'_>, &[u8])> {
+        let (format, body) = read_frame_format(data)?;
+        if format == FrameFormat::Format3 || format == FrameFormat::Format4 {
+            // wide frames have a different field layout (see WideFrameParser) and
+            // compressed frames must be decompressed first (see OwnedFrameParser)
+            return Err(Error::InvalidFrameFormat(data[0] as u32));
+        }
+        let (field_count, mut body) = read_frame_field_count(body)?;
+        if field_count > options.max_field_count {
+            return Err(Error::FieldCountTooLarge(field_count));
+        }
+        check_field_count(field_count, body, field_header_bytes(format))?;
+        let mut fields = Vec::with_capacity(field_count as usize);
+        for _ in 0..field_count {
+            let (tag, length, tail) = read_field_header(format, body)?;
+            let (value, tail) = read_field_value(tail, length)?;
+            fields.push(FrameParserField { tag, value });
+            body = tail
+        }
+        let body = read_frame_end(format, data, body)?;
+        if options.reject_duplicate_tags {
+            let mut tags = BTreeSet::new();
+            if let Some(field) = fields.iter().find(|f| !tags.insert(f.tag)) {
+                return Err(Error::DuplicateTag(field.tag));
+            }
+        }
+        let index = if options.indexed {
+            Some(index_fields(&fields))
+        } else {
+            None
+        };
+        let parser = FrameParser {
+            frame_data: &data[..data.len() - body.len()],
+            fields,
+            format,
+            options,
+            depth,
+            index,
+        };
+        Ok((parser, body))
+    }
+
+    /// The byte order used to decode numbers.
+    pub fn endianness(&self) -> Endianness {
+        self.options.endianness
+    }
+
+    /// The options this frame was parsed with.
+    pub fn options(&self) -> ParseOptions {
+        self.options
+    }
+
+    /// How deeply this frame is nested; the root frame has depth 0.
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// The frame-format this frame was encoded with.
+    ///
+    /// ```
+    /// # use yatlv::{FrameBuilder, FrameFormat, FrameParser, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut plain_data = Vec::new();
+    /// FrameBuilder::new(&mut plain_data);
+    /// let mut checked_data = Vec::new();
+    /// FrameBuilder::new_checked(&mut checked_data);
+    ///
+    /// assert_eq!(FrameFormat::Format1, FrameParser::new(&plain_data)?.format());
+    /// assert_eq!(FrameFormat::Format2, FrameParser::new(&checked_data)?.format());
+    /// # Ok(()) }
+    /// ```
+    pub fn format(&self) -> FrameFormat {
+        self.format
+    }
+
+    /// The encoded frame, as it was parsed.
+    ///
+    /// ```
+    /// # use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut data = Vec::new();
+    /// FrameBuilder::new(&mut data).add_u8(12, 1);
+    /// let frame_len = data.len();
+    /// data.push(9);
+    ///
+    /// let (parser, _) = FrameParser::parse_prefix(&data)?;
+    /// assert_eq!(&data[..frame_len], parser.raw_bytes());
+    /// # Ok(()) }
+    /// ```
+    pub fn raw_bytes(&self) -> &'a [u8] {
+        self.frame_data
+    }
+
+    /// Read the schema id of a frame written by [FrameBuilder::new_with_schema],
+    /// which is the u32 field with the reserved [SCHEMA_ID_TAG].
+    ///
+    /// ```
+    /// # use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut data = Vec::new();
+    /// FrameBuilder::new_with_schema(&mut data, 3).add_u8(45, 7);
+    /// let parser = FrameParser::new(&data)?;
+    /// match parser.schema_id()? {
+    ///     Some(3) => assert_eq!(Some(7), parser.get_u8(45)?),
+    ///     other => panic!("unexpected schema {:?}", other),
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn schema_id(&self) -> Result<Option<u32>> {
+        self.get_u32(SCHEMA_ID_TAG)
+    }
+
+    /// Wrap a big-endian number `decoder` so that it honours this frame's byte order.
+    fn number<T>(&self, decoder: fn(&[u8]) -> Result<T>) -> impl Fn(&[u8]) -> Result<T> + Copy {
+        let endianness = self.options.endianness;
+        move |value| endianness.decode(value, decoder)
+    }
+
+    /// Parse a field-value as a child frame, inheriting this frame's settings.
+    fn parse_child(&self, frame_data: &'a [u8]) -> Result<FrameParser<'a>> {
+        let depth = self.depth + 1;
+        if depth > self.options.max_depth {
+            return Err(Error::MaxDepthExceeded(depth));
+        }
+        FrameParser::parse(frame_data, self.options, depth)
+    }
+
+    /// Read field from frame.
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=[4, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: &[u8] = &[4, 5];
+    /// assert_eq!(Some(expected), parser.get_data(12));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_data(&self, search_tag: u16) -> Option<&'a [u8]> {
+        if let Some(index) = &self.index {
+            return index
+                .first
+                .get(&search_tag)
+                .map(|pos| self.fields[*pos].value);
+        }
+        for field in &self.fields {
+            if field.tag == search_tag {
+                return Some(field.value);
+            }
+        }
+        None
+    }
+
+    /// Read required field from frame.
+    ///
+    /// Behaves like [FrameParser::get_data] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=[4, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: &[u8] = &[4, 5];
+    /// assert_eq!(expected, parser.get_data_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_data_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_data_required(&self, search_tag: u16) -> Result<&'a [u8]> {
+        self.get_data(search_tag)
+            .ok_or(Error::MissingField(search_tag))
+    }
+
+    /// Read a field that must appear exactly once in the frame.
+    ///
+    /// Behaves like [FrameParser::get_data_required] except that more than one field
+    /// with `search_tag` is reported as [Error::DuplicateTag].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// #     bld.add_data(14, &[6]);
+    /// #     bld.add_data(14, &[7]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with three data fields
+    /// // (tag=12, value=[4, 5]), (tag=14, value=[6]) and (tag=14, value=[7])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: &[u8] = &[4, 5];
+    /// assert_eq!(expected, parser.get_data_unique(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_data_unique(13));
+    /// assert_eq!(Err(Error::DuplicateTag(14)), parser.get_data_unique(14));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_data_unique(&self, search_tag: u16) -> Result<&'a [u8]> {
+        let (first, second) = match &self.index {
+            Some(index) => match index.first.get(&search_tag) {
+                Some(&pos) => (Some(pos), index.next[pos]),
+                None => (None, None),
+            },
+            None => {
+                let mut positions =
+                    (0..self.fields.len()).filter(|&pos| self.fields[pos].tag == search_tag);
+                (positions.next(), positions.next())
+            }
+        };
+        match (first, second) {
+            (Some(pos), None) => Ok(self.fields[pos].value),
+            (None, _) => Err(Error::MissingField(search_tag)),
+            (Some(_), Some(_)) => Err(Error::DuplicateTag(search_tag)),
+        }
+    }
+
+    /// Read field from frame, or an empty slice if there is no field with `search_tag`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=[4, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: &[u8] = &[4, 5];
+    /// assert_eq!(expected, parser.get_data_or_default(12));
+    /// assert!(parser.get_data_or_default(13).is_empty());
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_data_or_default(&self, search_tag: u16) -> &'a [u8] {
+        self.get_data(search_tag).unwrap_or_default()
+    }
+
+    /// Read a fixed-size field (such as a hash or key) from frame as a byte array.
+    ///
+    /// A field-value that is not exactly `N` bytes long is reported as
+    /// [Error::IncompatibleFieldLength].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5, 6]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=[4, 5, 6])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some([4, 5, 6]), parser.get_array(12)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_array::<4>(12));
+    /// assert_eq!(None, parser.get_array::<3>(13)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_array<const N: usize>(&self, search_tag: u16) -> Result<Option<[u8; N]>> {
+        self.decode_value(search_tag, decode_array)
+    }
+
+    /// Read required fixed-size field from frame as a byte array.
+    ///
+    /// Behaves like [FrameParser::get_array] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5, 6]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=[4, 5, 6])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let value: [u8; 3] = parser.get_array_required(12)?;
+    /// assert_eq!([4, 5, 6], value);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_array_required::<3>(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_array_required<const N: usize>(&self, search_tag: u16) -> Result<[u8; N]> {
+        self.get_data_required(search_tag).and_then(decode_array)
+    }
+
+    /// Find where the value of the first field with `search_tag` is in the frame.
+    ///
+    /// The range is relative to the start of the data this frame was parsed from (for
+    /// a child frame, the start of the child frame).  This can be used to build an
+    /// index of field-values in a large buffer, such as a memory-mapped file.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// #     bld.add_data(13, &[6]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=[4, 5]) and (tag=13, value=[6])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(11..13), parser.get_data_range(12));
+    /// assert_eq!(Some(19..20), parser.get_data_range(13));
+    /// assert_eq!(&[6], &frame_data[19..20]);
+    /// assert_eq!(None, parser.get_data_range(14));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_data_range(&self, search_tag: u16) -> Option<core::ops::Range<usize>> {
+        self.get_data(search_tag)
+            .map(|value| self.value_range(value))
+    }
+
+    /// Read the encoded bytes (field-tag, field-length and field-value) of the first
+    /// field with `search_tag`, as they appear in the frame.
+    ///
+    /// This lets selected fields be fed to a hasher, or copied, without encoding
+    /// them again.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=[4, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: &[u8] = &[0, 12, 0, 0, 0, 2, 4, 5];
+    /// assert_eq!(Some(expected), parser.field_raw(12));
+    /// assert_eq!(None, parser.field_raw(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn field_raw(&self, search_tag: u16) -> Option<&'a [u8]> {
+        self.get_data(search_tag)
+            .map(|value| self.encoded_field(value))
+    }
+
+    /// Read the encoded bytes of all fields with `search_tag`, as they appear in the
+    /// frame (see [FrameParser::field_raw]).
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// #     bld.add_data(13, &[6]);
+    /// #     bld.add_data(12, &[]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a three fields
+    /// // (tag=12, value=[4, 5]), (tag=13, value=[6]), (tag=12, value=[])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: Vec<&[u8]> = vec![&[0, 12, 0, 0, 0, 2, 4, 5], &[0, 12, 0, 0, 0, 0]];
+    /// assert_eq!(expected, parser.field_raws(12).collect::<Vec<_>>());
+    /// # Ok(()) }
+    ///  ```
+    pub fn field_raws<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = &'a [u8]> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag)
+            .map(move |value| self.encoded_field(value))
+    }
+
+    /// The position of a field-value (which must be from this frame) in the frame.
+    fn value_range(&self, value: &[u8]) -> core::ops::Range<usize> {
+        let start = value.as_ptr() as usize - self.frame_data.as_ptr() as usize;
+        start..start + value.len()
+    }
+
+    /// The encoded field for a field-value (which must be from this frame).
+    fn encoded_field(&self, value: &[u8]) -> &'a [u8] {
+        let range = self.value_range(value);
+        &self.frame_data[range.start - field_header_bytes(self.format)..range.end]
+    }
+
+    /// Read fields from frame.
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// #     bld.add_data(12, &[3, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=[4, 5], value2=[3, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected = vec![&[4, 5], &[3, 5]];
+    /// let actual: Vec<&[u8]> =  parser.get_datas(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_datas<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = &'a [u8]>
+    where
+        'b: 'a,
+    {
+        // only one of these yields fields, depending on whether the frame is indexed
+        let (first, next, unindexed) = match &self.index {
+            Some(index) => (
+                index.first.get(&search_tag).copied(),
+                &index.next[..],
+                &[][..],
+            ),
+            None => (None, &[][..], &self.fields[..]),
+        };
+        core::iter::successors(first, move |pos| next[*pos])
+            .map(move |pos| self.fields[pos].value)
+            .chain(
+                unindexed
+                    .iter()
+                    .filter(move |f| f.tag == search_tag)
+                    .map(|f| f.value),
+            )
+    }
+
+    /// Read field from frame as an owned `Vec<u8>`.
+    ///
+    /// Unlike [FrameParser::get_data] this copies (and so allocates) the field-value,
+    /// which allows it to outlive the parser.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=[4, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(vec![4, 5]), parser.get_bytes(12));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_bytes(&self, search_tag: u16) -> Option<Vec<u8>> {
+        self.get_data(search_tag).map(|v| v.to_vec())
+    }
+
+    /// Read fields from frame as owned `Vec<u8>`s.
+    ///
+    /// Unlike [FrameParser::get_datas] this copies (and so allocates) each field-value,
+    /// which allows them to outlive the parser.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// #     bld.add_data(12, &[3, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=[4, 5], value2=[3, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let actual: Vec<Vec<u8>> = parser.get_bytes_all(12).collect();
+    /// assert_eq!(vec![vec![4, 5], vec![3, 5]], actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_bytes_all<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Vec<u8>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(|v| v.to_vec())
+    }
+
+    /// Read all fields from frame.
+    ///
+    /// The fields are returned in the order they were encoded, including
+    /// any repeated tags.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// #     bld.add_data(13, &[6]);
+    /// #     bld.add_data(12, &[3, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a three fields
+    /// // (tag=12, value=[4, 5]), (tag=13, value=[6]), (tag=12, value=[3, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: Vec<(u16, &[u8])> = vec![(12, &[4, 5]), (13, &[6]), (12, &[3, 5])];
+    /// let actual: Vec<(u16, &[u8])> = parser.fields().collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn fields<'b>(&'b self) -> impl Iterator<Item = (u16, &'a [u8])> + 'b
+    where
+        'b: 'a,
+    {
+        self.fields.iter().map(|f| (f.tag, f.value))
+    }
+
+    /// Read the tags and values of the fields whose tag matches `predicate`, in the
+    /// order they were added.
+    ///
+    /// This generalizes [FrameParser::get_datas] to a set of tags, such as a range
+    /// reserved for extensions.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(101, &[4, 5]);
+    /// #     bld.add_data(13, &[6]);
+    /// #     bld.add_data(100, &[3, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a three fields
+    /// // (tag=101, value=[4, 5]), (tag=13, value=[6]), (tag=100, value=[3, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: Vec<(u16, &[u8])> = vec![(101, &[4, 5]), (100, &[3, 5])];
+    /// let actual: Vec<(u16, &[u8])> = parser
+    ///     .get_data_where(|tag| (100..200).contains(&tag))
+    ///     .collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_data_where<'b, P>(
+        &'b self,
+        predicate: P,
+    ) -> impl Iterator<Item = (u16, &'a [u8])> + 'b
+    where
+        'b: 'a,
+        P: Fn(u16) -> bool + 'b,
+    {
+        self.fields().filter(move |(tag, _)| predicate(*tag))
+    }
+
+    /// Collect the fields into a map from tag to the values of the fields with that
+    /// tag, in the order they were added.
+    ///
+    /// This suits code that inspects many tags and would rather own the lookup
+    /// structure than use [ParseOptions::indexed].  The map is a [BTreeMap], so it
+    /// is also available without the `std` feature; it allocates in proportion to
+    /// the number of fields.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// #     bld.add_data(13, &[6]);
+    /// #     bld.add_data(12, &[3, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a three fields
+    /// // (tag=12, value=[4, 5]), (tag=13, value=[6]), (tag=12, value=[3, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let map = parser.to_map();
+    /// assert_eq!(2, map.len());
+    /// assert_eq!(vec![&[4, 5], &[3, 5]], map[&12]);
+    /// assert_eq!(vec![&[6]], map[&13]);
+    /// # Ok(()) }
+    ///  ```
+    pub fn to_map(&self) -> BTreeMap<u16, Vec<&'a [u8]>> {
+        let mut map: BTreeMap<u16, Vec<&'a [u8]>> = BTreeMap::new();
+        for field in &self.fields {
+            map.entry(field.tag).or_default().push(field.value);
+        }
+        map
+    }
+
+    /// Read the field at `index` (counting from zero), whatever its tag.
+    ///
+    /// This is useful for positional frames, where the meaning of a field depends on
+    /// its position rather than its tag.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// #     bld.add_data(13, &[6]);
+    /// #     bld.add_data(12, &[3, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a three fields
+    /// // (tag=12, value=[4, 5]), (tag=13, value=[6]), (tag=12, value=[3, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: (u16, &[u8]) = (12, &[3, 5]);
+    /// assert_eq!(Some(expected), parser.nth(2));
+    /// assert_eq!(None, parser.nth(3));
+    /// # Ok(()) }
+    ///  ```
+    pub fn nth(&self, index: usize) -> Option<(u16, &'a [u8])> {
+        self.fields.get(index).map(|f| (f.tag, f.value))
+    }
+
+    /// The number of fields in the frame (its field-count).
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut frame_data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut frame_data);
+    ///     bld.add_u8(12, 1);
+    ///     bld.add_u8(12, 2);
+    /// }
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(2, parser.len());
+    /// assert!(!parser.is_empty());
+    /// # Ok(()) }
+    ///  ```
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Whether the frame has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// The total length of the field-values of the frame, not counting any headers.
+    ///
+    /// Child frames are counted as field-values, so their headers are included.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut frame_data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut frame_data);
+    ///     bld.add_str(1, "hello");
+    ///     bld.add_u16(2, 3);
+    /// }
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(7, parser.value_bytes());
+    /// assert_eq!(24, parser.total_bytes());
+    /// # Ok(()) }
+    ///  ```
+    pub fn value_bytes(&self) -> usize {
+        self.fields.iter().map(|field| field.value.len()).sum()
+    }
+
+    /// The length of the encoded frame, including its headers (and checksum).
+    ///
+    /// This is the same as `raw_bytes().len()`.
+    pub fn total_bytes(&self) -> usize {
+        self.frame_data.len()
+    }
+
+    /// Compare the fields of two frames, ignoring the order they were added in.
+    ///
+    /// The frames are equal if they contain the same `(tag, value)` pairs, the same
+    /// number of times.  Field-values that differ byte-for-byte are still equal if
+    /// both can be parsed as child frames which are themselves equal by this method.
+    /// The frame-format (and so any checksum) is not compared.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut data1 = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data1);
+    ///     bld.add_u8(1, 10);
+    ///     bld.add_u8(2, 20);
+    /// }
+    /// let mut data2 = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut data2);
+    ///     bld.add_u8(2, 20);
+    ///     bld.add_u8(1, 10);
+    /// }
+    /// assert_ne!(data1, data2);
+    ///
+    /// let frame1 = FrameParser::new(&data1)?;
+    /// let frame2 = FrameParser::new(&data2)?;
+    /// assert!(frame1.semantic_eq(&frame2));
+    /// # Ok(()) }
+    /// ```
+    pub fn semantic_eq(&self, other: &FrameParser<'_>) -> bool {
+        if self.fields.len() != other.fields.len() {
+            return false;
+        }
+        // each field of this frame must be matched by a different field of the other
+        let mut matched = vec![false; other.fields.len()];
+        self.fields.iter().all(|field| {
+            let found = other.fields.iter().enumerate().position(|(idx, o)| {
+                !matched[idx] && o.tag == field.tag && self.values_eq(field.value, other, o.value)
+            });
+            match found {
+                Some(idx) => {
+                    matched[idx] = true;
+                    true
+                }
+                None => false,
+            }
+        })
+    }
+
+    fn values_eq<'b>(
+        &self,
+        value: &'a [u8],
+        other: &FrameParser<'b>,
+        other_value: &'b [u8],
+    ) -> bool {
+        if value == other_value {
+            return true;
+        }
+        match (self.parse_child(value), other.parse_child(other_value)) {
+            (Ok(child), Ok(other_child)) => child.semantic_eq(&other_child),
+            _ => false,
+        }
+    }
+
+    /// Read the distinct tags used in frame.
+    ///
+    /// Each tag is returned once, in the order it first appears in the frame.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[4, 5]);
+    /// #     bld.add_data(13, &[6]);
+    /// #     bld.add_data(12, &[3, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a three fields
+    /// // (tag=12, value=[4, 5]), (tag=13, value=[6]), (tag=12, value=[3, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let actual: Vec<u16> = parser.tags().collect();
+    /// assert_eq!(vec![12, 13], actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn tags<'b>(&'b self) -> impl Iterator<Item = u16> + 'b
+    where
+        'b: 'a,
+    {
+        self.fields
+            .iter()
+            .enumerate()
+            .filter(move |(idx, f)| self.fields[..*idx].iter().all(|p| p.tag != f.tag))
+            .map(|(_, f)| f.tag)
+    }
+
+    /// Read u8 field from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
+    /// is small enough to be returned in a `u8`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u8(12, 9);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=9)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(9), parser.get_u8(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u8(&self, search_tag: u16) -> Result<Option<u8>> {
+        self.decode_value(search_tag, decode_u8)
+    }
+
+    /// Read required u8 field from frame
+    ///
+    /// Behaves like [FrameParser::get_u8] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u8(12, 9);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=9)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(9, parser.get_u8_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_u8_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u8_required(&self, search_tag: u16) -> Result<u8> {
+        self.get_data_required(search_tag).and_then(decode_u8)
+    }
+
+    /// Read u8 field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_u8] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u8(12, 9);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=9), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(9, parser.get_u8_or(12, 7)?);
+    /// assert_eq!(7, parser.get_u8_or(13, 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_u8_or(14, 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u8_or(&self, search_tag: u16, default: u8) -> Result<u8> {
+        self.get_u8(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read u8 field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_u8] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u8(12, 9);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=9), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(9, parser.get_u8_or_else(12, || 7)?);
+    /// assert_eq!(7, parser.get_u8_or_else(13, || 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_u8_or_else(14, || 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u8_or_else<F>(&self, search_tag: u16, default: F) -> Result<u8>
+    where
+        F: FnOnce() -> u8,
+    {
+        self.get_u8(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read u8 fields from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
+    /// is small enough to be returned in a `u8`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u8(12, 6);
+    /// #     bld.add_u8(12, 9);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=6, value2=9)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<u8>> = vec![Ok(6), Ok(9)];
+    /// let actual: Vec<Result<u8>> = parser.get_u8s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u8s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<u8>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(decode_u8)
+    }
+
+    /// Read u16 field from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
+    /// is small enough to be returned in a `u16`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u16(12, 1024);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=1024)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(1024), parser.get_u16(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u16(&self, search_tag: u16) -> Result<Option<u16>> {
+        self.decode_value(search_tag, self.number(decode_u16))
+    }
+
+    /// Read required u16 field from frame
+    ///
+    /// Behaves like [FrameParser::get_u16] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u16(12, 1024);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=1024)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1024, parser.get_u16_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_u16_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u16_required(&self, search_tag: u16) -> Result<u16> {
+        self.get_data_required(search_tag)
+            .and_then(self.number(decode_u16))
+    }
+
+    /// Read u16 field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_u16] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u16(12, 1024);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=1024), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1024, parser.get_u16_or(12, 7)?);
+    /// assert_eq!(7, parser.get_u16_or(13, 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_u16_or(14, 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u16_or(&self, search_tag: u16, default: u16) -> Result<u16> {
+        self.get_u16(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read u16 field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_u16] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u16(12, 1024);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=1024), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1024, parser.get_u16_or_else(12, || 7)?);
+    /// assert_eq!(7, parser.get_u16_or_else(13, || 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_u16_or_else(14, || 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u16_or_else<F>(&self, search_tag: u16, default: F) -> Result<u16>
+    where
+        F: FnOnce() -> u16,
+    {
+        self.get_u16(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read u16 fields from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
+    /// is small enough to be returned in a `u16`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u16(12, 1024);
+    /// #     bld.add_u16(12, 1025);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=1024, value2=1025)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<u16>> = vec![Ok(1024), Ok(1025)];
+    /// let actual: Vec<Result<u16>> = parser.get_u16s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u16s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<u16>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(self.number(decode_u16))
+    }
+
+    /// Read u32 field from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
+    /// is small enough to be returned in a `u32`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u32(12, 1744964616);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=1744964616)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(1744964616), parser.get_u32(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u32(&self, search_tag: u16) -> Result<Option<u32>> {
+        self.decode_value(search_tag, self.number(decode_u32))
+    }
+
+    /// Read required u32 field from frame
+    ///
+    /// Behaves like [FrameParser::get_u32] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u32(12, 1744964616);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=1744964616)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1744964616, parser.get_u32_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_u32_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u32_required(&self, search_tag: u16) -> Result<u32> {
+        self.get_data_required(search_tag)
+            .and_then(self.number(decode_u32))
+    }
+
+    /// Read u32 field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_u32] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u32(12, 1744964616);
+    /// #     bld.add_data(14, &[0xFF; 17]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=1744964616), (tag=14, value=[0xFF; 17])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1744964616, parser.get_u32_or(12, 7)?);
+    /// assert_eq!(7, parser.get_u32_or(13, 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(17)), parser.get_u32_or(14, 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u32_or(&self, search_tag: u16, default: u32) -> Result<u32> {
+        self.get_u32(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read u32 field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_u32] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u32(12, 1744964616);
+    /// #     bld.add_data(14, &[0xFF; 17]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=1744964616), (tag=14, value=[0xFF; 17])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1744964616, parser.get_u32_or_else(12, || 7)?);
+    /// assert_eq!(7, parser.get_u32_or_else(13, || 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(17)), parser.get_u32_or_else(14, || 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u32_or_else<F>(&self, search_tag: u16, default: F) -> Result<u32>
+    where
+        F: FnOnce() -> u32,
+    {
+        self.get_u32(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read u32 fields from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
+    /// is small enough to be returned in a `u32`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u32(12, 1744964616);
+    /// #     bld.add_u32(12, 1744964617);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=1744964616, value2=1744964617)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<u32>> = vec![Ok(1744964616), Ok(1744964617)];
+    /// let actual: Vec<Result<u32>> = parser.get_u32s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u32s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<u32>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(self.number(decode_u32))
+    }
+
+    /// Read u32 fields from frame into `values`, returning the number of values added.
+    ///
+    /// This behaves like [FrameParser::get_u32s], but appends to a buffer owned by
+    /// the caller, which can be reused to avoid allocating when many frames are read.
+    /// If a field can not be decoded the error is returned and `values` is left as it
+    /// was.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u32(12, 1744964616);
+    /// #     bld.add_u32(12, 1744964617);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=1744964616, value2=1744964617)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let mut values = vec![7];
+    /// assert_eq!(2, parser.get_u32s_into(12, &mut values)?);
+    /// assert_eq!(vec![7, 1744964616, 1744964617], values);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u32s_into(&self, search_tag: u16, values: &mut Vec<u32>) -> Result<usize> {
+        let decode = self.number(decode_u32);
+        let start = values.len();
+        for field in self.fields.iter().filter(|f| f.tag == search_tag) {
+            match decode(field.value) {
+                Ok(value) => values.push(value),
+                Err(e) => {
+                    values.truncate(start);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(values.len() - start)
+    }
+
+    /// Read u64 field from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u64(12, 150626523450313736);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=150626523450313736)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(150626523450313736), parser.get_u64(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u64(&self, search_tag: u16) -> Result<Option<u64>> {
+        self.decode_value(search_tag, self.number(decode_u64))
+    }
+
+    /// Read required u64 field from frame
+    ///
+    /// Behaves like [FrameParser::get_u64] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u64(12, 150626523450313736);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=150626523450313736)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(150626523450313736, parser.get_u64_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_u64_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u64_required(&self, search_tag: u16) -> Result<u64> {
+        self.get_data_required(search_tag)
+            .and_then(self.number(decode_u64))
+    }
+
+    /// Read u64 field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_u64] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u64(12, 150626523450313736);
+    /// #     bld.add_data(14, &[0xFF; 17]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=150626523450313736), (tag=14, value=[0xFF; 17])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(150626523450313736, parser.get_u64_or(12, 7)?);
+    /// assert_eq!(7, parser.get_u64_or(13, 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(17)), parser.get_u64_or(14, 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u64_or(&self, search_tag: u16, default: u64) -> Result<u64> {
+        self.get_u64(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read u64 field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_u64] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u64(12, 150626523450313736);
+    /// #     bld.add_data(14, &[0xFF; 17]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=150626523450313736), (tag=14, value=[0xFF; 17])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(150626523450313736, parser.get_u64_or_else(12, || 7)?);
+    /// assert_eq!(7, parser.get_u64_or_else(13, || 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(17)), parser.get_u64_or_else(14, || 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u64_or_else<F>(&self, search_tag: u16, default: F) -> Result<u64>
+    where
+        F: FnOnce() -> u64,
+    {
+        self.get_u64(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read u64 fields from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u64(12, 150626523450313736);
+    /// #     bld.add_u64(12, 150626523450313737);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=150626523450313736, value2=150626523450313737)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<u64>> = vec![Ok(150626523450313736), Ok(150626523450313737)];
+    /// let actual: Vec<Result<u64>> = parser.get_u64s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u64s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<u64>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(self.number(decode_u64))
+    }
+
+    /// Read i8 field from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
+    /// is small enough to be returned in an `i8`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i8(12, -9);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=-9)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(-9), parser.get_i8(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i8(&self, search_tag: u16) -> Result<Option<i8>> {
+        self.decode_value(search_tag, decode_i8)
+    }
+
+    /// Read required i8 field from frame
+    ///
+    /// Behaves like [FrameParser::get_i8] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i8(12, -9);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=-9)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-9, parser.get_i8_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_i8_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i8_required(&self, search_tag: u16) -> Result<i8> {
+        self.get_data_required(search_tag).and_then(decode_i8)
+    }
+
+    /// Read i8 field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_i8] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i8(12, -9);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=-9), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-9, parser.get_i8_or(12, 7)?);
+    /// assert_eq!(7, parser.get_i8_or(13, 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_i8_or(14, 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i8_or(&self, search_tag: u16, default: i8) -> Result<i8> {
+        self.get_i8(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read i8 field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_i8] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i8(12, -9);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=-9), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-9, parser.get_i8_or_else(12, || 7)?);
+    /// assert_eq!(7, parser.get_i8_or_else(13, || 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_i8_or_else(14, || 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i8_or_else<F>(&self, search_tag: u16, default: F) -> Result<i8>
+    where
+        F: FnOnce() -> i8,
+    {
+        self.get_i8(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read i8 fields from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
+    /// is small enough to be returned in an `i8`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i8(12, -6);
+    /// #     bld.add_i8(12, 9);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=-6, value2=9)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<i8>> = vec![Ok(-6), Ok(9)];
+    /// let actual: Vec<Result<i8>> = parser.get_i8s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i8s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<i8>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(decode_i8)
+    }
+
+    /// Read i16 field from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
+    /// is small enough to be returned in an `i16`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i16(12, -1024);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=-1024)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(-1024), parser.get_i16(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i16(&self, search_tag: u16) -> Result<Option<i16>> {
+        self.decode_value(search_tag, self.number(decode_i16))
+    }
+
+    /// Read required i16 field from frame
+    ///
+    /// Behaves like [FrameParser::get_i16] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i16(12, -1024);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=-1024)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-1024, parser.get_i16_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_i16_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i16_required(&self, search_tag: u16) -> Result<i16> {
+        self.get_data_required(search_tag)
+            .and_then(self.number(decode_i16))
+    }
+
+    /// Read i16 field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_i16] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i16(12, -1024);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=-1024), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-1024, parser.get_i16_or(12, 7)?);
+    /// assert_eq!(7, parser.get_i16_or(13, 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_i16_or(14, 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i16_or(&self, search_tag: u16, default: i16) -> Result<i16> {
+        self.get_i16(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read i16 field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_i16] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i16(12, -1024);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=-1024), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-1024, parser.get_i16_or_else(12, || 7)?);
+    /// assert_eq!(7, parser.get_i16_or_else(13, || 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_i16_or_else(14, || 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i16_or_else<F>(&self, search_tag: u16, default: F) -> Result<i16>
+    where
+        F: FnOnce() -> i16,
+    {
+        self.get_i16(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read i16 fields from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
+    /// is small enough to be returned in an `i16`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i16(12, -1024);
+    /// #     bld.add_i16(12, 1025);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=-1024, value2=1025)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<i16>> = vec![Ok(-1024), Ok(1025)];
+    /// let actual: Vec<Result<i16>> = parser.get_i16s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i16s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<i16>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(self.number(decode_i16))
+    }
+
+    /// Read i32 field from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
+    /// is small enough to be returned in an `i32`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i32(12, -1744964616);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=-1744964616)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(-1744964616), parser.get_i32(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i32(&self, search_tag: u16) -> Result<Option<i32>> {
+        self.decode_value(search_tag, self.number(decode_i32))
+    }
+
+    /// Read required i32 field from frame
+    ///
+    /// Behaves like [FrameParser::get_i32] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i32(12, -1744964616);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=-1744964616)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-1744964616, parser.get_i32_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_i32_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i32_required(&self, search_tag: u16) -> Result<i32> {
+        self.get_data_required(search_tag)
+            .and_then(self.number(decode_i32))
+    }
+
+    /// Read i32 field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_i32] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i32(12, -1744964616);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=-1744964616), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-1744964616, parser.get_i32_or(12, 7)?);
+    /// assert_eq!(7, parser.get_i32_or(13, 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_i32_or(14, 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i32_or(&self, search_tag: u16, default: i32) -> Result<i32> {
+        self.get_i32(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read i32 field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_i32] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i32(12, -1744964616);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=-1744964616), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-1744964616, parser.get_i32_or_else(12, || 7)?);
+    /// assert_eq!(7, parser.get_i32_or_else(13, || 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_i32_or_else(14, || 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i32_or_else<F>(&self, search_tag: u16, default: F) -> Result<i32>
+    where
+        F: FnOnce() -> i32,
+    {
+        self.get_i32(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read i32 fields from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes, so long as the value
+    /// is small enough to be returned in an `i32`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i32(12, -1744964616);
+    /// #     bld.add_i32(12, 1744964617);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=-1744964616, value2=1744964617)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<i32>> = vec![Ok(-1744964616), Ok(1744964617)];
+    /// let actual: Vec<Result<i32>> = parser.get_i32s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i32s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<i32>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(self.number(decode_i32))
+    }
+
+    /// Read i64 field from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i64(12, -150626523450313736);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=-150626523450313736)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(-150626523450313736), parser.get_i64(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i64(&self, search_tag: u16) -> Result<Option<i64>> {
+        self.decode_value(search_tag, self.number(decode_i64))
+    }
+
+    /// Read required i64 field from frame
+    ///
+    /// Behaves like [FrameParser::get_i64] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i64(12, -150626523450313736);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=-150626523450313736)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-150626523450313736, parser.get_i64_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_i64_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i64_required(&self, search_tag: u16) -> Result<i64> {
+        self.get_data_required(search_tag)
+            .and_then(self.number(decode_i64))
+    }
+
+    /// Read a zigzag encoded i64 field from frame, as written by
+    /// [FrameBuilderLike::add_i64_zigzag].
+    ///
+    /// Can handle data stored in 1 to 8 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i64_zigzag(12, -150626523450313736);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single
+    /// // zigzag encoded data field (tag=12, value=-150626523450313736)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(-150626523450313736), parser.get_i64_zigzag(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i64_zigzag(&self, search_tag: u16) -> Result<Option<i64>> {
+        self.decode_value(search_tag, self.number(decode_i64_zigzag))
+    }
+
+    /// Read i64 field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_i64] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i64(12, -150626523450313736);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=-150626523450313736), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-150626523450313736, parser.get_i64_or(12, 7)?);
+    /// assert_eq!(7, parser.get_i64_or(13, 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_i64_or(14, 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i64_or(&self, search_tag: u16, default: i64) -> Result<i64> {
+        self.get_i64(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read i64 field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_i64] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i64(12, -150626523450313736);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=-150626523450313736), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-150626523450313736, parser.get_i64_or_else(12, || 7)?);
+    /// assert_eq!(7, parser.get_i64_or_else(13, || 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_i64_or_else(14, || 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i64_or_else<F>(&self, search_tag: u16, default: F) -> Result<i64>
+    where
+        F: FnOnce() -> i64,
+    {
+        self.get_i64(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read i64 fields from frame
+    ///
+    /// Can handle data stored a 1, 2, 4 or 8 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i64(12, -150626523450313736);
+    /// #     bld.add_i64(12, 150626523450313737);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=-150626523450313736, value2=150626523450313737)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<i64>> = vec![Ok(-150626523450313736), Ok(150626523450313737)];
+    /// let actual: Vec<Result<i64>> = parser.get_i64s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i64s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<i64>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(self.number(decode_i64))
+    }
+
+    /// Read u128 field from frame
+    ///
+    /// Can handle data stored a 1, 2, 4, 8 or 16 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u128(12, 2778590876009255082427012134912);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=2778590876009255082427012134912)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(2778590876009255082427012134912), parser.get_u128(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u128(&self, search_tag: u16) -> Result<Option<u128>> {
+        self.decode_value(search_tag, self.number(decode_u128))
+    }
+
+    /// Read required u128 field from frame
+    ///
+    /// Behaves like [FrameParser::get_u128] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u128(12, 2778590876009255082427012134912);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=2778590876009255082427012134912)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(2778590876009255082427012134912, parser.get_u128_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_u128_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u128_required(&self, search_tag: u16) -> Result<u128> {
+        self.get_data_required(search_tag)
+            .and_then(self.number(decode_u128))
+    }
+
+    /// Read u128 field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_u128] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u128(12, 2778590876009255082427012134912);
+    /// #     bld.add_data(14, &[0xFF; 17]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=2778590876009255082427012134912), (tag=14, value=[0xFF; 17])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(2778590876009255082427012134912, parser.get_u128_or(12, 7)?);
+    /// assert_eq!(7, parser.get_u128_or(13, 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(17)), parser.get_u128_or(14, 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u128_or(&self, search_tag: u16, default: u128) -> Result<u128> {
+        self.get_u128(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read u128 field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_u128] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u128(12, 2778590876009255082427012134912);
+    /// #     bld.add_data(14, &[0xFF; 17]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=2778590876009255082427012134912), (tag=14, value=[0xFF; 17])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(2778590876009255082427012134912, parser.get_u128_or_else(12, || 7)?);
+    /// assert_eq!(7, parser.get_u128_or_else(13, || 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(17)), parser.get_u128_or_else(14, || 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u128_or_else<F>(&self, search_tag: u16, default: F) -> Result<u128>
+    where
+        F: FnOnce() -> u128,
+    {
+        self.get_u128(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read u128 fields from frame
+    ///
+    /// Can handle data stored a 1, 2, 4, 8 or 16 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u128(12, 2778590876009255082427012134912);
+    /// #     bld.add_u128(12, 2778590876009255082427012134913);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=2778590876009255082427012134912, value2=2778590876009255082427012134913)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<u128>> = vec![Ok(2778590876009255082427012134912), Ok(2778590876009255082427012134913)];
+    /// let actual: Vec<Result<u128>> = parser.get_u128s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_u128s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<u128>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(self.number(decode_u128))
+    }
+
+    /// Read i128 field from frame
+    ///
+    /// Can handle data stored a 1, 2, 4, 8 or 16 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i128(12, -2778590876009255082427012134912);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=-2778590876009255082427012134912)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(-2778590876009255082427012134912), parser.get_i128(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i128(&self, search_tag: u16) -> Result<Option<i128>> {
+        self.decode_value(search_tag, self.number(decode_i128))
+    }
+
+    /// Read required i128 field from frame
+    ///
+    /// Behaves like [FrameParser::get_i128] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i128(12, -2778590876009255082427012134912);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=-2778590876009255082427012134912)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-2778590876009255082427012134912, parser.get_i128_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_i128_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i128_required(&self, search_tag: u16) -> Result<i128> {
+        self.get_data_required(search_tag)
+            .and_then(self.number(decode_i128))
+    }
+
+    /// Read i128 field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_i128] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i128(12, -2778590876009255082427012134912);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=-2778590876009255082427012134912), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-2778590876009255082427012134912, parser.get_i128_or(12, 7)?);
+    /// assert_eq!(7, parser.get_i128_or(13, 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_i128_or(14, 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i128_or(&self, search_tag: u16, default: i128) -> Result<i128> {
+        self.get_i128(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read i128 field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_i128] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i128(12, -2778590876009255082427012134912);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=-2778590876009255082427012134912), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(-2778590876009255082427012134912, parser.get_i128_or_else(12, || 7)?);
+    /// assert_eq!(7, parser.get_i128_or_else(13, || 7)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_i128_or_else(14, || 7));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i128_or_else<F>(&self, search_tag: u16, default: F) -> Result<i128>
+    where
+        F: FnOnce() -> i128,
+    {
+        self.get_i128(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read i128 fields from frame
+    ///
+    /// Can handle data stored a 1, 2, 4, 8 or 16 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_i128(12, -2778590876009255082427012134912);
+    /// #     bld.add_i128(12, 2778590876009255082427012134913);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=-2778590876009255082427012134912, value2=2778590876009255082427012134913)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<i128>> = vec![Ok(-2778590876009255082427012134912), Ok(2778590876009255082427012134913)];
+    /// let actual: Vec<Result<i128>> = parser.get_i128s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_i128s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<i128>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(self.number(decode_i128))
+    }
+
+    /// Read f32 field from frame
+    ///
+    /// Only handles data stored as 4 bytes; unlike the integer getters
+    /// no attempt is made to convert between widths.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_f32(12, 1.5);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=1.5)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(1.5), parser.get_f32(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_f32(&self, search_tag: u16) -> Result<Option<f32>> {
+        self.decode_value(search_tag, self.number(decode_f32))
+    }
+
+    /// Read required f32 field from frame
+    ///
+    /// Behaves like [FrameParser::get_f32] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_f32(12, 1.5);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=1.5)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1.5, parser.get_f32_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_f32_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_f32_required(&self, search_tag: u16) -> Result<f32> {
+        self.get_data_required(search_tag)
+            .and_then(self.number(decode_f32))
+    }
+
+    /// Read f32 field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_f32] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_f32(12, 1.5);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=1.5), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1.5, parser.get_f32_or(12, 0.25)?);
+    /// assert_eq!(0.25, parser.get_f32_or(13, 0.25)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_f32_or(14, 0.25));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_f32_or(&self, search_tag: u16, default: f32) -> Result<f32> {
+        self.get_f32(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read f32 field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_f32] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_f32(12, 1.5);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=1.5), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1.5, parser.get_f32_or_else(12, || 0.25)?);
+    /// assert_eq!(0.25, parser.get_f32_or_else(13, || 0.25)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_f32_or_else(14, || 0.25));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_f32_or_else<F>(&self, search_tag: u16, default: F) -> Result<f32>
+    where
+        F: FnOnce() -> f32,
+    {
+        self.get_f32(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read f32 fields from frame
+    ///
+    /// Only handles data stored as 4 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_f32(12, 1.5);
+    /// #     bld.add_f32(12, -2.25);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=1.5, value2=-2.25)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<f32>> = vec![Ok(1.5), Ok(-2.25)];
+    /// let actual: Vec<Result<f32>> = parser.get_f32s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_f32s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<f32>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(self.number(decode_f32))
+    }
+
+    /// Read f64 field from frame
+    ///
+    /// Only handles data stored as 8 bytes; a value written with `add_f32`
+    /// must be read with `get_f32`.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_f64(12, 1.5);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=1.5)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(1.5), parser.get_f64(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_f64(&self, search_tag: u16) -> Result<Option<f64>> {
+        self.decode_value(search_tag, self.number(decode_f64))
+    }
+
+    /// Read required f64 field from frame
+    ///
+    /// Behaves like [FrameParser::get_f64] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_f64(12, 1.5);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=1.5)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1.5, parser.get_f64_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_f64_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_f64_required(&self, search_tag: u16) -> Result<f64> {
+        self.get_data_required(search_tag)
+            .and_then(self.number(decode_f64))
+    }
+
+    /// Read f64 field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_f64] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_f64(12, 1.5);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=1.5), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1.5, parser.get_f64_or(12, 0.25)?);
+    /// assert_eq!(0.25, parser.get_f64_or(13, 0.25)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_f64_or(14, 0.25));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_f64_or(&self, search_tag: u16, default: f64) -> Result<f64> {
+        self.get_f64(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read f64 field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_f64] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_f64(12, 1.5);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=1.5), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1.5, parser.get_f64_or_else(12, || 0.25)?);
+    /// assert_eq!(0.25, parser.get_f64_or_else(13, || 0.25)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_f64_or_else(14, || 0.25));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_f64_or_else<F>(&self, search_tag: u16, default: F) -> Result<f64>
+    where
+        F: FnOnce() -> f64,
+    {
+        self.get_f64(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read f64 fields from frame
+    ///
+    /// Only handles data stored as 8 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_f64(12, 1.5);
+    /// #     bld.add_f64(12, -2.25);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=1.5, value2=-2.25)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<f64>> = vec![Ok(1.5), Ok(-2.25)];
+    /// let actual: Vec<Result<f64>> = parser.get_f64s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_f64s<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<f64>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(self.number(decode_f64))
+    }
+
+    /// Read a field holding a slice of u16 values from frame.
+    ///
+    /// The field-length must be a multiple of 2, with each value using 2 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u16_slice(12, &[7, 256]);
+    /// #     bld.add_data(13, &[1, 2, 3, 4, 5]);
+    /// # }
+    /// #
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(vec![7, 256]), parser.get_u16_slice(12)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(5)), parser.get_u16_slice(13));
+    /// assert_eq!(None, parser.get_u16_slice(14)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn get_u16_slice(&self, search_tag: u16) -> Result<Option<Vec<u16>>> {
+        self.decode_value(search_tag, |value| {
+            self.decode_numbers(value, 2, decode_u16)
+        })
+    }
+
+    /// Read a field holding a slice of u32 values from frame.
+    ///
+    /// The field-length must be a multiple of 4, with each value using 4 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u32_slice(12, &[7, 256]);
+    /// #     bld.add_data(13, &[1, 2, 3, 4, 5]);
+    /// # }
+    /// #
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(vec![7, 256]), parser.get_u32_slice(12)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(5)), parser.get_u32_slice(13));
+    /// assert_eq!(None, parser.get_u32_slice(14)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn get_u32_slice(&self, search_tag: u16) -> Result<Option<Vec<u32>>> {
+        self.decode_value(search_tag, |value| {
+            self.decode_numbers(value, 4, decode_u32)
+        })
+    }
+
+    /// Read a field holding a slice of u64 values from frame.
+    ///
+    /// The field-length must be a multiple of 8, with each value using 8 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u64_slice(12, &[7, 1 << 40]);
+    /// #     bld.add_data(13, &[1, 2, 3, 4, 5]);
+    /// # }
+    /// #
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(vec![7, 1 << 40]), parser.get_u64_slice(12)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(5)), parser.get_u64_slice(13));
+    /// assert_eq!(None, parser.get_u64_slice(14)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn get_u64_slice(&self, search_tag: u16) -> Result<Option<Vec<u64>>> {
+        self.decode_value(search_tag, |value| {
+            self.decode_numbers(value, 8, decode_u64)
+        })
+    }
+
+    /// Read a field holding a slice of f32 values from frame.
+    ///
+    /// The field-length must be a multiple of 4, with each value using 4 bytes.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_f32_slice(12, &[1.5, -2.0]);
+    /// #     bld.add_data(13, &[1, 2, 3, 4, 5]);
+    /// # }
+    /// #
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(vec![1.5, -2.0]), parser.get_f32_slice(12)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(5)), parser.get_f32_slice(13));
+    /// assert_eq!(None, parser.get_f32_slice(14)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn get_f32_slice(&self, search_tag: u16) -> Result<Option<Vec<f32>>> {
+        self.decode_value(search_tag, |value| {
+            self.decode_numbers(value, 4, decode_f32)
+        })
+    }
+
+    /// Decode a field-value made of numbers that each take `width` bytes.
+    fn decode_numbers<T>(
+        &self,
+        value: &[u8],
+        width: usize,
+        decoder: fn(&[u8]) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let chunks = value.chunks_exact(width);
+        if !chunks.remainder().is_empty() {
+            return Err(Error::IncompatibleFieldLength(value.len()));
+        }
+        chunks.map(self.number(decoder)).collect()
+    }
+
+    /// Read bool field from frame
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_bool(12, true);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=true)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(true), parser.get_bool(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_bool(&self, search_tag: u16) -> Result<Option<bool>> {
+        self.decode_value(search_tag, decode_bool)
+    }
+
+    /// Read required bool field from frame
+    ///
+    /// Behaves like [FrameParser::get_bool] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_bool(12, true);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=true)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(true, parser.get_bool_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_bool_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_bool_required(&self, search_tag: u16) -> Result<bool> {
+        self.get_data_required(search_tag).and_then(decode_bool)
+    }
+
+    /// Read bool field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_bool] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_bool(12, true);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=true), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(true, parser.get_bool_or(12, false)?);
+    /// assert_eq!(false, parser.get_bool_or(13, false)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_bool_or(14, false));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_bool_or(&self, search_tag: u16, default: bool) -> Result<bool> {
+        self.get_bool(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read bool field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_bool] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_bool(12, true);
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=true), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(true, parser.get_bool_or_else(12, || false)?);
+    /// assert_eq!(false, parser.get_bool_or_else(13, || false)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldLength(3)), parser.get_bool_or_else(14, || false));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_bool_or_else<F>(&self, search_tag: u16, default: F) -> Result<bool>
+    where
+        F: FnOnce() -> bool,
+    {
+        self.get_bool(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read bool fields from frame
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_bool(12, false);
+    /// #     bld.add_bool(12, true);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=false, value2=true)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<bool>> = vec![Ok(false), Ok(true)];
+    /// let actual: Vec<Result<bool>> = parser.get_bools(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_bools<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<bool>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(decode_bool)
+    }
+
+    /// Read bool field from frame, accepting any non-zero byte as `true`
+    ///
+    /// This deviates from the convention used by [FrameBuilderLike::add_bool] (and
+    /// checked by [FrameParser::get_bool]) that `true` is encoded as `0xFF`, so that
+    /// frames from senders which encode `true` as `0x01` can still be read.  It should
+    /// only be used for such senders; the field-value must still be a single byte.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_data(12, &[0x01]);
+    /// #     bld.add_data(13, &[0x00]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=[0x01]), (tag=13, value=[0x00])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(true), parser.get_bool_lenient(12)?);
+    /// assert_eq!(Some(false), parser.get_bool_lenient(13)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldValue), parser.get_bool(12));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_bool_lenient(&self, search_tag: u16) -> Result<Option<bool>> {
+        self.decode_value(search_tag, decode_bool_lenient)
+    }
+
+    /// Read field from frame as any type that implements [FromField].
+    ///
+    /// Numbers are decoded using the byte order of this frame.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u32(12, 1744964616);
+    /// #     bld.add_str(13, "test_str");
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=1744964616) and (tag=13, value="test_str")
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let x: u32 = parser.get(12)?.unwrap_or(0);
+    /// assert_eq!(1744964616, x);
+    /// assert_eq!(Some("test_str"), parser.get::<&str>(13)?);
+    /// assert_eq!(None, parser.get::<u64>(14)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get<T: FromField<'a>>(&self, search_tag: u16) -> Result<Option<T>> {
+        let endianness = self.options.endianness;
+        self.get_data(search_tag)
+            .map(|value| T::from_field_with_endianness(value, endianness))
+            .transpose()
+    }
+
+    /// Read field from frame as any type that implements [FromField], or `default`
+    /// if there is no field with `search_tag`.
+    ///
+    /// A field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_str(12, "test_str");
+    /// #     bld.add_data(14, &[0xFF]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value="test_str"), (tag=14, value=[0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!("test_str", parser.get_or(12, "none")?);
+    /// assert_eq!("none", parser.get_or(13, "none")?);
+    /// assert_eq!(Err(Error::IncompatibleFieldValue), parser.get_or(14, "none"));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_or<T: FromField<'a>>(&self, search_tag: u16, default: T) -> Result<T> {
+        self.get(search_tag).map(|value| value.unwrap_or(default))
+    }
+
+    /// Read field from frame as any type that implements [FromField], or the result
+    /// of `default` if there is no field with `search_tag`.
+    ///
+    /// `default` is only called for a missing field; a field that is present but can
+    /// not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_str(12, "test_str");
+    /// #     bld.add_data(14, &[0xFF]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value="test_str"), (tag=14, value=[0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let hostname = || String::from("localhost");
+    /// assert_eq!("test_str", parser.get_or_else::<String, _>(12, hostname)?);
+    /// assert_eq!("localhost", parser.get_or_else::<String, _>(13, hostname)?);
+    /// assert_eq!(
+    ///     Err(Error::IncompatibleFieldValue),
+    ///     parser.get_or_else::<String, _>(14, || unreachable!())
+    /// );
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_or_else<T, F>(&self, search_tag: u16, default: F) -> Result<T>
+    where
+        T: FromField<'a>,
+        F: FnOnce() -> T,
+    {
+        self.get(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read a field that must appear exactly once in the frame as any type that
+    /// implements [FromField].
+    ///
+    /// A missing field is reported as [Error::MissingField] and a repeated field as
+    /// [Error::DuplicateTag] (see [FrameParser::get_data_unique]).
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u32(12, 1744964616);
+    /// #     bld.add_str(14, "one");
+    /// #     bld.add_str(14, "two");
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with three data fields
+    /// // (tag=12, value=1744964616), (tag=14, value="one") and (tag=14, value="two")
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(1744964616, parser.get_unique::<u32>(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_unique::<u32>(13));
+    /// assert_eq!(Err(Error::DuplicateTag(14)), parser.get_unique::<&str>(14));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_unique<T: FromField<'a>>(&self, search_tag: u16) -> Result<T> {
+        let value = self.get_data_unique(search_tag)?;
+        T::from_field_with_endianness(value, self.options.endianness)
+    }
+
+    /// Attempt to find field-value of field that has the search_tag and then
+    /// attempts to convert it to the required type using the supplied `decoder` function.
+    fn decode_value<T, F>(&self, search_tag: u16, decoder: F) -> Result<Option<T>>
+    where
+        F: FnOnce(&[u8]) -> Result<T>,
+    {
+        self.get_data(search_tag).map(decoder).transpose()
+    }
+
+    /// Read str field from frame
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_str(12, "test_str");
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value="test_str" in UTF-8)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some("test_str"), parser.get_str(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_str(&self, search_tag: u16) -> Result<Option<&str>> {
+        self.decode_ref(search_tag, decode_str)
+    }
+
+    /// Read required str field from frame
+    ///
+    /// Behaves like [FrameParser::get_str] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_str(12, "test_str");
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value="test_str" in UTF-8)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!("test_str", parser.get_str_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_str_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_str_required(&self, search_tag: u16) -> Result<&'a str> {
+        self.get_data_required(search_tag).and_then(decode_str)
+    }
+
+    /// Read str fields from frame
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_str(12, "hello");
+    /// #     bld.add_str(12, "goodbye");
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1="hello", value2="goodbye")
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<&str>> = vec![Ok("hello"), Ok("goodbye")];
+    /// let actual: Vec<Result<&str>> = parser.get_strs(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_strs<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<&'a str>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(decode_str)
+    }
+
+    /// Read str field from frame, replacing any invalid UTF-8 with `U+FFFD`.
+    ///
+    /// This is for display and logging, where a best-effort string is more useful
+    /// than an error; use [FrameParser::get_str] where invalid UTF-8 indicates a
+    /// corrupt frame.  The str is only copied if it needs replacements.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_str(12, "test_str");
+    /// #     bld.add_data(13, b"bad \xFF");
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value="test_str" in UTF-8), (tag=13, value=b"bad \xFF")
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some("test_str".into()), parser.get_str_lossy(12));
+    /// assert_eq!(Some("bad \u{FFFD}".into()), parser.get_str_lossy(13));
+    /// assert_eq!(None, parser.get_str_lossy(14));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_str_lossy(&self, search_tag: u16) -> Option<Cow<'a, str>> {
+        self.get_data(search_tag).map(String::from_utf8_lossy)
+    }
+
+    /// Read str field from frame as an owned `String`.
+    ///
+    /// Unlike [FrameParser::get_str] this copies (and so allocates) the field-value,
+    /// which allows it to outlive the parser.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_str(12, "test_str");
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value="test_str" in UTF-8)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some("test_str".to_string()), parser.get_string(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_string(&self, search_tag: u16) -> Result<Option<String>> {
+        self.decode_value(search_tag, decode_string)
+    }
+
+    /// Read str fields from frame as owned `String`s.
+    ///
+    /// Unlike [FrameParser::get_strs] this copies (and so allocates) each field-value,
+    /// which allows them to outlive the parser.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_str(12, "hello");
+    /// #     bld.add_str(12, "goodbye");
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1="hello", value2="goodbye")
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<String>> = vec![Ok("hello".to_string()), Ok("goodbye".to_string())];
+    /// let actual: Vec<Result<String>> = parser.get_strings(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_strings<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<String>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(decode_string)
+    }
+
+    /// Read char field from frame
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_char(12, '€');
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value='€')
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some('€'), parser.get_char(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_char(&self, search_tag: u16) -> Result<Option<char>> {
+        self.decode_value(search_tag, decode_char)
+    }
+
+    /// Read required char field from frame
+    ///
+    /// Behaves like [FrameParser::get_char] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_char(12, 'x');
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value='x')
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!('x', parser.get_char_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_char_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_char_required(&self, search_tag: u16) -> Result<char> {
+        self.get_data_required(search_tag).and_then(decode_char)
+    }
+
+    /// Read char field from frame, or `default` if there is no field with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_char] except that a missing field gives
+    /// `default`; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_char(12, 'x');
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value='x'), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!('x', parser.get_char_or(12, '?')?);
+    /// assert_eq!('?', parser.get_char_or(13, '?')?);
+    /// assert_eq!(Err(Error::IncompatibleFieldValue), parser.get_char_or(14, '?'));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_char_or(&self, search_tag: u16, default: char) -> Result<char> {
+        self.get_char(search_tag)
+            .map(|value| value.unwrap_or(default))
+    }
+
+    /// Read char field from frame, or the result of `default` if there is no field
+    /// with `search_tag`
+    ///
+    /// Behaves like [FrameParser::get_char] except that `default` is called for a
+    /// missing field; a field that is present but can not be decoded is still an error.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_char(12, 'x');
+    /// #     bld.add_data(14, &[0xFF; 3]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value='x'), (tag=14, value=[0xFF, 0xFF, 0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!('x', parser.get_char_or_else(12, || '?')?);
+    /// assert_eq!('?', parser.get_char_or_else(13, || '?')?);
+    /// assert_eq!(Err(Error::IncompatibleFieldValue), parser.get_char_or_else(14, || '?'));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_char_or_else<F>(&self, search_tag: u16, default: F) -> Result<char>
+    where
+        F: FnOnce() -> char,
+    {
+        self.get_char(search_tag)
+            .map(|value| value.unwrap_or_else(default))
+    }
+
+    /// Read char fields from frame
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_char(12, 'x');
+    /// #     bld.add_char(12, 'y');
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1='x', value2='y')
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected : Vec<Result<char>> = vec![Ok('x'), Ok('y')];
+    /// let actual: Vec<Result<char>> = parser.get_chars(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_chars<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<char>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(decode_char)
+    }
+
+    /// Read C-style enum field from frame, as written by [FrameBuilderLike::add_enum].
+    ///
+    /// The field is read as a u32, and a value that `E` can not be converted from is
+    /// reported as [Error::IncompatibleFieldValue].
+    ///
+    /// ```
+    /// # use core::convert::TryFrom;
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// #[derive(Debug, PartialEq)]
+    /// enum Mode {
+    ///     Off,
+    ///     On,
+    /// }
+    ///
+    /// impl TryFrom<u32> for Mode {
+    ///     type Error = ();
+    ///     fn try_from(value: u32) -> core::result::Result<Mode, ()> {
+    ///         match value {
+    ///             0 => Ok(Mode::Off),
+    ///             1 => Ok(Mode::On),
+    ///             _ => Err(()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u32(12, 1);
+    /// #     bld.add_u32(13, 7);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two u32 fields (tag=12, value=1)
+    /// // and (tag=13, value=7)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(Mode::On), parser.get_enum(12)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldValue), parser.get_enum::<Mode>(13));
+    /// assert_eq!(None, parser.get_enum::<Mode>(14)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_enum<E: TryFrom<u32>>(&self, search_tag: u16) -> Result<Option<E>> {
+        self.get_u32(search_tag)?
+            .map(|value| E::try_from(value).map_err(|_| Error::IncompatibleFieldValue))
+            .transpose()
+    }
+
+    /// Read required C-style enum field from frame.
+    ///
+    /// Behaves like [FrameParser::get_enum] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use core::convert::TryFrom;
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # #[derive(Debug, PartialEq)]
+    /// # enum Mode {
+    /// #     Off,
+    /// #     On,
+    /// # }
+    /// # impl TryFrom<u32> for Mode {
+    /// #     type Error = ();
+    /// #     fn try_from(value: u32) -> core::result::Result<Mode, ()> {
+    /// #         match value {
+    /// #             0 => Ok(Mode::Off),
+    /// #             1 => Ok(Mode::On),
+    /// #             _ => Err(()),
+    /// #         }
+    /// #     }
+    /// # }
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_u32(12, 0);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single u32 field (tag=12, value=0)
+    /// // and Mode is an enum implementing TryFrom<u32>
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Mode::Off, parser.get_enum_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_enum_required::<Mode>(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_enum_required<E: TryFrom<u32>>(&self, search_tag: u16) -> Result<E> {
+        self.get_enum(search_tag)?
+            .ok_or(Error::MissingField(search_tag))
+    }
+
+    /// Read IPv4 address field from frame
+    ///
+    /// ```
+    /// # use core::net::Ipv4Addr;
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_ipv4(12, Ipv4Addr::new(192, 168, 0, 1));
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=192.168.0.1)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(Ipv4Addr::new(192, 168, 0, 1)), parser.get_ipv4(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_ipv4(&self, search_tag: u16) -> Result<Option<core::net::Ipv4Addr>> {
+        self.decode_value(search_tag, decode_ipv4)
+    }
+
+    /// Read required IPv4 address field from frame
+    ///
+    /// Behaves like [FrameParser::get_ipv4] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use core::net::Ipv4Addr;
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_ipv4(12, Ipv4Addr::new(192, 168, 0, 1));
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=192.168.0.1)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Ipv4Addr::new(192, 168, 0, 1), parser.get_ipv4_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_ipv4_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_ipv4_required(&self, search_tag: u16) -> Result<core::net::Ipv4Addr> {
+        self.get_data_required(search_tag).and_then(decode_ipv4)
+    }
+
+    /// Read IPv4 address fields from frame
+    ///
+    /// ```
+    /// # use core::net::Ipv4Addr;
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_ipv4(12, Ipv4Addr::new(192, 168, 0, 1));
+    /// #     bld.add_ipv4(12, Ipv4Addr::new(10, 0, 0, 1));
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=192.168.0.1, value2=10.0.0.1)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: Vec<Result<Ipv4Addr>> = vec![Ok(Ipv4Addr::new(192, 168, 0, 1)), Ok(Ipv4Addr::new(10, 0, 0, 1))];
+    /// let actual: Vec<Result<Ipv4Addr>> = parser.get_ipv4s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_ipv4s<'b>(
+        &'b self,
+        search_tag: u16,
+    ) -> impl Iterator<Item = Result<core::net::Ipv4Addr>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(decode_ipv4)
+    }
+
+    /// Read IPv6 address field from frame
+    ///
+    /// ```
+    /// # use core::net::Ipv6Addr;
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_ipv6(12, Ipv6Addr::LOCALHOST);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=::1)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(Ipv6Addr::LOCALHOST), parser.get_ipv6(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_ipv6(&self, search_tag: u16) -> Result<Option<core::net::Ipv6Addr>> {
+        self.decode_value(search_tag, decode_ipv6)
+    }
+
+    /// Read required IPv6 address field from frame
+    ///
+    /// Behaves like [FrameParser::get_ipv6] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use core::net::Ipv6Addr;
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_ipv6(12, Ipv6Addr::LOCALHOST);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=::1)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Ipv6Addr::LOCALHOST, parser.get_ipv6_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_ipv6_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_ipv6_required(&self, search_tag: u16) -> Result<core::net::Ipv6Addr> {
+        self.get_data_required(search_tag).and_then(decode_ipv6)
+    }
+
+    /// Read IPv6 address fields from frame
+    ///
+    /// ```
+    /// # use core::net::Ipv6Addr;
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_ipv6(12, Ipv6Addr::LOCALHOST);
+    /// #     bld.add_ipv6(12, Ipv6Addr::UNSPECIFIED);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=::1, value2=::)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: Vec<Result<Ipv6Addr>> = vec![Ok(Ipv6Addr::LOCALHOST), Ok(Ipv6Addr::UNSPECIFIED)];
+    /// let actual: Vec<Result<Ipv6Addr>> = parser.get_ipv6s(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_ipv6s<'b>(
+        &'b self,
+        search_tag: u16,
+    ) -> impl Iterator<Item = Result<core::net::Ipv6Addr>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(decode_ipv6)
+    }
+
+    /// Read IP address field from frame
+    ///
+    /// ```
+    /// # use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_ip(12, IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)));
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=192.168.0.1)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1))), parser.get_ip(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_ip(&self, search_tag: u16) -> Result<Option<core::net::IpAddr>> {
+        self.decode_value(search_tag, decode_ip)
+    }
+
+    /// Read required IP address field from frame
+    ///
+    /// Behaves like [FrameParser::get_ip] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_ip(12, IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)));
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=192.168.0.1)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)), parser.get_ip_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_ip_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_ip_required(&self, search_tag: u16) -> Result<core::net::IpAddr> {
+        self.get_data_required(search_tag).and_then(decode_ip)
+    }
+
+    /// Read IP address fields from frame
+    ///
+    /// ```
+    /// # use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_ip(12, IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)));
+    /// #     bld.add_ip(12, IpAddr::V6(Ipv6Addr::LOCALHOST));
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=192.168.0.1, value2=::1)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: Vec<Result<IpAddr>> = vec![Ok(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1))), Ok(IpAddr::V6(Ipv6Addr::LOCALHOST))];
+    /// let actual: Vec<Result<IpAddr>> = parser.get_ips(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_ips<'b>(
+        &'b self,
+        search_tag: u16,
+    ) -> impl Iterator<Item = Result<core::net::IpAddr>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(decode_ip)
+    }
+
+    /// Read socket address field from frame
+    ///
+    /// ```
+    /// # use core::net::{Ipv4Addr, SocketAddr};
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_socket_addr(12, SocketAddr::from((Ipv4Addr::new(192, 168, 0, 1), 8080)));
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=192.168.0.1:8080)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(SocketAddr::from((Ipv4Addr::new(192, 168, 0, 1), 8080))), parser.get_socket_addr(12)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_socket_addr(&self, search_tag: u16) -> Result<Option<core::net::SocketAddr>> {
+        self.decode_value(search_tag, decode_socket_addr)
+    }
+
+    /// Read required socket address field from frame
+    ///
+    /// Behaves like [FrameParser::get_socket_addr] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use core::net::{Ipv4Addr, SocketAddr};
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_socket_addr(12, SocketAddr::from((Ipv4Addr::new(192, 168, 0, 1), 8080)));
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=192.168.0.1:8080)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(SocketAddr::from((Ipv4Addr::new(192, 168, 0, 1), 8080)), parser.get_socket_addr_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_socket_addr_required(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_socket_addr_required(&self, search_tag: u16) -> Result<core::net::SocketAddr> {
+        self.get_data_required(search_tag)
+            .and_then(decode_socket_addr)
+    }
+
+    /// Read socket address fields from frame
+    ///
+    /// ```
+    /// # use core::net::{Ipv4Addr, SocketAddr};
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_socket_addr(12, SocketAddr::from((Ipv4Addr::new(192, 168, 0, 1), 8080)));
+    /// #     bld.add_socket_addr(12, SocketAddr::from((Ipv4Addr::new(10, 0, 0, 1), 80)));
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=192.168.0.1:8080, value2=10.0.0.1:80)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: Vec<Result<SocketAddr>> = vec![Ok(SocketAddr::from((Ipv4Addr::new(192, 168, 0, 1), 8080))), Ok(SocketAddr::from((Ipv4Addr::new(10, 0, 0, 1), 80)))];
+    /// let actual: Vec<Result<SocketAddr>> = parser.get_socket_addrs(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_socket_addrs<'b>(
+        &'b self,
+        search_tag: u16,
+    ) -> impl Iterator<Item = Result<core::net::SocketAddr>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(decode_socket_addr)
+    }
+
+    ///Read uuid field from frame
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     let uuid = uuid::Uuid::parse_str("3011712a-d972-4a5d-bbf9-ec5fb7525c97").unwrap();
+    /// #     bld.add_uuid(12, &uuid);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=3011712a-d972-4a5d-bbf9-ec5fb7525c97)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected = uuid::Uuid::parse_str("3011712a-d972-4a5d-bbf9-ec5fb7525c97").unwrap();
+    /// assert_eq!(Some(expected), parser.get_uuid(12)?);
+    /// # Ok(()) }
+    ///  ```
+    #[cfg(feature = "uuid")]
+    pub fn get_uuid(&self, search_tag: u16) -> Result<Option<uuid::Uuid>> {
+        self.decode_value(search_tag, decode_uuid)
+    }
+
+    ///Read required uuid field from frame
+    ///
+    /// Behaves like [FrameParser::get_uuid] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     let uuid = uuid::Uuid::parse_str("3011712a-d972-4a5d-bbf9-ec5fb7525c97").unwrap();
+    /// #     bld.add_uuid(12, &uuid);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=3011712a-d972-4a5d-bbf9-ec5fb7525c97)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected = uuid::Uuid::parse_str("3011712a-d972-4a5d-bbf9-ec5fb7525c97").unwrap();
+    /// assert_eq!(expected, parser.get_uuid_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_uuid_required(13));
+    /// # Ok(()) }
+    ///  ```
+    #[cfg(feature = "uuid")]
+    pub fn get_uuid_required(&self, search_tag: u16) -> Result<uuid::Uuid> {
+        self.get_data_required(search_tag).and_then(decode_uuid)
+    }
+
+    ///Read uuid fields from frame
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     let uuid = uuid::Uuid::parse_str("3011712a-d972-4a5d-bbf9-ec5fb7525c97").unwrap();
+    /// #     bld.add_uuid(12, &uuid);
+    /// #     let uuid = uuid::Uuid::parse_str("40b4e52c-1501-48d3-98eb-e2c66cb76cbf").unwrap();
+    /// #     bld.add_uuid(12, &uuid);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=3011712a-d972-4a5d-bbf9-ec5fb7525c97, value2=40b4e52c-1501-48d3-98eb-e2c66cb76cbf)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected = vec! [Ok(uuid::Uuid::parse_str("3011712a-d972-4a5d-bbf9-ec5fb7525c97").unwrap()),
+    ///                      Ok(uuid::Uuid::parse_str("40b4e52c-1501-48d3-98eb-e2c66cb76cbf").unwrap())];
+    /// let actual: Vec<Result<uuid::Uuid>> = parser.get_uuids(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    #[cfg(feature = "uuid")]
+    pub fn get_uuids<'b>(&'b self, search_tag: u16) -> impl Iterator<Item = Result<uuid::Uuid>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(decode_uuid)
+    }
+
+    /// Read protobuf message field from frame
+    ///
+    /// A field-value that can not be decoded as an `M` is reported as
+    /// [Error::IncompatibleFieldValue].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_message(12, &String::from("hi"));
+    /// #     bld.add_data(14, &[0xFF]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two data fields
+    /// // (tag=12, value=StringValue("hi")), (tag=14, value=[0xFF])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(String::from("hi")), parser.get_message(12)?);
+    /// assert_eq!(None, parser.get_message::<String>(13)?);
+    /// assert_eq!(Err(Error::IncompatibleFieldValue), parser.get_message::<String>(14));
+    /// # Ok(()) }
+    ///  ```
+    #[cfg(feature = "prost")]
+    pub fn get_message<M: prost::Message + Default>(&self, search_tag: u16) -> Result<Option<M>> {
+        self.decode_value(search_tag, decode_message)
+    }
+
+    /// Read required protobuf message field from frame
+    ///
+    /// Behaves like [FrameParser::get_message] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_message(12, &7u32);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=UInt32Value(7))
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(7, parser.get_message_required::<u32>(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_message_required::<u32>(13));
+    /// # Ok(()) }
+    ///  ```
+    #[cfg(feature = "prost")]
+    pub fn get_message_required<M: prost::Message + Default>(&self, search_tag: u16) -> Result<M> {
+        self.get_data_required(search_tag).and_then(decode_message)
+    }
+
+    /// Attempt to find field-value of field that has the search_tag and then
+    /// attempts to convert it to the required type using the supplied `decoder` function.
+    fn decode_ref<T, F>(&self, search_tag: u16, decoder: F) -> Result<Option<&T>>
+    where
+        F: FnOnce(&[u8]) -> Result<&T>,
+        T: ?Sized,
+    {
+        self.get_data(search_tag).map(decoder).transpose()
+    }
+
+    /// Read duration field (stored as nanoseconds) from frame
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_duration_nanos(12, Duration::from_millis(1500))?;
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=1.5s)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(Duration::from_millis(1500)), parser.get_duration_nanos(12)?);
+    /// # Ok(()) }
+    ///  ```
+    #[cfg(feature = "time")]
+    pub fn get_duration_nanos(&self, search_tag: u16) -> Result<Option<core::time::Duration>> {
+        Ok(self
+            .get_u64(search_tag)?
+            .map(core::time::Duration::from_nanos))
+    }
+
+    /// Read required duration field (stored as nanoseconds) from frame
+    ///
+    /// Behaves like [FrameParser::get_duration_nanos] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_duration_nanos(12, Duration::from_millis(1500))?;
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=1.5s)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Duration::from_millis(1500), parser.get_duration_nanos_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_duration_nanos_required(13));
+    /// # Ok(()) }
+    ///  ```
+    #[cfg(feature = "time")]
+    pub fn get_duration_nanos_required(&self, search_tag: u16) -> Result<core::time::Duration> {
+        self.get_u64_required(search_tag)
+            .map(core::time::Duration::from_nanos)
+    }
+
+    /// Read duration fields (stored as nanoseconds) from frame
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_duration_nanos(12, Duration::from_secs(1))?;
+    /// #     bld.add_duration_nanos(12, Duration::from_secs(2))?;
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=1s, value2=2s)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: Vec<Result<Duration>> = vec![Ok(Duration::from_secs(1)), Ok(Duration::from_secs(2))];
+    /// let actual: Vec<Result<Duration>> = parser.get_duration_nanos_all(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    #[cfg(feature = "time")]
+    pub fn get_duration_nanos_all<'b>(
+        &'b self,
+        search_tag: u16,
+    ) -> impl Iterator<Item = Result<core::time::Duration>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_u64s(search_tag)
+            .map(|v| v.map(core::time::Duration::from_nanos))
+    }
+
+    /// Read system time field (stored as nanoseconds since the unix epoch) from frame
+    ///
+    /// ```
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let time = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_system_time(12, time)?;
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // single data field (tag=12, value=time)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(Some(time), parser.get_system_time(12)?);
+    /// # Ok(()) }
+    ///  ```
+    #[cfg(feature = "time")]
+    pub fn get_system_time(&self, search_tag: u16) -> Result<Option<std::time::SystemTime>> {
+        self.get_u64(search_tag)?
+            .map(decode_system_time)
+            .transpose()
+    }
+
+    /// Read required system time field (stored as nanoseconds since the unix epoch) from frame
+    ///
+    /// Behaves like [FrameParser::get_system_time] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let time = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_system_time(12, time)?;
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a single data field (tag=12, value=time)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// assert_eq!(time, parser.get_system_time_required(12)?);
+    /// assert_eq!(Err(Error::MissingField(13)), parser.get_system_time_required(13));
+    /// # Ok(()) }
+    ///  ```
+    #[cfg(feature = "time")]
+    pub fn get_system_time_required(&self, search_tag: u16) -> Result<std::time::SystemTime> {
+        self.get_u64_required(search_tag)
+            .and_then(decode_system_time)
+    }
+
+    /// Read system time fields (stored as nanoseconds since the unix epoch) from frame
+    ///
+    /// ```
+    /// # use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let time1 = UNIX_EPOCH + Duration::from_secs(1);
+    /// # let time2 = UNIX_EPOCH + Duration::from_secs(2);
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_system_time(12, time1)?;
+    /// #     bld.add_system_time(12, time2)?;
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a two fields
+    /// // (tag=12, value1=time1, value2=time2)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: Vec<Result<SystemTime>> = vec![Ok(time1), Ok(time2)];
+    /// let actual: Vec<Result<SystemTime>> = parser.get_system_times(12).collect();
+    /// assert_eq!(expected, actual);
+    /// # Ok(()) }
+    ///  ```
+    #[cfg(feature = "time")]
+    pub fn get_system_times<'b>(
+        &'b self,
+        search_tag: u16,
+    ) -> impl Iterator<Item = Result<std::time::SystemTime>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_u64s(search_tag)
+            .map(|v| v.and_then(decode_system_time))
+    }
+
+    /// Read a child frame from a frame.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     let mut bld2 = bld.add_frame(12);
+    /// #     bld2.add_u8(13, 2);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // child frame (tag=12) which contains a single
+    /// // value (tag=13, value=2)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let child_parser = parser.get_frame(12)?.unwrap();
+    /// assert_eq!(Some(2), child_parser.get_u8(13)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_frame(&self, search_tag: u16) -> Result<Option<FrameParser<'a>>> {
+        self.get_data(search_tag)
+            .map(|v| self.parse_child(v))
+            .transpose()
+    }
+
+    /// Read a required child frame from a frame.
+    ///
+    /// Behaves like [FrameParser::get_frame] except that a missing field
+    /// is reported as [Error::MissingField].
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     let mut bld2 = bld.add_frame(12);
+    /// #     bld2.add_u8(13, 2);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a
+    /// // child frame (tag=12) which contains a single
+    /// // value (tag=13, value=2)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let child_parser = parser.get_frame_required(12)?;
+    /// assert_eq!(Some(2), child_parser.get_u8(13)?);
+    /// assert!(matches!(parser.get_frame_required(13), Err(Error::MissingField(13))));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_frame_required(&self, search_tag: u16) -> Result<FrameParser<'a>> {
+        self.get_data_required(search_tag)
+            .and_then(|v| self.parse_child(v))
+    }
+
+    /// Read the encoded bytes of a child frame, without parsing it.
+    ///
+    /// This returns the same field-value as [FrameParser::get_data], but shows that the
+    /// field is expected to hold a child frame, for example one that is passed on
+    /// with [FrameBuilderLike::add_frame_raw] without being inspected.
+    ///
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     bld.add_frame(12);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with an empty child frame (tag=12)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: &[u8] = &[1, 0, 0, 0, 0];
+    /// assert_eq!(Some(expected), parser.get_frame_raw(12));
+    /// assert_eq!(None, parser.get_frame_raw(13));
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_frame_raw(&self, search_tag: u16) -> Option<&'a [u8]> {
+        self.get_data(search_tag)
+    }
+
+    /// Read a child frame from a frame, keeping a missing field apart from a field
+    /// that is not a valid frame.
+    ///
+    /// This is [FrameParser::get_frame] with the `Result` and `Option` swapped: `None`
+    /// means there is no field with `search_tag`, and `Some(Err(_))` means the field
+    /// is present but its value could not be parsed as a frame.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut frame_data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut frame_data);
+    ///     bld.add_frame(12).add_u8(13, 2);
+    ///     bld.add_data(14, &[9]);
+    /// }
+    ///
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// match parser.get_frame_result(12) {
+    ///     Some(Ok(child)) => assert_eq!(Some(2), child.get_u8(13)?),
+    ///     _ => panic!("expected a child frame"),
+    /// }
+    /// assert_eq!(Some(Error::InvalidFrameFormat(9)), parser.get_frame_result(14).and_then(|r| r.err()));
+    /// assert!(parser.get_frame_result(15).is_none());
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_frame_result(&self, search_tag: u16) -> Option<Result<FrameParser<'a>>> {
+        self.get_frame(search_tag).transpose()
+    }
+
+    /// Read child frames from a frame.
+    ///
+    /// Each child is parsed independently, so a malformed child produces an
+    /// `Err` item without stopping iteration over the remaining children.
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     for _ in 0..2 {
+    /// #         let mut child = bld.add_frame(12);
+    /// #         child.add_u8(13, 2);
+    /// #     }
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with two
+    /// // child frame (tag=12) which each contains a single
+    /// // value (tag=13, value=2)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let children: Vec<FrameParser> = parser.get_frames(12).map(|v| v.unwrap()).collect();
+    /// assert_eq!(2, children.len());
+    /// assert_eq!(Some(2), children[0].get_u8(13)?);
+    /// assert_eq!(Some(2), children[1].get_u8(13)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_frames<'b>(
+        &'b self,
+        search_tag: u16,
+    ) -> impl Iterator<Item = Result<FrameParser<'a>>> + 'b
+    where
+        'b: 'a,
+    {
+        self.get_datas(search_tag).map(move |v| self.parse_child(v))
+    }
+
+    /// Try to read every field as a child frame, whatever its tag.
+    ///
+    /// This is for tools that walk frames whose layout they do not know, such as a
+    /// generic frame explorer.  Each field is yielded with its tag, in the order the
+    /// fields were added; a field-value that is not a valid frame gives an `Err`, and
+    /// can be treated as opaque bytes.  Children are parsed with this frame's
+    /// [ParseOptions], so [ParseOptions::max_depth] limits how far a recursive walk
+    /// can go on hostile input.
+    ///
+    /// ```
+    /// # use yatlv::{Error, FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut frame_data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new(&mut frame_data);
+    ///     bld.add_frame(12).add_u8(13, 2);
+    ///     bld.add_str(14, "text");
+    /// }
+    ///
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let mut children = parser.children();
+    /// let (tag, child) = children.next().unwrap();
+    /// assert_eq!((12, Some(2)), (tag, child?.get_u8(13)?));
+    /// let (tag, child) = children.next().unwrap();
+    /// assert_eq!((14, Some(Error::InvalidFrameFormat(b't' as u32))), (tag, child.err()));
+    /// assert!(children.next().is_none());
+    /// # Ok(()) }
+    ///  ```
+    pub fn children<'b>(&'b self) -> impl Iterator<Item = (u16, Result<FrameParser<'a>>)> + 'b
+    where
+        'b: 'a,
+    {
+        self.fields()
+            .map(move |(tag, value)| (tag, self.parse_child(value)))
+    }
+
+    /// Read a nested child frame by following a path of tags.
+    ///
+    /// Each tag selects the first child frame with that tag, starting from this frame.
+    /// Returns `None` as soon as a tag in the path is missing, and an empty path
+    /// returns this frame.
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     let mut child = bld.add_frame(12);
+    /// #     let mut grandchild = child.add_frame(13);
+    /// #     grandchild.add_u8(14, 2);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a child frame (tag=12)
+    /// // that contains a child frame (tag=13) with a single value (tag=14, value=2)
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let grandchild = parser.get_path(&[12, 13])?.unwrap();
+    /// assert_eq!(Some(2), grandchild.get_u8(14)?);
+    /// assert!(parser.get_path(&[12, 15])?.is_none());
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_path(&self, path: &[u16]) -> Result<Option<FrameParser<'a>>> {
+        let (first_tag, tail) = match path.split_first() {
+            Some(split) => split,
+            None => return Ok(Some(self.clone())),
+        };
+        let mut frame = match self.get_frame(*first_tag)? {
+            Some(frame) => frame,
+            None => return Ok(None),
+        };
+        for tag in tail {
+            frame = match frame.get_frame(*tag)? {
+                Some(child) => child,
+                None => return Ok(None),
+            };
+        }
+        Ok(Some(frame))
+    }
+
+    /// Read field from a nested child frame, see [FrameParser::get_path].
+    /// ```
+    /// # use yatlv::{FrameParser, FrameBuilder, FrameBuilderLike, Result};
+    /// # fn main() -> Result<()> {
+    /// # let mut frame_data = Vec::new();
+    /// # {
+    /// #     let mut bld = FrameBuilder::new(&mut frame_data);
+    /// #     let mut child = bld.add_frame(12);
+    /// #     let mut grandchild = child.add_frame(13);
+    /// #     grandchild.add_data(14, &[4, 5]);
+    /// # }
+    /// #
+    /// // Assuming frame_data contains a frame with a child frame (tag=12)
+    /// // that contains a child frame (tag=13) with a single value (tag=14, value=[4, 5])
+    /// let parser = FrameParser::new(&frame_data)?;
+    /// let expected: &[u8] = &[4, 5];
+    /// assert_eq!(Some(expected), parser.get_data_at_path(&[12, 13], 14)?);
+    /// assert_eq!(None, parser.get_data_at_path(&[12], 14)?);
+    /// # Ok(()) }
+    ///  ```
+    pub fn get_data_at_path(&self, path: &[u16], search_tag: u16) -> Result<Option<&'a [u8]>> {
+        Ok(self
+            .get_path(path)?
+            .and_then(|frame| frame.get_data(search_tag)))
+    }
+}
+
+/// Strip the leading zero bytes (keeping at least one) from a big-endian unsigned
+/// number, returning the remaining bytes (in the given byte order) at the start of
+/// the array, and how many there are.
+fn encode_compact<const N: usize>(bytes: [u8; N], endianness: Endianness) -> ([u8; N], usize) {
+    let len = N - bytes[..N - 1].iter().take_while(|b| **b == 0).count();
+    let mut bytes = bytes;
+    bytes.copy_within(N - len.., 0);
+    if endianness == Endianness::Little {
+        bytes[..len].reverse();
+    }
+    (bytes, len)
+}
+
+/// Encode numbers (in the given byte order) one after another.
+fn encode_numbers<T: Copy, const N: usize>(
+    endianness: Endianness,
+    values: &[T],
+    to_be_bytes: fn(T) -> [u8; N],
+) -> Vec<u8> {
+    let mut data = Vec::with_capacity(values.len() * N);
+    for value in values {
+        data.extend_from_slice(&endianness.order(to_be_bytes(*value)));
+    }
+    data
+}
+
+fn decode_u8(value: &[u8]) -> Result<u8> {
+    match value.len() {
+        1 => Ok(value[0]),
+
+        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    }
+}
+
+/// Left-pad a big-endian unsigned number of 1 to `N` bytes with zeros to `N` bytes.
+fn decode_unsigned<const N: usize>(value: &[u8]) -> Result<[u8; N]> {
+    if value.is_empty() || value.len() > N {
+        return Err(Error::IncompatibleFieldLength(value.len()));
+    }
+    let mut bytes = [0; N];
+    bytes[N - value.len()..].copy_from_slice(value);
+    Ok(bytes)
+}
+
+fn decode_u16(value: &[u8]) -> Result<u16> {
+    decode_unsigned(value).map(u16::from_be_bytes)
+}
+
+fn decode_u32(value: &[u8]) -> Result<u32> {
+    decode_unsigned(value).map(u32::from_be_bytes)
+}
+
+fn decode_u64(value: &[u8]) -> Result<u64> {
+    decode_unsigned(value).map(u64::from_be_bytes)
+}
+
+fn decode_i8(value: &[u8]) -> Result<i8> {
+    match value.len() {
+        1 => Ok(value[0] as i8),
+
+        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    }
+}
+
+fn decode_i16(value: &[u8]) -> Result<i16> {
+    match value.len() {
+        1 => Ok(value[0] as i8 as i16),
+
+        2 => Ok(i16::from_be_bytes(value.try_into().unwrap())),
+
+        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    }
+}
+
+fn decode_i32(value: &[u8]) -> Result<i32> {
+    match value.len() {
+        1 => Ok(value[0] as i8 as i32),
+
+        2 => Ok(i16::from_be_bytes(value.try_into().unwrap()) as i32),
+
+        4 => Ok(i32::from_be_bytes(value.try_into().unwrap())),
+
+        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    }
+}
+
+fn decode_i64(value: &[u8]) -> Result<i64> {
+    match value.len() {
+        1 => Ok(value[0] as i8 as i64),
+
+        2 => Ok(i16::from_be_bytes(value.try_into().unwrap()) as i64),
+
+        4 => Ok(i32::from_be_bytes(value.try_into().unwrap()) as i64),
+
+        8 => Ok(i64::from_be_bytes(value.try_into().unwrap())),
+
+        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    }
+}
+
+fn decode_i64_zigzag(value: &[u8]) -> Result<i64> {
+    decode_u64(value).map(|n| (n >> 1) as i64 ^ -((n & 1) as i64))
+}
+
+fn decode_u128(value: &[u8]) -> Result<u128> {
+    decode_unsigned(value).map(u128::from_be_bytes)
+}
+
+fn decode_i128(value: &[u8]) -> Result<i128> {
+    match value.len() {
+        1 => Ok(value[0] as i8 as i128),
+
+        2 => Ok(i16::from_be_bytes(value.try_into().unwrap()) as i128),
+
+        4 => Ok(i32::from_be_bytes(value.try_into().unwrap()) as i128),
+
+        8 => Ok(i64::from_be_bytes(value.try_into().unwrap()) as i128),
+
+        16 => Ok(i128::from_be_bytes(value.try_into().unwrap())),
+
+        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    }
+}
+
+fn decode_f32(value: &[u8]) -> Result<f32> {
+    match value.len() {
+        4 => Ok(f32::from_be_bytes(value.try_into().unwrap())),
+
+        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    }
+}
+
+fn decode_f64(value: &[u8]) -> Result<f64> {
+    match value.len() {
+        8 => Ok(f64::from_be_bytes(value.try_into().unwrap())),
+
+        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    }
+}
+
+fn decode_bool(value: &[u8]) -> Result<bool> {
+    if value.len() != 1 {
+        return Err(Error::IncompatibleFieldLength(value.len()));
+    }
+    match value[0] {
+        0x00 => Ok(false),
+        0xFF => Ok(true),
+        _ => Err(Error::IncompatibleFieldValue),
+    }
+}
+
+fn decode_bool_lenient(value: &[u8]) -> Result<bool> {
+    match value {
+        [b] => Ok(*b != 0x00),
+        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    }
+}
+
+fn decode_str(value: &[u8]) -> Result<&str> {
+    core::str::from_utf8(value).map_err(|_| Error::IncompatibleFieldValue)
+}
+
+fn decode_string(value: &[u8]) -> Result<String> {
+    decode_str(value).map(|v| v.to_owned())
+}
+
+fn decode_char(value: &[u8]) -> Result<char> {
+    if value.is_empty() || value.len() > 4 {
+        return Err(Error::IncompatibleFieldLength(value.len()));
+    }
+    let mut chars = decode_str(value)?.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(Error::IncompatibleFieldValue),
+    }
+}
+
+fn decode_array<const N: usize>(value: &[u8]) -> Result<[u8; N]> {
+    value
+        .try_into()
+        .map_err(|_| Error::IncompatibleFieldLength(value.len()))
+}
+
+#[cfg(feature = "uuid")]
+fn decode_uuid(value: &[u8]) -> Result<uuid::Uuid> {
+    uuid::Uuid::from_slice(value).map_err(|_| Error::IncompatibleFieldLength(value.len()))
+}
+
+#[cfg(feature = "prost")]
+fn decode_message<M: prost::Message + Default>(value: &[u8]) -> Result<M> {
+    M::decode(value).map_err(|_| Error::IncompatibleFieldValue)
+}
+
+fn decode_ipv4(value: &[u8]) -> Result<core::net::Ipv4Addr> {
+    let octets: [u8; 4] = value
+        .try_into()
+        .map_err(|_| Error::IncompatibleFieldLength(value.len()))?;
+    Ok(octets.into())
+}
+
+fn decode_ipv6(value: &[u8]) -> Result<core::net::Ipv6Addr> {
+    let octets: [u8; 16] = value
+        .try_into()
+        .map_err(|_| Error::IncompatibleFieldLength(value.len()))?;
+    Ok(octets.into())
+}
+
+fn decode_ip(value: &[u8]) -> Result<core::net::IpAddr> {
+    match value.len() {
+        4 => decode_ipv4(value).map(core::net::IpAddr::V4),
+
+        16 => decode_ipv6(value).map(core::net::IpAddr::V6),
+
+        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    }
+}
+
+fn decode_socket_addr(value: &[u8]) -> Result<core::net::SocketAddr> {
+    match value.len() {
+        6 | 18 => {
+            let (ip, port) = value.split_at(value.len() - 2);
+            let port = u16::from_be_bytes(port.try_into().unwrap());
+            decode_ip(ip).map(|ip| core::net::SocketAddr::new(ip, port))
+        }
+
+        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    }
+}
+
+#[cfg(feature = "time")]
+fn decode_system_time(nanos: u64) -> Result<std::time::SystemTime> {
+    std::time::UNIX_EPOCH
+        .checked_add(core::time::Duration::from_nanos(nanos))
+        .ok_or(Error::IncompatibleFieldValue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_make_an_empty_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            FrameBuilder::new(&mut data);
+        }
+        assert_eq!(&[1, 0, 0, 0, 0], &data[..]);
+    }
+
+    #[test]
+    fn can_make_an_empty_packet_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            PacketFrameBuilder::new(&mut data);
+        }
+        assert_eq!(&[0, 0, 0, 5, 1, 0, 0, 0, 0], &data[..]);
+    }
+
+    #[test]
+    fn can_finish_a_frame() {
+        let mut data = vec![9, 9];
+        let mut bld = FrameBuilder::new(&mut data);
+        bld.add_data(1022, &[9, 255]);
+        assert_eq!(13, bld.finish());
+        assert_eq!(
+            &[
+                9, 9, // existing data
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 2, // field length = 2
+                9, 255, // field value
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_finish_a_packet_frame() {
+        let mut data = vec![9, 9];
+        let mut bld = PacketFrameBuilder::new(&mut data);
+        bld.add_data(1022, &[9, 255]);
+        assert_eq!(17, bld.finish());
+        assert_eq!(
+            &[
+                9, 9, // existing data
+                0, 0, 0, 13, // frame size = 13
+                1,  // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 2, // field length = 2
+                9, 255, // field value
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn can_write_a_frame() {
+        let mut data = vec![9, 9];
+        let mut out = vec![8];
+        let mut bld = FrameBuilder::new(&mut data);
+        bld.add_data(1022, &[9, 255]);
+        assert_eq!(13, bld.write_to(&mut out).unwrap());
+        assert_eq!(&data[2..], &out[1..]);
+        assert_eq!(
+            Some(&[9, 255][..]),
+            FrameParser::new(&out[1..]).unwrap().get_data(1022)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn can_write_a_packet_frame() {
+        let mut data = vec![9, 9];
+        let mut out = vec![8];
+        let mut bld = PacketFrameBuilder::new(&mut data);
+        bld.add_data(1022, &[9, 255]);
+        assert_eq!(17, bld.write_to(&mut out).unwrap());
+        assert_eq!(&data[2..], &out[1..]);
+        assert_eq!(&[0, 0, 0, 13], &out[1..5]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn can_report_failure_to_write_a_frame() {
+        let mut data = Vec::new();
+        let mut out = [0u8; 4];
+        let bld = FrameBuilder::new(&mut data);
+        let err = bld.write_to(&mut &mut out[..]).unwrap_err();
+        assert_eq!(std::io::ErrorKind::WriteZero, err.kind());
+    }
+
+    #[test]
+    fn can_finish_a_child_frame() {
+        let mut data = Vec::new();
+        let mut bld = FrameBuilder::new(&mut data);
+        let mut child_bld = bld.add_frame(1022);
+        child_bld.add_data(60, &[9, 255]);
+        assert_eq!(17, child_bld.finish());
+        assert_eq!(24, bld.finish());
+
+        let frame = FrameParser::new(&data).unwrap();
+        let child_frame = frame.get_frame(1022).unwrap().unwrap();
+        assert_eq!(Some(&[9, 255][..]), child_frame.get_data(60));
+    }
+
+    #[test]
+    fn can_add_data_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_data(1022, &[9, 255]);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 2, // field length = 2
+                9, 255, // field value
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_data_to_packet_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = PacketFrameBuilder::new(&mut data);
+            bld.add_data(1022, &[9, 255]);
+        }
+        assert_eq!(
+            &[
+                0, 0, 0, 13, // frame size = 13
+                1,  // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 2, // field length = 2
+                9, 255, // field value
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn add_data_iter_matches_add_data() {
+        let values = vec![vec![9, 255], vec![], vec![1]];
+        let mut expected = Vec::new();
+        let mut data = Vec::new();
+        {
+            let mut bld = PacketFrameBuilder::new(&mut expected);
+            bld.add_u8(1, 1);
+            for value in &values {
+                bld.add_data(2, value);
+            }
+        }
+        {
+            let mut bld = PacketFrameBuilder::new(&mut data);
+            bld.add_u8(1, 1);
+            assert_eq!(3, bld.add_data_iter(2, &values));
+            assert_eq!(0, bld.add_data_iter(3, core::iter::empty::<&[u8]>()));
+        }
+        assert_eq!(expected, data);
+
+        let mut bld = CanonicalFrameBuilder::new();
+        assert_eq!(
+            2,
+            bld.add_data_iter(2, values.iter().filter(|v| !v.is_empty()))
+        );
+        let mut frame = Vec::new();
+        bld.finish(&mut frame);
+        let parser = FrameParser::new(&frame).unwrap();
+        assert_eq!(2, parser.get_datas(2).count());
+    }
+
+    #[test]
+    fn add_raw_field_matches_add_data() {
+        let mut expected = Vec::new();
+        {
+            let mut bld = PacketFrameBuilder::new(&mut expected);
+            bld.add_data(1022, &[9, 255]);
+            bld.add_data(1023, &[]);
+        }
+        let source = PacketFrameParser::new(&expected).unwrap();
+
+        let mut data = Vec::new();
+        {
+            let mut bld = PacketFrameBuilder::new(&mut data);
+            assert_eq!((15, 2), bld.add_raw_field(source.field_raw(1022).unwrap()));
+            assert_eq!((23, 0), bld.add_raw_field(source.field_raw(1023).unwrap()));
+        }
+        assert_eq!(expected, data);
+
+        let mut bld = CanonicalFrameBuilder::new();
+        bld.add_raw_field(source.field_raw(1022).unwrap());
+        let mut frame = Vec::new();
+        bld.finish(&mut frame);
+        let parser = FrameParser::new(&frame).unwrap();
+        assert_eq!(Some(&[9, 255][..]), parser.get_data(1022));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "not a single encoded field")]
+    fn add_raw_field_checks_the_field_in_debug_builds() {
+        let mut data = Vec::new();
+        FrameBuilder::new(&mut data).add_raw_field(&[0, 12, 0, 0, 0, 2, 4]);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "field-count 2 does not match the fields written")]
+    fn builders_check_the_field_count_in_debug_builds() {
+        let mut data = Vec::new();
+        let mut bld = PacketFrameBuilder::new(&mut data);
+        bld.add_u8(1, 1);
+        bld.field_count += 1;
+        bld.finish();
+    }
+
+    #[test]
+    fn add_with_panics_if_the_value_is_not_only_appended() {
+        fn panic_message(write: fn(&mut Vec<u8>), new_builder: usize) -> String {
+            let err = std::panic::catch_unwind(|| {
+                let mut data = vec![9];
+                match new_builder {
+                    0 => FrameBuilder::new(&mut data).add_with(1, write),
+                    1 => FrameBuilder::new_large(&mut data).add_with(1, write),
+                    _ => PacketFrameBuilder::new(&mut data).add_with(1, write),
+                };
+            })
+            .unwrap_err();
+            match err.downcast_ref::<&str>() {
+                Some(message) => message.to_string(),
+                None => err.downcast_ref::<String>().cloned().unwrap_or_default(),
+            }
+        }
+        let writes: [fn(&mut Vec<u8>); 3] = [
+            |data| data.clear(),
+            |data| data.truncate(data.len() - 1),
+            |data| {
+                let len = data.len();
+                data[len - 1] = 7;
+                data.push(7);
+            },
+        ];
+        for &write in &writes {
+            for new_builder in 0..3 {
+                assert_eq!(
+                    "add_with must only append to the Vec it is given",
+                    panic_message(write, new_builder)
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "value could not be written")]
+    fn a_panic_while_adding_a_field_is_not_turned_into_an_abort() {
+        let mut data = Vec::new();
+        let mut bld = FrameBuilder::new(&mut data);
+        bld.add_frame(1).add_with(2, |value| {
+            value.push(3);
+            panic!("value could not be written");
+        });
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn child_frames_round_trip_in_frames_and_packet_frames() {
+        fn add_children<B: FrameBuilderLike>(bld: &mut B) {
+            bld.add_child(1).add_u8(2, 2);
+            bld.add_frame(3).add_child(4).add_u8(5, 5);
+        }
+        fn check_children(parser: &FrameParser<'_>) {
+            let child = parser.get_frame_required(1).unwrap();
+            assert_eq!(Some(2), child.get_u8(2).unwrap());
+            assert_eq!(child.raw_bytes(), parser.get_data(1).unwrap());
+            let grandchild = parser
+                .get_frame_required(3)
+                .unwrap()
+                .get_frame_required(4)
+                .unwrap();
+            assert_eq!(Some(5), grandchild.get_u8(5).unwrap());
+        }
+
+        let mut frame = Vec::new();
+        add_children(&mut FrameBuilder::new(&mut frame));
+        check_children(&FrameParser::new(&frame).unwrap());
+
+        let mut packet_frame = Vec::new();
+        add_children(&mut PacketFrameBuilder::new(&mut packet_frame));
+        check_children(&PacketFrameParser::new(&packet_frame).unwrap());
+        assert_eq!(&frame[..], &packet_frame[SIZE_BYTES..]);
+    }
+
+    #[test]
+    fn large_frames_have_64_bit_field_lengths() {
+        let mut data = vec![9];
+        {
+            let mut bld = FrameBuilder::new_large(&mut data);
+            assert_eq!((15, 2), bld.add_data(1, &[1, 2]));
+            assert_eq!((27, 1), bld.add_with(2, |v| v.push(3)));
+            assert_eq!((38, 1), bld.add_raw_field(&[0, 3, 0, 0, 0, 1, 4]));
+            bld.add_frame(4).add_u8(5, 5);
+            assert_eq!(Ok((71, 0)), bld.try_add_data(6, &[]));
+        }
+        let frame = &data[1..];
+        assert_eq!(Ok(frame.len()), frame_encoded_len(frame));
+        assert_eq!(Ok(()), validate_frame(frame));
+
+        let parser = FrameParser::new(frame).unwrap();
+        assert_eq!(FrameFormat::Format5, parser.format());
+        assert_eq!(5, parser.len());
+        assert_eq!(Some(&[1, 2][..]), parser.get_data(1));
+        assert_eq!(Some(3), parser.get_u8(2).unwrap());
+        assert_eq!(Some(4), parser.get_u8(3).unwrap());
+        let child = parser.get_frame_required(4).unwrap();
+        assert_eq!(FrameFormat::Format1, child.format());
+        assert_eq!(Some(5), child.get_u8(5).unwrap());
+        assert_eq!(
+            Some(&[0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 2][..]),
+            parser.field_raw(1)
+        );
+
+        let owned = parser.into_owned();
+        assert_eq!(frame, owned.as_bytes());
+    }
+
+    #[test]
+    fn children_of_large_frames_get_the_whole_64_bit_field_length() {
+        // the high half is filled in as well as the frame-size
+        let mut data = vec![0xFF; 4];
+        let mut bld = PacketFrameBuilder::new(&mut data);
+        bld.large = true;
+        bld.add_u8(1, 2);
+        assert_eq!(Ok(16), bld.try_finish());
+        assert_eq!(&[0, 0, 0, 0, 0, 0, 0, 12], &data[..8]);
+    }
+
+    #[test]
+    fn packet_frames_dropped_while_panicking_do_not_panic_again() {
+        let mut data = Vec::new();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut bld = PacketFrameBuilder::new(&mut data);
+            bld.add_u8(1, 2);
+            panic!("while building");
+        }));
+        assert!(result.is_err());
+        assert_eq!(&[0, 0, 0, 12, 1, 0, 0, 0, 1], &data[..9]);
+    }
+
+    #[test]
+    fn large_field_lengths_must_fit_the_data() {
+        let mut data = vec![5, 0, 0, 0, 1, 0, 1];
+        data.extend_from_slice(&u64::MAX.to_be_bytes());
+        data.push(0);
+        assert_eq!(
+            Some(Error::IncompleteFieldValue(usize::MAX, 1)),
+            FrameParser::new(&data).err()
+        );
+        assert_eq!(
+            Some(Error::IncompleteFieldTagOrLength),
+            FrameParser::new(&data[..14]).err()
+        );
+    }
+
+    #[test]
+    fn add_with_matches_add_data() {
+        let mut expected = vec![7];
+        let mut data = vec![7];
+        {
+            let mut bld = PacketFrameBuilder::new(&mut expected);
+            assert_eq!((15, 2), bld.add_data(1022, &[9, 255]));
+            assert_eq!((23, 0), bld.add_data(1023, &[]));
+            bld.add_frame(1).add_data(2, b"child");
+        }
+        {
+            let mut bld = PacketFrameBuilder::new(&mut data);
+            assert_eq!(
+                (15, 2),
+                bld.add_with(1022, |v| v.extend_from_slice(&[9, 255]))
+            );
+            assert_eq!((23, 0), bld.add_with(1023, |_| ()));
+            bld.add_frame(1)
+                .add_with(2, |v| v.extend_from_slice(b"child"));
+        }
+        assert_eq!(expected, data);
+
+        let mut bld = CanonicalFrameBuilder::new();
+        assert_eq!(
+            (0, 2),
+            bld.add_with(1022, |v| v.extend_from_slice(&[9, 255]))
+        );
+        let mut data = Vec::new();
+        bld.finish(&mut data);
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(&[9, 255][..]), frame.get_data(1022));
+    }
+
+    #[test]
+    fn can_locate_added_values_in_frame() {
+        let mut data = vec![9, 9];
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            assert_eq!((11, 2), bld.add_data(1022, &[9, 255]));
+            assert_eq!((19, 4), bld.add_u32(1023, 7));
+            assert_eq!((29, 5), bld.add_str(1024, "hello"));
+        }
+        let frame = &data[2..];
+        assert_eq!(&[9, 255], &frame[11..13]);
+        assert_eq!(&[0, 0, 0, 7], &frame[19..23]);
+        assert_eq!(b"hello", &frame[29..34]);
+    }
+
+    #[test]
+    fn can_locate_added_values_in_packet_frame() {
+        let mut data = vec![9, 9];
+        {
+            let mut bld = PacketFrameBuilder::new(&mut data);
+            assert_eq!((15, 2), bld.add_data(1022, &[9, 255]));
+            assert_eq!((23, 1), bld.add_bool(1023, true));
+        }
+        let packet = &data[2..];
+        assert_eq!(&[9, 255], &packet[15..17]);
+        assert_eq!(&[255], &packet[23..24]);
+    }
+
+    #[test]
+    fn can_add_child_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            let mut child_bld = bld.add_frame(1022);
+            child_bld.add_data(60, &[9, 255]);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 13, // child frame size
+                1,  // child frame format
+                0, 0, 0, 1, // child frame field count
+                0, 60, // field-tag in child frame
+                0, 0, 0, 2, // field-length in child frame
+                9, 255 // field-value in child frame
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_child_to_packet_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = PacketFrameBuilder::new(&mut data);
+            let mut child_bld = bld.add_frame(1022);
+            child_bld.add_data(60, &[9, 255]);
+        }
+        assert_eq!(
+            &[
+                0, 0, 0, 24, // packet size
+                1,  // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 13, // child frame size
+                1,  // child frame format
+                0, 0, 0, 1, // child frame field count
+                0, 60, // field-tag in child frame
+                0, 0, 0, 2, // field-length in child frame
+                9, 255 // field-value in child frame
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_bool_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_bool(1022, true);
+            bld.add_bool(1021, false);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 2, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 1,   // field length = 2
+                255, // field value
+                3, 253, // tag = 1021
+                0, 0, 0, 1, // field length = 2
+                0  // field value
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_u8_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(1022, 89);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 1,  // field length = 2
+                89  // field value
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_u16_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u16(1022, 1009);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 2, // field length = 2
+                3, 241 // field value (1009)
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_u32_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u32(1022, 156090);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 4, // field length = 2
+                0, 2, 97, 186 // field value (156090)
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_u64_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u64(1022, 156234234090);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 8, // field length = 2
+                0, 0, 0, 36, 96, 73, 56, 234 // field value (156234234090)
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_i8_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_i8(1022, -89);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 1,   // field length = 1
+                167  // field value (-89)
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_i16_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_i16(1022, -1009);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 2, // field length = 2
+                252, 15 // field value (-1009)
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_i32_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_i32(1022, -156090);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 4, // field length = 4
+                255, 253, 158, 70 // field value (-156090)
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_i64_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_i64(1022, -156234234090);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 8, // field length = 8
+                255, 255, 255, 219, 159, 182, 199, 22 // field value (-156234234090)
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_f32_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_f32(1022, -0.15625);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 4, // field length = 4
+                190, 32, 0, 0 // field value (-0.15625)
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_f64_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_f64(1022, -0.15625);
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 8, // field length = 8
+                191, 196, 0, 0, 0, 0, 0, 0 // field value (-0.15625)
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_utf8_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_str(1022, "hello");
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 1, // field count = 1
+                3, 254, // tag = 1022
+                0, 0, 0, 5, // field length = 2
+                104, 101, 108, 108, 111 // field value (156234234090)
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn can_add_char_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_char(1022, 'a');
+            bld.add_char(1021, '🦀');
+        }
+        assert_eq!(
+            &[
+                1, // frame format
+                0, 0, 0, 2, // field count = 2
+                3, 254, // tag = 1022
+                0, 0, 0, 1,  // field length = 1
+                97, // field value ('a')
+                3, 253, // tag = 1021
+                0, 0, 0, 4, // field length = 4
+                240, 159, 166, 128 // field value ('🦀')
+            ],
+            &data[..]
+        );
+    }
+
+    #[test]
+    fn frame_builder_with_capacity_does_not_reallocate() {
+        let mut data = vec![9];
+        let capacity = {
+            let mut bld =
+                FrameBuilder::with_capacity(&mut data, 3, 4 + 2 + estimate_frame_size(1, 1));
+            let capacity = bld.data.capacity();
+            bld.add_u32(1, 1);
+            bld.add_str(2, "hi");
+            bld.add_frame(3).add_u8(4, 4);
+            capacity
+        };
+        assert_eq!(capacity, data.capacity());
+        assert_eq!(
+            1 + estimate_frame_size(3, 4 + 2 + estimate_frame_size(1, 1)),
+            data.len()
+        );
+    }
+
+    #[test]
+    fn add_optional_methods_only_add_some_values() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            assert_eq!(Some((11, 4)), bld.add_optional_u32(1, Some(7)));
+            assert_eq!(None, bld.add_optional_u32(2, None));
+            assert_eq!(Some((21, 5)), bld.add_optional_str(3, Some("hello")));
+            assert_eq!(None, bld.add_optional_str::<&str>(4, None));
+            assert_eq!(None, bld.add_optional_bool(5, None));
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(7), frame.get_u32(1).unwrap());
+        assert_eq!(None, frame.get_u32(2).unwrap());
+        assert_eq!(Some("hello"), frame.get_str(3).unwrap());
+        assert_eq!(vec![1, 3], frame.tags().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn with_methods_match_add_methods() {
+        let mut added = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut added);
+            bld.add_data(1, &[1, 2]);
+            bld.add_bool(2, true);
+            bld.add_u8(3, 3);
+            bld.add_u16(4, 4);
+            bld.add_u32(5, 5);
+            bld.add_u64(6, 6);
+            bld.add_u128(7, 7);
+            bld.add_i8(8, -8);
+            bld.add_i16(9, -9);
+            bld.add_i32(10, -10);
+            bld.add_i64(11, -11);
+            bld.add_i128(12, -12);
+            bld.add_f32(13, 1.5);
+            bld.add_f64(14, -1.5);
+            bld.add_frame(15).add_str(16, "hello");
+        }
+
+        let mut chained = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut chained);
+            bld.with_data(1, &[1, 2])
+                .with_bool(2, true)
+                .with_u8(3, 3)
+                .with_u16(4, 4)
+                .with_u32(5, 5)
+                .with_u64(6, 6)
+                .with_u128(7, 7)
+                .with_i8(8, -8)
+                .with_i16(9, -9)
+                .with_i32(10, -10)
+                .with_i64(11, -11)
+                .with_i128(12, -12)
+                .with_f32(13, 1.5)
+                .with_f64(14, -1.5)
+                .add_frame(15)
+                .with_str(16, "hello");
+        }
+
+        assert_eq!(added, chained);
+    }
+
+    #[test]
+    fn can_not_parse_a_frame_if_there_is_not_enough_data_for_frame_format() {
+        let data = &[]; // need four bytes for a field count.
+        assert_eq!(
+            Some(Error::IncompleteFrameFormat),
+            FrameParser::new(data).err()
+        );
+    }
+
+    #[test]
+    fn can_not_parse_a_frame_if_frame_format_is_not_recognized() {
+        let data = &[8]; // need four bytes for a field count.
+        assert_eq!(
+            Some(Error::InvalidFrameFormat(8)),
+            FrameParser::new(data).err()
+        );
+    }
+
+    #[test]
+    fn can_parse_a_checked_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new_checked(&mut data);
+            bld.add_str(1, "hello");
+            bld.add_frame(2).add_u8(3, 4);
+        }
+        assert_eq!(2, data[0]);
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some("hello"), frame.get_str(1).unwrap());
+        let child = frame.get_frame_required(2).unwrap();
+        assert_eq!(Some(4), child.get_u8(3).unwrap());
+        assert_eq!(FrameFormat::Format2, frame.format());
+        assert_eq!(FrameFormat::Format1, child.format());
+        assert_eq!(Ok(data.len()), frame_encoded_len(&data));
+
+        data.push(9);
+        let (_, tail) = FrameParser::parse_prefix(&data).unwrap();
+        assert_eq!(&[9], tail);
+    }
+
+    #[test]
+    fn can_not_parse_a_checked_frame_with_a_bad_checksum() {
+        let mut data = Vec::new();
+        FrameBuilder::new_checked(&mut data).add_u32(1, 1);
+        let checksum_pos = data.len() - 4;
+        let expected = u32::from_be_bytes(data[checksum_pos..].try_into().unwrap());
+
+        data[checksum_pos - 1] = 2;
+        let actual = crc32(&data[..checksum_pos]);
+        assert_eq!(
+            Some(Error::ChecksumMismatch { expected, actual }),
+            FrameParser::new(&data).err()
+        );
+        assert_eq!(
+            Err(Error::ChecksumMismatch { expected, actual }),
+            frame_encoded_len(&data)
+        );
+
+        assert_eq!(
+            Some(Error::IncompleteFrameChecksum),
+            FrameParser::new(&data[..checksum_pos + 3]).err()
+        );
+    }
+
+    #[test]
+    fn crc32_matches_the_standard_check_value() {
+        assert_eq!(0xCBF4_3926, crc32(b"123456789"));
+        assert_eq!(0, crc32(&[]));
+    }
+
+    #[test]
+    fn can_not_parse_a_frame_if_there_is_not_enough_data_for_field_count() {
+        let data = &[1, 0, 0, 0]; // need four bytes for a field count.
+        assert_eq!(
+            Some(Error::IncompleteFrameFieldCount),
+            FrameParser::new(data).err()
+        );
+    }
+
+    #[test]
+    fn can_not_parse_a_frame_if_there_is_not_enough_data_for_field_tag_and_length() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 1, // field count = 1
+            0, 1, // tag = 1
+            0, 0, 0, // incomplete field length
+        ];
+        assert_eq!(
+            Some(Error::IncompleteFieldTagOrLength),
+            FrameParser::new(data).err()
+        );
+    }
+
+    #[test]
+    fn can_not_parse_a_frame_if_there_is_not_enough_data_for_a_field_value() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 1, // field count = 1
+            0, 1, // tag = 1
+            0, 0, 0, 4, // field length = 4
+            1, 2, 3, // incomplete value
+        ];
+        assert_eq!(
+            Some(Error::IncompleteFieldValue(4, 3)),
+            FrameParser::new(data).err()
+        );
+    }
+
+    #[test]
+    fn can_not_parse_a_frame_if_there_is_excess_data() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 1, // field count = 1
+            0, 1, // tag = 1
+            0, 0, 0, 4, // field length = 4
+            1, 2, 3, 4, // incomplete value
+            5, // excess data
+        ];
+        assert_eq!(Some(Error::UnexpectedData), FrameParser::new(data).err());
+    }
+
+    #[test]
+    fn can_parse_a_frame_followed_by_other_data() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 1, // field count = 1
+            0, 1, // tag = 1
+            0, 0, 0, 1, // field length = 1
+            9, // value
+            1, 0, // excess data
+        ];
+        let (frame, tail) = FrameParser::parse_prefix(data).unwrap();
+        assert_eq!(Some(9), frame.get_u8(1).unwrap());
+        assert_eq!(&[1, 0], tail);
+        assert_eq!(
+            Some(Error::IncompleteFrameFieldCount),
+            FrameParser::parse_prefix(tail).err()
+        );
+    }
+
+    #[test]
+    fn can_find_the_encoded_len_of_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_str(1, "hello");
+            bld.add_frame(2).add_u8(3, 3);
+        }
+        let frame_len = data.len();
+        data.extend_from_slice(&[9; 10]);
+        assert_eq!(Ok(frame_len), frame_encoded_len(&data));
+        assert_eq!(
+            Some(Error::IncompleteFieldValue(12, 8)),
+            frame_encoded_len(&data[..30]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompleteFieldTagOrLength),
+            frame_encoded_len(&data[..13]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompleteFieldTagOrLength),
+            frame_encoded_len(&[1, 255, 255, 255, 255]).err()
+        );
+    }
+
+    #[test]
+    fn can_not_parse_a_frame_with_a_hostile_field_count() {
+        // must fail on the missing data rather than reserving space for 4 billion fields
+        let data = &[
+            1, // frame format
+            255, 255, 255, 255, // field count = u32::MAX
+        ];
+        assert_eq!(
+            Some(Error::IncompleteFieldTagOrLength),
+            FrameParser::new(data).err()
+        );
+    }
+
+    #[test]
+    fn can_not_parse_a_frame_with_more_fields_than_allowed() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 2, // field count = 2
+            0, 1, // tag = 1
+            0, 0, 0, 0, // field length = 0
+            0, 2, // tag = 2
+            0, 0, 0, 0, // field length = 0
+        ];
+        let options = ParseOptions {
+            max_field_count: 1,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            Some(Error::FieldCountTooLarge(2)),
+            FrameParser::new_with_options(data, options).err()
+        );
+
+        let options = ParseOptions {
+            max_field_count: 2,
+            ..ParseOptions::default()
+        };
+        assert!(FrameParser::new_with_options(data, options).is_ok());
+    }
+
+    #[test]
+    fn child_frames_inherit_the_max_field_count() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            let mut child_bld = bld.add_frame(1);
+            child_bld.add_u8(2, 1);
+            child_bld.add_u8(3, 2);
+        }
+        let options = ParseOptions {
+            max_field_count: 1,
+            ..ParseOptions::default()
+        };
+        let frame = FrameParser::new_with_options(&data, options).unwrap();
+        assert_eq!(options, frame.options());
+        assert_eq!(Some(Error::FieldCountTooLarge(2)), frame.get_frame(1).err());
+    }
+
+    fn build_nested_frames(data: &mut Vec<u8>, depth: usize) {
+        let mut bld = FrameBuilder::new(data);
+        fn nest<B: FrameBuilderLike>(bld: &mut B, depth: usize) {
+            if depth > 0 {
+                nest(&mut bld.add_frame(1), depth - 1);
+            }
+        }
+        nest(&mut bld, depth);
+    }
+
+    #[test]
+    fn can_not_read_frames_nested_deeper_than_allowed() {
+        let mut data = Vec::new();
+        build_nested_frames(&mut data, 100);
+
+        let mut frame = FrameParser::new(&data).unwrap();
+        for depth in 1..=64 {
+            frame = frame.get_frame_required(1).unwrap();
+            assert_eq!(depth, frame.depth());
+        }
+        assert_eq!(Some(Error::MaxDepthExceeded(65)), frame.get_frame(1).err());
+        assert_eq!(
+            Some(Error::MaxDepthExceeded(65)),
+            frame.get_frame_required(1).err()
+        );
+        assert_eq!(
+            Some(Error::MaxDepthExceeded(65)),
+            frame.get_frames(1).next().unwrap().err()
+        );
+    }
+
+    #[test]
+    fn can_configure_max_depth() {
+        let mut data = Vec::new();
+        build_nested_frames(&mut data, 3);
+
+        let options = ParseOptions {
+            max_depth: 1,
+            ..ParseOptions::default()
+        };
+        let frame = FrameParser::new_with_options(&data, options).unwrap();
+        let child_frame = frame.get_frame(1).unwrap().unwrap();
+        assert_eq!(
+            Some(Error::MaxDepthExceeded(2)),
+            child_frame.get_frame(1).err()
+        );
+    }
+
+    #[test]
+    fn children_are_limited_by_max_depth() {
+        let mut data = Vec::new();
+        build_nested_frames(&mut data, 3);
+
+        let options = ParseOptions {
+            max_depth: 1,
+            ..ParseOptions::default()
+        };
+        let frame = FrameParser::new_with_options(&data, options).unwrap();
+        let (tag, child_frame) = frame.children().next().unwrap();
+        assert_eq!(1, tag);
+        let child_frame = child_frame.unwrap();
+        let (_, grandchild) = child_frame.children().next().unwrap();
+        assert_eq!(Some(Error::MaxDepthExceeded(2)), grandchild.err());
+    }
+
+    #[test]
+    fn can_reject_duplicate_tags() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(1, 1);
+            bld.add_u8(2, 2);
+            bld.add_u8(2, 3);
+            bld.add_frame(3).add_u8(4, 4);
+        }
+        let strict = ParseOptions {
+            reject_duplicate_tags: true,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            Some(Error::DuplicateTag(2)),
+            FrameParser::new_with_options(&data, strict).err()
+        );
+        assert!(FrameParser::new(&data).is_ok());
+
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            let mut child_bld = bld.add_frame(1);
+            child_bld.add_u8(2, 2);
+            child_bld.add_u8(2, 3);
+        }
+        let frame = FrameParser::new_with_options(&data, strict).unwrap();
+        assert_eq!(Some(Error::DuplicateTag(2)), frame.get_frame(1).err());
+    }
+
+    #[test]
+    fn indexed_frames_read_the_same_fields() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(1, 1);
+            bld.add_u8(2, 2);
+            bld.add_u8(1, 3);
+            bld.add_frame(3).add_u8(4, 4);
+        }
+
+        let frame = FrameParser::new_indexed(&data).unwrap();
+        assert!(frame.index.is_some());
+        assert_eq!(Some(1), frame.get_u8(1).unwrap());
+        assert_eq!(Some(2), frame.get_u8(2).unwrap());
+        assert_eq!(None, frame.get_u8(5).unwrap());
+        let values: Vec<u8> = frame.get_u8s(1).map(|v| v.unwrap()).collect();
+        assert_eq!(vec![1, 3], values);
+        assert_eq!(0, frame.get_datas(5).count());
+
+        let child_frame = frame.get_frame(3).unwrap().unwrap();
+        assert!(child_frame.index.is_some());
+        assert_eq!(Some(4), child_frame.get_u8(4).unwrap());
+
+        assert!(FrameParser::new(&data).unwrap().index.is_none());
+    }
+
+    #[test]
+    fn unique_fields_are_checked_with_and_without_an_index() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(1, 1);
+            bld.add_u8(2, 2);
+            bld.add_u8(1, 3);
+        }
+        for frame in [
+            FrameParser::new(&data).unwrap(),
+            FrameParser::new_indexed(&data).unwrap(),
+        ] {
+            assert_eq!(Ok(2), frame.get_unique::<u8>(2));
+            assert_eq!(Err(Error::DuplicateTag(1)), frame.get_unique::<u8>(1));
+            assert_eq!(Err(Error::MissingField(3)), frame.get_data_unique(3));
+            assert_eq!(
+                Err(Error::IncompatibleFieldValue),
+                frame.get_unique::<bool>(2)
+            );
+        }
+    }
+
+    #[test]
+    fn can_read_data_from_frame() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 1, // field count = 1
+            0, 1, // tag = 1
+            0, 0, 0, 4, // field length = 4
+            1, 2, 3, 4, //
+        ];
+        let frame = FrameParser::new(data).unwrap();
+        assert_eq!(&[1, 2, 3, 4], frame.get_data(1).unwrap());
+    }
+
+    #[test]
+    fn can_read_datas_from_a_frame() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 3, // field count = 3
+            0, 1, // tag = 1
+            0, 0, 0, 2, // field length = 2
+            10, 11, //
+            0, 2, // tag = 2, will be skipped
+            0, 0, 0, 2, // field length = 2
+            20, 22, //
+            0, 1, // tag = 1
+            0, 0, 0, 2, // field length = 2
+            30, 33, //
+        ];
+        let frame = FrameParser::new(data).unwrap();
+        let expected = vec![&[10, 11], &[30, 33]];
+        let actual: Vec<&[u8]> = frame.get_datas(1).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn can_read_owned_bytes_from_a_frame() {
+        let values = {
+            let mut data = Vec::new();
+            {
+                let mut bld = FrameBuilder::new(&mut data);
+                bld.add_data(1, &[10, 11]);
+                bld.add_data(2, &[20]); // will be ignored
+                bld.add_data(1, &[]);
+            }
+            let frame = FrameParser::new(&data).unwrap();
+            assert_eq!(Some(vec![10, 11]), frame.get_bytes(1));
+            assert_eq!(None, frame.get_bytes(3));
+            frame.get_bytes_all(1).collect::<Vec<_>>()
+        };
+        assert_eq!(vec![vec![10, 11], vec![]], values);
+    }
+
+    #[test]
+    fn can_read_all_fields_from_a_frame() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 3, // field count = 3
+            0, 1, // tag = 1
+            0, 0, 0, 2, // field length = 2
+            10, 11, //
+            0, 2, // tag = 2
+            0, 0, 0, 0, // field length = 0
+            0, 1, // tag = 1
+            0, 0, 0, 1,  // field length = 1
+            30, //
+        ];
+        let frame = FrameParser::new(data).unwrap();
+        let expected: Vec<(u16, &[u8])> = vec![(1, &[10, 11]), (2, &[]), (1, &[30])];
+        let actual: Vec<(u16, &[u8])> = frame.fields().collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn can_read_distinct_tags_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(3, 1);
+            bld.add_u8(1, 2);
+            bld.add_u8(3, 3);
+            bld.add_u8(2, 4);
+            bld.add_u8(1, 5);
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        let actual: Vec<u16> = frame.tags().collect();
+        assert_eq!(vec![3, 1, 2], actual);
+    }
+
+    #[test]
+    fn can_read_no_fields_from_an_empty_frame() {
+        let data = &[1, 0, 0, 0, 0];
+        let frame = FrameParser::new(data).unwrap();
+        assert_eq!(0, frame.fields().count());
+        assert_eq!(0, frame.tags().count());
+    }
+
+    #[test]
+    fn can_attempt_to_read_data_from_a_frame_if_it_is_not_there() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 1, // field count = 1
+            0, 1, // tag = 1
+            0, 0, 0, 4, // field length = 4
+            1, 2, 3, 4, //
+        ];
+        let frame = FrameParser::new(data).unwrap();
+        assert_eq!(None, frame.get_data(3));
+    }
+
+    #[test]
+    fn can_not_decode_u8_with_zero_bytes() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_u8(&[]).err()
+        );
+    }
+
+    #[test]
+    fn can_decode_compatible_values_into_u8() {
+        assert_eq!(Ok(8), decode_u8(&[8]));
+    }
+
+    #[test]
+    fn can_not_decode_incompatible_values_into_u8() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(2)),
+            decode_u8(&[0, 8]).err()
+        );
+
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(4)),
+            decode_u8(&[0, 0, 0, 8]).err()
+        );
+
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(8)),
+            decode_u8(&[0, 0, 0, 0, 0, 0, 0, 8]).err()
+        );
+
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(2)),
+            decode_u8(&[1, 8]).err()
+        );
+
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(4)),
+            decode_u8(&[0, 0, 1, 8]).err()
+        );
+
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(8)),
+            decode_u8(&[0, 0, 0, 0, 0, 0, 1, 8]).err()
+        );
+    }
+
+    #[test]
+    fn can_read_u8_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(100, 250);
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(250), frame.get_u8(100).unwrap());
+    }
+
+    #[test]
+    fn can_read_u8s_from_a_frame() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 3, // field count = 3
+            0, 1, // tag = 1
+            0, 0, 0, 1,  // field length = 2
+            10, //
+            0, 2, // tag = 2, will be skipped
+            0, 0, 0, 1,  // field length = 2
+            20, //
+            0, 1, // tag = 1
+            0, 0, 0, 1,  // field length = 2
+            30, //
+        ];
+        let frame = FrameParser::new(data).unwrap();
+        let expected: Vec<Result<u8>> = vec![Ok(10), Ok(30)];
+        let actual: Vec<Result<u8>> = frame.get_u8s(1).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn can_not_decode_u16_with_zero_bytes() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_u16(&[]).err()
+        );
+    }
+
+    #[test]
+    fn can_decode_compatible_values_into_u16() {
+        assert_eq!(Ok(8), decode_u16(&[8]));
+        assert_eq!(Ok(3080), decode_u16(&[12, 8]));
+    }
+
+    #[test]
+    fn can_not_decode_incompatible_values_into_u16() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(4)),
+            decode_u16(&[0, 0, 12, 8]).err()
+        );
+
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(4)),
+            decode_u16(&[0, 1, 255, 255]).err()
+        );
+
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(8)),
+            decode_u16(&[0, 0, 0, 0, 0, 0, 0, 1]).err()
+        );
+    }
+
+    #[test]
+    fn can_read_u16_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(100, 90);
+            bld.add_u16(200, 1025);
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(90), frame.get_u16(100).unwrap());
+        assert_eq!(Some(1025), frame.get_u16(200).unwrap());
+    }
+
+    #[test]
+    fn can_read_u16s_from_a_frame() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 3, // field count = 3
+            0, 1, // tag = 1
+            0, 0, 0, 1,  // field length = 2
+            10, //
+            0, 2, // tag = 2, will be skipped
+            0, 0, 0, 1,  // field length = 2
+            20, //
+            0, 1, // tag = 1
+            0, 0, 0, 1,  // field length = 2
+            30, //
+        ];
+        let frame = FrameParser::new(data).unwrap();
+        let expected: Vec<Result<u16>> = vec![Ok(10), Ok(30)];
+        let actual: Vec<Result<u16>> = frame.get_u16s(1).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn can_not_decode_u32_with_zero_bytes() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_u32(&[]).err()
+        );
+    }
+
+    #[test]
+    fn can_decode_compatible_values_into_u32() {
+        assert_eq!(Ok(8), decode_u32(&[8]));
+        assert_eq!(Ok(3080), decode_u32(&[12, 8]));
+        assert_eq!(Ok(1744964616), decode_u32(&[104, 2, 12, 8]));
+    }
+
+    #[test]
+    fn can_not_decode_incompatible_values_into_u32() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(8)),
+            decode_u32(&[0, 0, 0, 0, 104, 2, 12, 8]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(8)),
+            decode_u32(&[0, 0, 0, 1, 255, 255, 255, 255]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(16)),
+            decode_u32(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]).err()
+        );
+    }
+
+    #[test]
+    fn can_read_u32_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(100, 90);
+            bld.add_u16(200, 1025);
+            bld.add_u32(300, 1744964616);
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(90), frame.get_u32(100).unwrap());
+        assert_eq!(Some(1025), frame.get_u32(200).unwrap());
+        assert_eq!(Some(1744964616), frame.get_u32(300).unwrap());
+    }
+
+    #[test]
+    fn can_read_u32s_from_a_frame() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 3, // field count = 3
+            0, 1, // tag = 1
+            0, 0, 0, 1,  // field length = 2
+            10, //
+            0, 2, // tag = 2, will be skipped
+            0, 0, 0, 1,  // field length = 2
+            20, //
+            0, 1, // tag = 1
+            0, 0, 0, 1,  // field length = 2
+            30, //
+        ];
+        let frame = FrameParser::new(data).unwrap();
+        let expected: Vec<Result<u32>> = vec![Ok(10), Ok(30)];
+        let actual: Vec<Result<u32>> = frame.get_u32s(1).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn can_not_decode_u64_with_zero_bytes() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_u64(&[]).err()
+        );
+    }
+
+    #[test]
+    fn can_decode_compatible_values_into_u64() {
+        assert_eq!(Ok(8), decode_u64(&[8]));
+        assert_eq!(Ok(3080), decode_u64(&[12, 8]));
+        assert_eq!(Ok(1744964616), decode_u64(&[104, 2, 12, 8]));
+        assert_eq!(
+            Ok(150626523450313736),
+            decode_u64(&[2, 23, 34, 6, 104, 2, 12, 8])
+        );
+    }
+
+    #[test]
+    fn can_read_u64_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(100, 90);
+            bld.add_u16(200, 1025);
+            bld.add_u32(300, 1744964616);
+            bld.add_u64(400, 150626523450313736);
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(90), frame.get_u64(100).unwrap());
+        assert_eq!(Some(1025), frame.get_u64(200).unwrap());
+        assert_eq!(Some(1744964616), frame.get_u64(300).unwrap());
+        assert_eq!(Some(150626523450313736), frame.get_u64(400).unwrap());
+    }
+
+    #[test]
+    fn can_read_u64s_from_a_frame() {
+        let data = &[
+            1, // frame format
+            0, 0, 0, 3, // field count = 3
+            0, 1, // tag = 1
+            0, 0, 0, 1,  // field length = 2
+            10, //
+            0, 2, // tag = 2, will be skipped
+            0, 0, 0, 1,  // field length = 2
+            20, //
+            0, 1, // tag = 1
+            0, 0, 0, 1,  // field length = 2
+            30, //
+        ];
+        let frame = FrameParser::new(data).unwrap();
+        let expected: Vec<Result<u64>> = vec![Ok(10), Ok(30)];
+        let actual: Vec<Result<u64>> = frame.get_u64s(1).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn can_not_decode_i8_with_zero_bytes() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_i8(&[]).err()
+        );
+    }
+
+    #[test]
+    fn can_decode_compatible_values_into_i8() {
+        assert_eq!(Ok(8), decode_i8(&[8]));
+        assert_eq!(Ok(-8), decode_i8(&[248]));
+        assert_eq!(Ok(i8::MIN), decode_i8(&[128]));
+    }
+
+    #[test]
+    fn can_not_decode_incompatible_values_into_i8() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(2)),
+            decode_i8(&[255, 248]).err()
+        );
+
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(4)),
+            decode_i8(&[0, 0, 0, 8]).err()
+        );
+
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(8)),
+            decode_i8(&[0, 0, 0, 0, 0, 0, 0, 8]).err()
+        );
+    }
+
+    #[test]
+    fn can_read_i8_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_i8(100, -120);
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(-120), frame.get_i8(100).unwrap());
+    }
+
+    #[test]
+    fn can_read_i8s_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_i8(1, -10);
+            bld.add_i8(2, 20); // will be ignored
+            bld.add_i8(1, 30);
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        let expected: Vec<Result<i8>> = vec![Ok(-10), Ok(30)];
+        let actual: Vec<Result<i8>> = frame.get_i8s(1).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn can_not_decode_i16_with_zero_bytes() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_i16(&[]).err()
+        );
+    }
+
+    #[test]
+    fn can_decode_compatible_values_into_i16() {
+        assert_eq!(Ok(8), decode_i16(&[8]));
+        assert_eq!(Ok(-8), decode_i16(&[248]));
+        assert_eq!(Ok(3080), decode_i16(&[12, 8]));
+        assert_eq!(Ok(-3080), decode_i16(&[243, 248]));
+    }
+
+    #[test]
+    fn can_not_decode_incompatible_values_into_i16() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(4)),
+            decode_i16(&[255, 255, 243, 248]).err()
+        );
+
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(8)),
+            decode_i16(&[0, 0, 0, 0, 0, 0, 0, 1]).err()
+        );
     }
-}
 
-fn decode_u8(value: &[u8]) -> Result<u8> {
-    match value.len() {
-        1 => Ok(value[0]),
+    #[test]
+    fn can_read_i16_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_i8(100, -90);
+            bld.add_i16(200, -1025);
+        }
 
-        _ => Err(Error::IncompatibleFieldLength(value.len())),
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(-90), frame.get_i16(100).unwrap());
+        assert_eq!(Some(-1025), frame.get_i16(200).unwrap());
     }
-}
 
-fn decode_u16(value: &[u8]) -> Result<u16> {
-    match value.len() {
-        1 => Ok(value[0] as u16),
+    #[test]
+    fn can_read_i16s_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_i16(1, -10);
+            bld.add_i16(2, 20); // will be ignored
+            bld.add_i8(1, -30);
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        let expected: Vec<Result<i16>> = vec![Ok(-10), Ok(-30)];
+        let actual: Vec<Result<i16>> = frame.get_i16s(1).collect();
+        assert_eq!(expected, actual);
+    }
 
-        2 => Ok(u16::from_be_bytes(value.try_into().unwrap())),
+    #[test]
+    fn can_not_decode_i32_with_zero_bytes() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_i32(&[]).err()
+        );
+    }
 
-        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    #[test]
+    fn can_decode_compatible_values_into_i32() {
+        assert_eq!(Ok(-8), decode_i32(&[248]));
+        assert_eq!(Ok(-3080), decode_i32(&[243, 248]));
+        assert_eq!(Ok(1744964616), decode_i32(&[104, 2, 12, 8]));
+        assert_eq!(Ok(-1744964616), decode_i32(&[151, 253, 243, 248]));
     }
-}
 
-fn decode_u32(value: &[u8]) -> Result<u32> {
-    match value.len() {
-        1 => Ok(value[0] as u32),
+    #[test]
+    fn can_not_decode_incompatible_values_into_i32() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(8)),
+            decode_i32(&[255, 255, 255, 255, 151, 253, 243, 248]).err()
+        );
+    }
 
-        2 => Ok(u16::from_be_bytes(value.try_into().unwrap()) as u32),
+    #[test]
+    fn can_read_i32_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_i8(100, -90);
+            bld.add_i16(200, -1025);
+            bld.add_i32(300, -1744964616);
+        }
 
-        4 => Ok(u32::from_be_bytes(value.try_into().unwrap())),
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(-90), frame.get_i32(100).unwrap());
+        assert_eq!(Some(-1025), frame.get_i32(200).unwrap());
+        assert_eq!(Some(-1744964616), frame.get_i32(300).unwrap());
+    }
 
-        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    #[test]
+    fn can_read_i32s_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_i32(1, -10);
+            bld.add_i32(2, 20); // will be ignored
+            bld.add_i32(1, 30);
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        let expected: Vec<Result<i32>> = vec![Ok(-10), Ok(30)];
+        let actual: Vec<Result<i32>> = frame.get_i32s(1).collect();
+        assert_eq!(expected, actual);
     }
-}
 
-fn decode_u64(value: &[u8]) -> Result<u64> {
-    match value.len() {
-        1 => Ok(value[0] as u64),
+    #[test]
+    fn can_not_decode_i64_with_zero_bytes() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_i64(&[]).err()
+        );
+    }
 
-        2 => Ok(u16::from_be_bytes(value.try_into().unwrap()) as u64),
+    #[test]
+    fn can_decode_compatible_values_into_i64() {
+        assert_eq!(Ok(-8), decode_i64(&[248]));
+        assert_eq!(Ok(-3080), decode_i64(&[243, 248]));
+        assert_eq!(Ok(-1744964616), decode_i64(&[151, 253, 243, 248]));
+        assert_eq!(
+            Ok(-150626523450313736),
+            decode_i64(&[253, 232, 221, 249, 151, 253, 243, 248])
+        );
+    }
 
-        4 => Ok(u32::from_be_bytes(value.try_into().unwrap()) as u64),
+    #[test]
+    fn can_read_i64_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_i8(100, -90);
+            bld.add_i16(200, -1025);
+            bld.add_i32(300, -1744964616);
+            bld.add_i64(400, -150626523450313736);
+        }
 
-        8 => Ok(u64::from_be_bytes(value.try_into().unwrap())),
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(-90), frame.get_i64(100).unwrap());
+        assert_eq!(Some(-1025), frame.get_i64(200).unwrap());
+        assert_eq!(Some(-1744964616), frame.get_i64(300).unwrap());
+        assert_eq!(Some(-150626523450313736), frame.get_i64(400).unwrap());
+    }
 
-        _ => Err(Error::IncompatibleFieldLength(value.len())),
+    #[test]
+    fn can_read_i64s_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_i64(1, -10);
+            bld.add_i64(2, 20); // will be ignored
+            bld.add_i64(1, 30);
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        let expected: Vec<Result<i64>> = vec![Ok(-10), Ok(30)];
+        let actual: Vec<Result<i64>> = frame.get_i64s(1).collect();
+        assert_eq!(expected, actual);
     }
-}
 
-fn decode_bool(value: &[u8]) -> Result<bool> {
-    if value.len() != 1 {
-        return Err(Error::IncompatibleFieldLength(value.len()));
+    #[test]
+    fn can_decode_compatible_values_into_f32() {
+        assert_eq!(Ok(1.5), decode_f32(&[63, 192, 0, 0]));
+        assert_eq!(Ok(f32::INFINITY), decode_f32(&[127, 128, 0, 0]));
+        assert_eq!(Ok(f32::NEG_INFINITY), decode_f32(&[255, 128, 0, 0]));
     }
-    match value[0] {
-        0x00 => Ok(false),
-        0xFF => Ok(true),
-        _ => Err(Error::IncompatibleFieldValue),
+
+    #[test]
+    fn can_not_decode_incompatible_values_into_f32() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_f32(&[]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(2)),
+            decode_f32(&[63, 192]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(8)),
+            decode_f32(&[63, 248, 0, 0, 0, 0, 0, 0]).err()
+        );
     }
-}
 
-fn decode_str(value: &[u8]) -> Result<&str> {
-    std::str::from_utf8(value).map_err(|_| Error::IncompatibleFieldValue)
-}
+    #[test]
+    fn can_decode_compatible_values_into_f64() {
+        assert_eq!(Ok(1.5), decode_f64(&[63, 248, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(Ok(f64::INFINITY), decode_f64(&[127, 240, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(
+            Ok(f64::NEG_INFINITY),
+            decode_f64(&[255, 240, 0, 0, 0, 0, 0, 0])
+        );
+    }
 
-#[cfg(feature = "uuid")]
-fn decode_uuid(value: &[u8]) -> Result<uuid::Uuid> {
-    uuid::Uuid::from_slice(value).map_err(|_| Error::IncompatibleFieldLength(value.len()))
-}
+    #[test]
+    fn can_not_decode_incompatible_values_into_f64() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_f64(&[]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(4)),
+            decode_f64(&[63, 192, 0, 0]).err()
+        );
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn can_read_f32_from_a_frame() {
+        let subnormal = f32::from_bits(1);
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_f32(100, 1.25);
+            bld.add_f32(200, subnormal);
+            bld.add_f32(300, -0.0);
+            bld.add_f32(400, f32::NAN);
+            bld.add_f32(500, f32::INFINITY);
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(1.25), frame.get_f32(100).unwrap());
+        assert_eq!(Some(subnormal), frame.get_f32(200).unwrap());
+
+        let negative_zero = frame.get_f32(300).unwrap().unwrap();
+        assert_eq!((-0.0f32).to_bits(), negative_zero.to_bits());
+
+        let nan = frame.get_f32(400).unwrap().unwrap();
+        assert_eq!(f32::NAN.to_bits(), nan.to_bits());
+
+        assert_eq!(Some(f32::INFINITY), frame.get_f32(500).unwrap());
+    }
 
     #[test]
-    fn can_make_an_empty_frame() {
-        let mut data = Vec::with_capacity(100);
+    fn can_read_f32s_from_a_frame() {
+        let mut data = Vec::new();
         {
-            FrameBuilder::new(&mut data);
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_f32(1, 1.5);
+            bld.add_f32(2, 2.5); // will be ignored
+            bld.add_f32(1, f32::NEG_INFINITY);
         }
-        assert_eq!(&[1, 0, 0, 0, 0], &data[..]);
+        let frame = FrameParser::new(&data).unwrap();
+        let expected: Vec<Result<f32>> = vec![Ok(1.5), Ok(f32::NEG_INFINITY)];
+        let actual: Vec<Result<f32>> = frame.get_f32s(1).collect();
+        assert_eq!(expected, actual);
     }
 
     #[test]
-    fn can_make_an_empty_packet_frame() {
-        let mut data = Vec::with_capacity(100);
+    fn can_read_f64_from_a_frame() {
+        let subnormal = f64::from_bits(1);
+        let mut data = Vec::new();
         {
-            PacketFrameBuilder::new(&mut data);
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_f64(100, 1.25);
+            bld.add_f64(200, subnormal);
+            bld.add_f64(300, -0.0);
+            bld.add_f64(400, f64::NAN);
+            bld.add_f64(500, f64::INFINITY);
         }
-        assert_eq!(&[0, 0, 0, 5, 1, 0, 0, 0, 0], &data[..]);
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(1.25), frame.get_f64(100).unwrap());
+        assert_eq!(Some(subnormal), frame.get_f64(200).unwrap());
+
+        let negative_zero = frame.get_f64(300).unwrap().unwrap();
+        assert_eq!((-0.0f64).to_bits(), negative_zero.to_bits());
+
+        let nan = frame.get_f64(400).unwrap().unwrap();
+        assert_eq!(f64::NAN.to_bits(), nan.to_bits());
+
+        assert_eq!(Some(f64::INFINITY), frame.get_f64(500).unwrap());
     }
 
     #[test]
-    fn can_add_data_to_frame() {
-        let mut data = Vec::with_capacity(100);
+    fn can_read_f64s_from_a_frame() {
+        let mut data = Vec::new();
         {
             let mut bld = FrameBuilder::new(&mut data);
-            bld.add_data(1022, &[9, 255]);
+            bld.add_f64(1, 1.5);
+            bld.add_f64(2, 2.5); // will be ignored
+            bld.add_f64(1, f64::NEG_INFINITY);
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        let expected: Vec<Result<f64>> = vec![Ok(1.5), Ok(f64::NEG_INFINITY)];
+        let actual: Vec<Result<f64>> = frame.get_f64s(1).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn can_not_read_f32_as_f64() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_f32(1, 1.5);
         }
+        let frame = FrameParser::new(&data).unwrap();
         assert_eq!(
-            &[
-                1, // frame format
-                0, 0, 0, 1, // field count = 1
-                3, 254, // tag = 1022
-                0, 0, 0, 2, // field length = 2
-                9, 255, // field value
-            ],
-            &data[..]
+            Some(Error::IncompatibleFieldLength(4)),
+            frame.get_f64(1).err()
         );
     }
 
     #[test]
-    fn can_add_data_to_packet_frame() {
-        let mut data = Vec::with_capacity(100);
+    fn can_not_decode_incompatible_values_into_u64() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(16)),
+            decode_u64(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]).err()
+        );
+    }
+
+    #[test]
+    fn can_decode_minimally_sized_unsigned_values() {
+        assert_eq!(Ok(0x10203), decode_u32(&[1, 2, 3]));
+        assert_eq!(Ok(0x1_0203_0405), decode_u64(&[1, 2, 3, 4, 5]));
+        assert_eq!(Ok(0x1_0203_0405_0607), decode_u64(&[1, 2, 3, 4, 5, 6, 7]));
+        assert_eq!(Ok(0xFF_FFFF_FFFF), decode_u128(&[0xFF; 5]));
+        assert_eq!(
+            Ok(0x10203),
+            Endianness::Little.decode(&[3, 2, 1], decode_u32)
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(3)),
+            decode_u16(&[0, 0, 1]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_u32(&[]).err()
+        );
+    }
+
+    #[test]
+    fn compact_numbers_use_the_fewest_bytes() {
+        assert_eq!(([0, 0, 0, 0], 1), encode_compact([0; 4], Endianness::Big));
+        assert_eq!(
+            ([1, 2, 3, 3], 3),
+            encode_compact([0, 1, 2, 3], Endianness::Big)
+        );
+        assert_eq!(
+            ([3, 2, 1, 3], 3),
+            encode_compact([0, 1, 2, 3], Endianness::Little)
+        );
+
+        let mut data = Vec::new();
         {
-            let mut bld = PacketFrameBuilder::new(&mut data);
-            bld.add_data(1022, &[9, 255]);
+            let mut bld = FrameBuilder::new_with_endianness(&mut data, Endianness::Little);
+            bld.add_u64_compact(1, 0x1_0203_0405);
+            bld.add_u128_compact(2, u128::MAX);
+            bld.add_u16_compact(3, 0);
+        }
+        let options = ParseOptions {
+            endianness: Endianness::Little,
+            ..ParseOptions::default()
+        };
+        let frame = FrameParser::new_with_options(&data, options).unwrap();
+        assert_eq!(Some(&[5, 4, 3, 2, 1][..]), frame.get_data(1));
+        assert_eq!(Some(0x1_0203_0405), frame.get_u64(1).unwrap());
+        assert_eq!(Some(u128::MAX), frame.get_u128(2).unwrap());
+        assert_eq!(Some(&[0][..]), frame.get_data(3));
+    }
+
+    #[test]
+    fn get_u32s_into_keeps_values_on_error() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new_with_endianness(&mut data, Endianness::Little);
+            bld.add_u32(1, 1);
+            bld.add_u16(2, 2);
+            bld.add_u8(1, 3);
+            bld.add_u64(1, u64::MAX);
         }
+        let options = ParseOptions {
+            endianness: Endianness::Little,
+            indexed: true,
+            ..ParseOptions::default()
+        };
+        let frame = FrameParser::new_with_options(&data, options).unwrap();
+        let mut values = vec![9];
+        assert_eq!(Ok(1), frame.get_u32s_into(2, &mut values));
+        assert_eq!(Ok(0), frame.get_u32s_into(3, &mut values));
         assert_eq!(
-            &[
-                0, 0, 0, 13, // frame size = 13
-                1,  // frame format
-                0, 0, 0, 1, // field count = 1
-                3, 254, // tag = 1022
-                0, 0, 0, 2, // field length = 2
-                9, 255, // field value
-            ],
-            &data[..]
+            Err(Error::IncompatibleFieldLength(8)),
+            frame.get_u32s_into(1, &mut values)
         );
+        assert_eq!(vec![9, 2], values);
     }
 
     #[test]
-    fn can_add_child_to_frame() {
-        let mut data = Vec::with_capacity(100);
+    fn truncated_strs_are_cut_at_char_boundaries() {
+        let mut data = Vec::new();
         {
             let mut bld = FrameBuilder::new(&mut data);
-            let mut child_bld = bld.add_frame(1022);
-            child_bld.add_data(60, &[9, 255])
+            let mut child = bld.add_frame(1);
+            for max_bytes in 0..7 {
+                child.add_str_truncated(2, "a😀b", max_bytes);
+            }
+        }
+        let parser = FrameParser::new(&data).unwrap();
+        let child = parser.get_frame_required(1).unwrap();
+        let values: Vec<&str> = child
+            .get_datas(2)
+            .map(|value| core::str::from_utf8(value).unwrap())
+            .collect();
+        assert_eq!(vec!["", "a", "a", "a", "a", "a😀", "a😀b"], values);
+    }
+
+    #[cfg(feature = "prost")]
+    #[test]
+    fn messages_round_trip_in_frames_and_packet_frames() {
+        fn add_messages<B: FrameBuilderLike>(bld: &mut B) {
+            bld.add_message(1, &String::from("héllo"));
+            bld.add_message(2, &0u64);
+            bld.add_frame(3).add_message(4, &-5i64);
+        }
+        fn check_messages(parser: &FrameParser<'_>) {
+            assert_eq!("héllo", parser.get_message_required::<String>(1).unwrap());
+            assert_eq!(Some(&[][..]), parser.get_data(2));
+            assert_eq!(Some(0u64), parser.get_message(2).unwrap());
+            let child = parser.get_frame_required(3).unwrap();
+            assert_eq!(-5, child.get_message_required::<i64>(4).unwrap());
+            assert_eq!(
+                Err(Error::IncompatibleFieldValue),
+                parser.get_message::<u64>(1)
+            );
+        }
+
+        let mut data = Vec::new();
+        add_messages(&mut FrameBuilder::new(&mut data));
+        check_messages(&FrameParser::new(&data).unwrap());
+
+        let mut data = Vec::new();
+        add_messages(&mut PacketFrameBuilder::new(&mut data));
+        check_messages(&FrameParser::from_packet(&data).unwrap().0);
+    }
+
+    #[test]
+    fn zigzag_numbers_round_trip() {
+        let values = [0, -1, 1, -64, 128, i64::MIN, i64::MAX];
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new_with_endianness(&mut data, Endianness::Little);
+            for value in values.iter() {
+                bld.add_i64_zigzag(1, *value);
+            }
         }
+        let options = ParseOptions {
+            endianness: Endianness::Little,
+            ..ParseOptions::default()
+        };
+        let frame = FrameParser::new_with_options(&data, options).unwrap();
+        let lengths: Vec<_> = frame.get_datas(1).map(|v| v.len()).collect();
+        assert_eq!(vec![1, 1, 1, 1, 2, 8, 8], lengths);
+        assert_eq!(Some(&[1][..]), frame.get_datas(1).nth(1));
+        assert_eq!(Some(0), frame.get_i64_zigzag(1).unwrap());
+        assert_eq!(Ok(i64::MIN), decode_i64_zigzag(&[0xFF; 8]));
         assert_eq!(
-            &[
-                1, // frame format
-                0, 0, 0, 1, // field count = 1
-                3, 254, // tag = 1022
-                0, 0, 0, 13, // child frame size
-                1,  // child frame format
-                0, 0, 0, 1, // child frame field count
-                0, 60, // field-tag in child frame
-                0, 0, 0, 2, // field-length in child frame
-                9, 255 // field-value in child frame
-            ],
-            &data[..]
+            Ok(i64::MAX),
+            decode_i64_zigzag(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE])
+        );
+        assert_eq!(Ok(-1), decode_i64_zigzag(&[0, 1]));
+        assert_eq!(
+            Err(Error::IncompatibleFieldLength(9)),
+            decode_i64_zigzag(&[0; 9])
+        );
+    }
+
+    #[test]
+    fn can_not_decode_u128_with_zero_bytes() {
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_u128(&[]).err()
         );
     }
 
     #[test]
-    fn can_add_child_to_packet_frame() {
-        let mut data = Vec::with_capacity(100);
-        {
-            let mut bld = PacketFrameBuilder::new(&mut data);
-            let mut child_bld = bld.add_frame(1022);
-            child_bld.add_data(60, &[9, 255])
-        }
+    fn can_decode_compatible_values_into_u128() {
+        assert_eq!(Ok(8), decode_u128(&[8]));
+        assert_eq!(Ok(3080), decode_u128(&[12, 8]));
+        assert_eq!(Ok(1744964616), decode_u128(&[104, 2, 12, 8]));
         assert_eq!(
-            &[
-                0, 0, 0, 24, // packet size
-                1,  // frame format
-                0, 0, 0, 1, // field count = 1
-                3, 254, // tag = 1022
-                0, 0, 0, 13, // child frame size
-                1,  // child frame format
-                0, 0, 0, 1, // child frame field count
-                0, 60, // field-tag in child frame
-                0, 0, 0, 2, // field-length in child frame
-                9, 255 // field-value in child frame
-            ],
-            &data[..]
+            Ok(150626523450313736),
+            decode_u128(&[2, 23, 34, 6, 104, 2, 12, 8])
+        );
+        assert_eq!(
+            Ok(2772985687999251815774038261768),
+            decode_u128(&[0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8])
         );
     }
 
     #[test]
-    fn can_add_bool_to_frame() {
-        let mut data = Vec::with_capacity(100);
-        {
-            let mut bld = FrameBuilder::new(&mut data);
-            bld.add_bool(1022, true);
-            bld.add_bool(1021, false);
-        }
+    fn can_not_decode_incompatible_values_into_u128() {
         assert_eq!(
-            &[
-                1, // frame format
-                0, 0, 0, 2, // field count = 1
-                3, 254, // tag = 1022
-                0, 0, 0, 1,   // field length = 2
-                255, // field value
-                3, 253, // tag = 1021
-                0, 0, 0, 1, // field length = 2
-                0  // field value
-            ],
-            &data[..]
+            Some(Error::IncompatibleFieldLength(17)),
+            decode_u128(&[0; 17]).err()
         );
     }
 
     #[test]
-    fn can_add_u8_to_frame() {
-        let mut data = Vec::with_capacity(100);
+    fn can_read_u128_from_a_frame() {
+        let mut data = Vec::new();
         {
             let mut bld = FrameBuilder::new(&mut data);
-            bld.add_u8(1022, 89);
+            bld.add_u8(100, 90);
+            bld.add_u16(200, 1025);
+            bld.add_u32(300, 1744964616);
+            bld.add_u64(400, 150626523450313736);
+            bld.add_u128(500, u128::MAX);
         }
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(90), frame.get_u128(100).unwrap());
+        assert_eq!(Some(1025), frame.get_u128(200).unwrap());
+        assert_eq!(Some(1744964616), frame.get_u128(300).unwrap());
+        assert_eq!(Some(150626523450313736), frame.get_u128(400).unwrap());
+        assert_eq!(Some(u128::MAX), frame.get_u128(500).unwrap());
         assert_eq!(
-            &[
-                1, // frame format
-                0, 0, 0, 1, // field count = 1
-                3, 254, // tag = 1022
-                0, 0, 0, 1,  // field length = 2
-                89  // field value
-            ],
-            &data[..]
+            Some(Error::IncompatibleFieldLength(16)),
+            frame.get_u64(500).err()
         );
     }
 
     #[test]
-    fn can_add_u16_to_frame() {
-        let mut data = Vec::with_capacity(100);
+    fn can_read_u128s_from_a_frame() {
+        let mut data = Vec::new();
         {
             let mut bld = FrameBuilder::new(&mut data);
-            bld.add_u16(1022, 1009);
+            bld.add_u128(1, 10);
+            bld.add_u128(2, 20); // will be ignored
+            bld.add_u64(1, 30);
         }
+        let frame = FrameParser::new(&data).unwrap();
+        let expected: Vec<Result<u128>> = vec![Ok(10), Ok(30)];
+        let actual: Vec<Result<u128>> = frame.get_u128s(1).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn can_decode_compatible_values_into_i128() {
+        assert_eq!(Ok(-8), decode_i128(&[248]));
+        assert_eq!(Ok(-3080), decode_i128(&[243, 248]));
+        assert_eq!(Ok(-1744964616), decode_i128(&[151, 253, 243, 248]));
         assert_eq!(
-            &[
-                1, // frame format
-                0, 0, 0, 1, // field count = 1
-                3, 254, // tag = 1022
-                0, 0, 0, 2, // field length = 2
-                3, 241 // field value (1009)
-            ],
-            &data[..]
+            Ok(-150626523450313736),
+            decode_i128(&[253, 232, 221, 249, 151, 253, 243, 248])
+        );
+        assert_eq!(
+            Ok(-2),
+            decode_i128(&[
+                255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254
+            ])
         );
     }
 
     #[test]
-    fn can_add_u32_to_frame() {
-        let mut data = Vec::with_capacity(100);
-        {
-            let mut bld = FrameBuilder::new(&mut data);
-            bld.add_u32(1022, 156090);
-        }
+    fn can_not_decode_incompatible_values_into_i128() {
         assert_eq!(
-            &[
-                1, // frame format
-                0, 0, 0, 1, // field count = 1
-                3, 254, // tag = 1022
-                0, 0, 0, 4, // field length = 2
-                0, 2, 97, 186 // field value (156090)
-            ],
-            &data[..]
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_i128(&[]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(12)),
+            decode_i128(&[0; 12]).err()
         );
     }
 
     #[test]
-    fn can_add_u64_to_frame() {
-        let mut data = Vec::with_capacity(100);
+    fn can_read_i128_from_a_frame() {
+        let mut data = Vec::new();
         {
             let mut bld = FrameBuilder::new(&mut data);
-            bld.add_u64(1022, 156234234090);
+            bld.add_i8(100, -90);
+            bld.add_i64(200, -150626523450313736);
+            bld.add_i128(300, i128::MIN);
         }
-        assert_eq!(
-            &[
-                1, // frame format
-                0, 0, 0, 1, // field count = 1
-                3, 254, // tag = 1022
-                0, 0, 0, 8, // field length = 2
-                0, 0, 0, 36, 96, 73, 56, 234 // field value (156234234090)
-            ],
-            &data[..]
-        );
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(-90), frame.get_i128(100).unwrap());
+        assert_eq!(Some(-150626523450313736), frame.get_i128(200).unwrap());
+        assert_eq!(Some(i128::MIN), frame.get_i128(300).unwrap());
     }
 
     #[test]
-    fn can_add_utf8_to_frame() {
-        let mut data = Vec::with_capacity(100);
+    fn can_read_i128s_from_a_frame() {
+        let mut data = Vec::new();
         {
             let mut bld = FrameBuilder::new(&mut data);
-            bld.add_str(1022, "hello");
+            bld.add_i128(1, -10);
+            bld.add_i128(2, 20); // will be ignored
+            bld.add_i16(1, -30);
         }
-        assert_eq!(
-            &[
-                1, // frame format
-                0, 0, 0, 1, // field count = 1
-                3, 254, // tag = 1022
-                0, 0, 0, 5, // field length = 2
-                104, 101, 108, 108, 111 // field value (156234234090)
-            ],
-            &data[..]
-        );
+        let frame = FrameParser::new(&data).unwrap();
+        let expected: Vec<Result<i128>> = vec![Ok(-10), Ok(-30)];
+        let actual: Vec<Result<i128>> = frame.get_i128s(1).collect();
+        assert_eq!(expected, actual);
     }
 
     #[test]
-    fn can_not_parse_a_frame_if_there_is_not_enough_data_for_frame_format() {
-        let data = &[]; // need four bytes for a field count.
+    fn can_not_decode_bool_with_zero_bytes() {
         assert_eq!(
-            Some(Error::IncompleteFrameFormat),
-            FrameParser::new(data).err()
+            Some(Error::IncompatibleFieldLength(0)),
+            decode_bool(&[]).err()
         );
     }
 
     #[test]
-    fn can_not_parse_a_frame_if_frame_format_is_not_recognized() {
-        let data = &[8]; // need four bytes for a field count.
-        assert_eq!(
-            Some(Error::InvalidFrameFormat(8)),
-            FrameParser::new(data).err()
-        );
+    fn can_decode_compatible_values_into_bool() {
+        assert_eq!(Ok(false), decode_bool(&[0x00]));
+        assert_eq!(Ok(true), decode_bool(&[0xFF]));
     }
 
     #[test]
-    fn can_not_parse_a_frame_if_there_is_not_enough_data_for_field_count() {
-        let data = &[1, 0, 0, 0]; // need four bytes for a field count.
+    fn can_not_decode_incompatible_values_into_bool() {
         assert_eq!(
-            Some(Error::IncompleteFrameFieldCount),
-            FrameParser::new(data).err()
+            Some(Error::IncompatibleFieldValue),
+            decode_bool(&[0x01]).err()
         );
     }
 
     #[test]
-    fn can_not_parse_a_frame_if_there_is_not_enough_data_for_field_tag_and_length() {
-        let data = &[
-            1, // frame format
-            0, 0, 0, 1, // field count = 1
-            0, 1, // tag = 1
-            0, 0, 0, // incomplete field length
-        ];
-        assert_eq!(
-            Some(Error::IncompleteFieldTagOrLength),
-            FrameParser::new(data).err()
-        );
-    }
+    fn can_read_bool_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_bool(100, true);
+            bld.add_bool(200, false);
+        }
 
-    #[test]
-    fn can_not_parse_a_frame_if_there_is_not_enough_data_for_a_field_value() {
-        let data = &[
-            1, // frame format
-            0, 0, 0, 1, // field count = 1
-            0, 1, // tag = 1
-            0, 0, 0, 4, // field length = 4
-            1, 2, 3, // incomplete value
-        ];
-        assert_eq!(
-            Some(Error::IncompleteFieldValue(4, 3)),
-            FrameParser::new(data).err()
-        );
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(true), frame.get_bool(100).unwrap());
+        assert_eq!(Some(false), frame.get_bool(200).unwrap());
     }
 
     #[test]
-    fn can_not_parse_a_frame_if_there_is_excess_data() {
-        let data = &[
-            1, // frame format
-            0, 0, 0, 1, // field count = 1
-            0, 1, // tag = 1
-            0, 0, 0, 4, // field length = 4
-            1, 2, 3, 4, // incomplete value
-            5, // excess data
-        ];
-        assert_eq!(Some(Error::UnexpectedData), FrameParser::new(data).err());
+    fn can_read_bools_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_bool(1, false);
+            bld.add_bool(2, false); // will be ignored
+            bld.add_bool(1, true);
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        let expected: Vec<Result<bool>> = vec![Ok(false), Ok(true)];
+        let actual: Vec<Result<bool>> = frame.get_bools(1).collect();
+        assert_eq!(expected, actual);
     }
 
     #[test]
-    fn can_read_data_from_frame() {
-        let data = &[
-            1, // frame format
-            0, 0, 0, 1, // field count = 1
-            0, 1, // tag = 1
-            0, 0, 0, 4, // field length = 4
-            1, 2, 3, 4, //
-        ];
-        let frame = FrameParser::new(data).unwrap();
-        assert_eq!(&[1, 2, 3, 4], frame.get_data(1).unwrap());
+    fn can_read_str_from_a_frame() {
+        let test_str = "short test string";
+        let mut data = Vec::new();
+
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_str(100, test_str);
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(test_str), frame.get_str(100).unwrap());
     }
 
     #[test]
-    fn can_read_datas_from_a_frame() {
-        let data = &[
-            1, // frame format
-            0, 0, 0, 3, // field count = 3
-            0, 1, // tag = 1
-            0, 0, 0, 2, // field length = 2
-            10, 11, //
-            0, 2, // tag = 2, will be skipped
-            0, 0, 0, 2, // field length = 2
-            20, 22, //
-            0, 1, // tag = 1
-            0, 0, 0, 2, // field length = 2
-            30, 33, //
-        ];
-        let frame = FrameParser::new(data).unwrap();
-        let expected = vec![&[10, 11], &[30, 33]];
-        let actual: Vec<&[u8]> = frame.get_datas(1).collect();
+    fn can_read_strs_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_str(1, "hello");
+            bld.add_str(2, "welcome"); // will be ignored
+            bld.add_str(1, "goodbye");
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        let expected: Vec<Result<&str>> = vec![Ok("hello"), Ok("goodbye")];
+        let actual: Vec<Result<&str>> = frame.get_strs(1).collect();
         assert_eq!(expected, actual);
     }
 
     #[test]
-    fn can_attempt_to_read_data_from_a_frame_if_it_is_not_there() {
-        let data = &[
-            1, // frame format
-            0, 0, 0, 1, // field count = 1
-            0, 1, // tag = 1
-            0, 0, 0, 4, // field length = 4
-            1, 2, 3, 4, //
-        ];
-        let frame = FrameParser::new(data).unwrap();
-        assert_eq!(None, frame.get_data(3));
+    fn can_read_string_from_a_frame() {
+        let value = {
+            let mut data = Vec::new();
+            {
+                let mut bld = FrameBuilder::new(&mut data);
+                bld.add_str(100, "short test string");
+                bld.add_data(200, &[0xC3, 0x28]); // invalid UTF-8
+            }
+            let frame = FrameParser::new(&data).unwrap();
+            assert_eq!(
+                Some(Error::IncompatibleFieldValue),
+                frame.get_string(200).err()
+            );
+            frame.get_string(100).unwrap()
+        };
+        assert_eq!(Some("short test string".to_string()), value);
     }
 
     #[test]
-    fn can_not_decode_u8_with_zero_bytes() {
+    fn can_read_strings_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_str(1, "hello");
+            bld.add_str(2, "welcome"); // will be ignored
+            bld.add_data(1, &[0xC3, 0x28]);
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        let expected: Vec<Result<String>> =
+            vec![Ok("hello".to_string()), Err(Error::IncompatibleFieldValue)];
+        let actual: Vec<Result<String>> = frame.get_strings(1).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn can_decode_compatible_values_into_char() {
+        assert_eq!(Ok('a'), decode_char(&[97]));
+        assert_eq!(Ok('€'), decode_char(&[226, 130, 172]));
+        assert_eq!(Ok('🦀'), decode_char(&[240, 159, 166, 128]));
+    }
+
+    #[test]
+    fn can_not_decode_incompatible_values_into_char() {
         assert_eq!(
             Some(Error::IncompatibleFieldLength(0)),
-            decode_u8(&[]).err()
+            decode_char(&[]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(5)),
+            decode_char(b"hello").err()
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldValue),
+            decode_char(b"ab").err()
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldValue),
+            decode_char(&[0xC3, 0x28]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldValue),
+            decode_char(&[240, 159, 166]).err()
         );
     }
 
     #[test]
-    fn can_decode_compatible_values_into_u8() {
-        assert_eq!(Ok(8), decode_u8(&[8]));
+    fn can_read_chars_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_char(1, 'a');
+            bld.add_char(2, 'b'); // will be ignored
+            bld.add_char(1, '🦀');
+            bld.add_str(1, "ab");
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some('a'), frame.get_char(1).unwrap());
+        assert_eq!('b', frame.get_char_required(2).unwrap());
+        let expected: Vec<Result<char>> =
+            vec![Ok('a'), Ok('🦀'), Err(Error::IncompatibleFieldValue)];
+        let actual: Vec<Result<char>> = frame.get_chars(1).collect();
+        assert_eq!(expected, actual);
     }
 
     #[test]
-    fn can_not_decode_incompatible_values_into_u8() {
-        assert_eq!(
-            Some(Error::IncompatibleFieldLength(2)),
-            decode_u8(&[0, 8]).err()
-        );
+    #[cfg(feature = "uuid")]
+    fn can_read_uuid_from_a_frame() {
+        let test_uuid = uuid::Uuid::parse_str("40b4e52c-1501-48d3-98eb-e2c66cb76cbf").unwrap();
+        let mut data = Vec::new();
+
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_uuid(100, &test_uuid);
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(test_uuid), frame.get_uuid(100).unwrap());
+    }
 
+    #[test]
+    fn can_decode_compatible_values_into_ip_addresses() {
+        use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+        let v4 = Ipv4Addr::new(1, 2, 3, 4);
+        let v6 = Ipv6Addr::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let v6_bytes = [0, 1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0, 7, 0, 8];
+        assert_eq!(Ok(v4), decode_ipv4(&[1, 2, 3, 4]));
+        assert_eq!(Ok(v6), decode_ipv6(&v6_bytes));
+        assert_eq!(Ok(IpAddr::V4(v4)), decode_ip(&[1, 2, 3, 4]));
+        assert_eq!(Ok(IpAddr::V6(v6)), decode_ip(&v6_bytes));
         assert_eq!(
-            Some(Error::IncompatibleFieldLength(4)),
-            decode_u8(&[0, 0, 0, 8]).err()
+            Ok(SocketAddr::from((v4, 258))),
+            decode_socket_addr(&[1, 2, 3, 4, 1, 2])
         );
-
+        let mut v6_socket_bytes = v6_bytes.to_vec();
+        v6_socket_bytes.extend_from_slice(&[1, 2]);
         assert_eq!(
-            Some(Error::IncompatibleFieldLength(8)),
-            decode_u8(&[0, 0, 0, 0, 0, 0, 0, 8]).err()
+            Ok(SocketAddr::from((v6, 258))),
+            decode_socket_addr(&v6_socket_bytes)
         );
+    }
 
+    #[test]
+    fn can_not_decode_incompatible_values_into_ip_addresses() {
+        for len in &[0, 1, 5, 15, 17] {
+            let value = vec![0; *len];
+            assert_eq!(
+                Some(Error::IncompatibleFieldLength(*len)),
+                decode_ip(&value).err()
+            );
+            assert_eq!(
+                Some(Error::IncompatibleFieldLength(*len)),
+                decode_socket_addr(&value).err()
+            );
+        }
         assert_eq!(
-            Some(Error::IncompatibleFieldLength(2)),
-            decode_u8(&[1, 8]).err()
+            Some(Error::IncompatibleFieldLength(16)),
+            decode_ipv4(&[0; 16]).err()
         );
-
         assert_eq!(
             Some(Error::IncompatibleFieldLength(4)),
-            decode_u8(&[0, 0, 1, 8]).err()
+            decode_ipv6(&[0; 4]).err()
         );
+    }
 
+    #[test]
+    fn can_read_ip_addresses_from_a_frame() {
+        use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+        let v4 = Ipv4Addr::new(1, 2, 3, 4);
+        let v6 = Ipv6Addr::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new_with_endianness(&mut data, Endianness::Little);
+            bld.add_ipv4(1, v4);
+            bld.add_ipv6(2, v6);
+            bld.with_ip(3, IpAddr::V4(v4))
+                .with_ip(3, IpAddr::V6(v6))
+                .with_socket_addr(4, SocketAddr::from((v4, 80)))
+                .with_socket_addr(4, SocketAddr::from((v6, 443)));
+        }
+        let frame = FrameParser::new_with_endianness(&data, Endianness::Little).unwrap();
+        assert_eq!(Some(v4), frame.get_ipv4(1).unwrap());
+        assert_eq!(v6, frame.get_ipv6_required(2).unwrap());
         assert_eq!(
-            Some(Error::IncompatibleFieldLength(8)),
-            decode_u8(&[0, 0, 0, 0, 0, 0, 1, 8]).err()
+            Some(Error::IncompatibleFieldLength(16)),
+            frame.get_ipv4(2).err()
         );
+        let expected = vec![Ok(IpAddr::V4(v4)), Ok(IpAddr::V6(v6))];
+        assert_eq!(expected, frame.get_ips(3).collect::<Vec<_>>());
+        let expected = vec![
+            Ok(SocketAddr::from((v4, 80))),
+            Ok(SocketAddr::from((v6, 443))),
+        ];
+        assert_eq!(expected, frame.get_socket_addrs(4).collect::<Vec<_>>());
+        // ports are always big-endian
+        assert_eq!(Some(&[1, 2, 3, 4, 0, 80][..]), frame.get_data(4));
     }
 
     #[test]
-    fn can_read_u8_from_a_frame() {
+    #[cfg(feature = "time")]
+    fn can_read_times_from_a_frame() {
+        use std::time::{Duration, UNIX_EPOCH};
+        let max_duration = Duration::from_nanos(u64::MAX);
         let mut data = Vec::new();
         {
             let mut bld = FrameBuilder::new(&mut data);
-            bld.add_u8(100, 250);
+            bld.add_duration_nanos(1, Duration::new(5, 6)).unwrap();
+            bld.add_duration_nanos(1, max_duration).unwrap();
+            bld.add_system_time(2, UNIX_EPOCH).unwrap();
+            bld.add_system_time(2, UNIX_EPOCH + max_duration).unwrap();
+            bld.add_data(3, &[1; 9]);
         }
-
         let frame = FrameParser::new(&data).unwrap();
-        assert_eq!(Some(250), frame.get_u8(100).unwrap());
+        assert_eq!(
+            Some(Duration::new(5, 6)),
+            frame.get_duration_nanos(1).unwrap()
+        );
+        let expected = vec![Ok(Duration::new(5, 6)), Ok(max_duration)];
+        assert_eq!(
+            expected,
+            frame.get_duration_nanos_all(1).collect::<Vec<_>>()
+        );
+        assert_eq!(UNIX_EPOCH, frame.get_system_time_required(2).unwrap());
+        let expected = vec![Ok(UNIX_EPOCH), Ok(UNIX_EPOCH + max_duration)];
+        assert_eq!(expected, frame.get_system_times(2).collect::<Vec<_>>());
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(9)),
+            frame.get_system_time(3).err()
+        );
     }
 
     #[test]
-    fn can_read_u8s_from_a_frame() {
-        let data = &[
-            1, // frame format
-            0, 0, 0, 3, // field count = 3
-            0, 1, // tag = 1
-            0, 0, 0, 1,  // field length = 2
-            10, //
-            0, 2, // tag = 2, will be skipped
-            0, 0, 0, 1,  // field length = 2
-            20, //
-            0, 1, // tag = 1
-            0, 0, 0, 1,  // field length = 2
-            30, //
-        ];
-        let frame = FrameParser::new(data).unwrap();
-        let expected: Vec<Result<u8>> = vec![Ok(10), Ok(30)];
-        let actual: Vec<Result<u8>> = frame.get_u8s(1).collect();
-        assert_eq!(expected, actual);
+    #[cfg(feature = "time")]
+    fn can_not_add_times_that_do_not_fit_in_u64_nanos() {
+        use std::time::{Duration, UNIX_EPOCH};
+        let too_long = Duration::from_nanos(u64::MAX) + Duration::from_nanos(1);
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            assert_eq!(
+                Err(Error::IncompatibleFieldValue),
+                bld.add_duration_nanos(1, too_long)
+            );
+            assert_eq!(
+                Err(Error::IncompatibleFieldValue),
+                bld.add_system_time(1, UNIX_EPOCH + too_long)
+            );
+            assert_eq!(
+                Err(Error::IncompatibleFieldValue),
+                bld.add_system_time(1, UNIX_EPOCH - Duration::from_nanos(1))
+            );
+        }
+        assert_eq!(&[1, 0, 0, 0, 0], &data[..]);
     }
 
     #[test]
-    fn can_not_decode_u16_with_zero_bytes() {
+    #[cfg(feature = "uuid")]
+    fn can_not_decode_incompatible_values_into_uuid() {
         assert_eq!(
-            Some(Error::IncompatibleFieldLength(0)),
-            decode_u16(&[]).err()
+            Some(Error::IncompatibleFieldLength(15)),
+            decode_uuid(&[0; 15]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(17)),
+            decode_uuid(&[0; 17]).err()
         );
     }
 
     #[test]
-    fn can_decode_compatible_values_into_u16() {
-        assert_eq!(Ok(8), decode_u16(&[8]));
-        assert_eq!(Ok(3080), decode_u16(&[12, 8]));
+    #[cfg(feature = "uuid")]
+    fn can_read_uuids_from_a_frame() {
+        let test_uuid1 = uuid::Uuid::parse_str("40b4e52c-1501-48d3-98eb-e2c66cb76cbf").unwrap();
+        let test_uuid2 = uuid::Uuid::parse_str("e6bb35e5-547b-4930-b72c-5d50aa60ff49").unwrap();
+        let test_uuid3 = uuid::Uuid::parse_str("451f82e5-c17a-4843-a9c7-5ff3f7ae20fd").unwrap();
+
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_uuid(1, &test_uuid1);
+            bld.add_uuid(2, &test_uuid2); // will be ignored
+            bld.add_uuid(1, &test_uuid3);
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        let expected: Vec<Result<uuid::Uuid>> = vec![Ok(test_uuid1), Ok(test_uuid3)];
+        let actual: Vec<Result<uuid::Uuid>> = frame.get_uuids(1).collect();
+        assert_eq!(expected, actual);
     }
 
     #[test]
-    fn can_not_decode_incompatible_values_into_u16() {
+    fn can_add_little_endian_numbers_to_frame() {
+        let mut data = Vec::with_capacity(100);
+        {
+            let mut bld = FrameBuilder::new_with_endianness(&mut data, Endianness::Little);
+            bld.add_u32(1022, 156090);
+            bld.add_str(1023, "hi");
+        }
         assert_eq!(
-            Some(Error::IncompatibleFieldLength(4)),
-            decode_u16(&[0, 0, 12, 8]).err()
+            &[
+                1, // frame format
+                0, 0, 0, 2, // field count = 2 (always big-endian)
+                3, 254, // tag = 1022 (always big-endian)
+                0, 0, 0, 4, // field length = 4 (always big-endian)
+                186, 97, 2, 0, // field value (156090)
+                3, 255, // tag = 1023
+                0, 0, 0, 2, // field length = 2
+                104, 105 // field value ("hi", unaffected by endianness)
+            ],
+            &data[..]
         );
+    }
 
-        assert_eq!(
-            Some(Error::IncompatibleFieldLength(4)),
-            decode_u16(&[0, 1, 255, 255]).err()
-        );
+    #[test]
+    fn can_read_little_endian_numbers_from_a_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new_with_endianness(&mut data, Endianness::Little);
+            bld.add_u8(100, 90);
+            bld.add_u16(200, 1025);
+            bld.add_u32(300, 1744964616);
+            bld.add_i16(400, -1025);
+            bld.add_f64(500, -0.15625);
+            bld.add_u128(600, 2772985687999251815774038261768);
+        }
 
+        let frame = FrameParser::new_with_endianness(&data, Endianness::Little).unwrap();
+        assert_eq!(Some(90), frame.get_u64(100).unwrap());
+        assert_eq!(Some(1025), frame.get_u32(200).unwrap());
+        assert_eq!(Some(1025), frame.get_u64(200).unwrap());
+        assert_eq!(Some(1744964616), frame.get_u32(300).unwrap());
+        assert_eq!(Some(1744964616), frame.get_u64(300).unwrap());
+        assert_eq!(Some(-1025), frame.get_i64(400).unwrap());
+        assert_eq!(Some(-0.15625), frame.get_f64(500).unwrap());
         assert_eq!(
-            Some(Error::IncompatibleFieldLength(8)),
-            decode_u16(&[0, 0, 0, 0, 0, 0, 0, 1]).err()
+            Some(2772985687999251815774038261768),
+            frame.get_u128(600).unwrap()
         );
+
+        let values: Vec<Result<u64>> = frame.get_u64s(200).collect();
+        assert_eq!(vec![Ok(1025)], values);
+        assert_eq!(Ok(1025), frame.get_u16_required(200));
+
+        // the same bytes read as big-endian give a different answer
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Some(260), frame.get_u16(200).unwrap());
     }
 
     #[test]
-    fn can_read_u16_from_a_frame() {
+    fn can_read_little_endian_numbers_from_a_child_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = PacketFrameBuilder::new_with_endianness(&mut data, Endianness::Little);
+            let mut child_bld = bld.add_frame(1);
+            assert_eq!(Endianness::Little, child_bld.endianness());
+            child_bld.add_u16(2, 1025);
+        }
+
+        let frame = FrameParser::new_with_endianness(&data[4..], Endianness::Little).unwrap();
+        let child_frame = frame.get_frame(1).unwrap().unwrap();
+        assert_eq!(Endianness::Little, child_frame.endianness());
+        assert_eq!(Some(1025), child_frame.get_u16(2).unwrap());
+    }
+
+    #[test]
+    fn can_read_required_fields_from_a_frame() {
         let mut data = Vec::new();
         {
             let mut bld = FrameBuilder::new(&mut data);
-            bld.add_u8(100, 90);
-            bld.add_u16(200, 1025);
+            bld.add_u16(100, 1025);
+            bld.add_i32(200, -1744964616);
+            bld.add_str(300, "hello");
         }
 
         let frame = FrameParser::new(&data).unwrap();
-        assert_eq!(Some(90), frame.get_u16(100).unwrap());
-        assert_eq!(Some(1025), frame.get_u16(200).unwrap());
+        assert_eq!(Ok(1025), frame.get_u16_required(100));
+        assert_eq!(Ok(1025), frame.get_u64_required(100));
+        assert_eq!(Ok(-1744964616), frame.get_i32_required(200));
+        assert_eq!(Ok("hello"), frame.get_str_required(300));
     }
 
     #[test]
-    fn can_read_u16s_from_a_frame() {
-        let data = &[
-            1, // frame format
-            0, 0, 0, 3, // field count = 3
-            0, 1, // tag = 1
-            0, 0, 0, 1,  // field length = 2
-            10, //
-            0, 2, // tag = 2, will be skipped
-            0, 0, 0, 1,  // field length = 2
-            20, //
-            0, 1, // tag = 1
-            0, 0, 0, 1,  // field length = 2
-            30, //
-        ];
-        let frame = FrameParser::new(data).unwrap();
-        let expected: Vec<Result<u16>> = vec![Ok(10), Ok(30)];
-        let actual: Vec<Result<u16>> = frame.get_u16s(1).collect();
-        assert_eq!(expected, actual);
+    fn can_not_read_required_fields_that_are_missing() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u16(100, 1025);
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
+        assert_eq!(Err(Error::MissingField(1)), frame.get_data_required(1));
+        assert_eq!(Err(Error::MissingField(2)), frame.get_u32_required(2));
+        assert_eq!(Err(Error::MissingField(3)), frame.get_bool_required(3));
+        assert_eq!(Err(Error::MissingField(4)), frame.get_str_required(4));
+        assert_eq!(
+            Some(Error::MissingField(5)),
+            frame.get_frame_required(5).err()
+        );
     }
 
     #[test]
-    fn can_not_decode_u32_with_zero_bytes() {
+    fn can_not_read_required_fields_that_are_incompatible() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u16(100, 1025);
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
         assert_eq!(
-            Some(Error::IncompatibleFieldLength(0)),
-            decode_u32(&[]).err()
+            Err(Error::IncompatibleFieldLength(2)),
+            frame.get_u8_required(100)
         );
     }
 
-    #[test]
-    fn can_decode_compatible_values_into_u32() {
-        assert_eq!(Ok(8), decode_u32(&[8]));
-        assert_eq!(Ok(3080), decode_u32(&[12, 8]));
-        assert_eq!(Ok(1744964616), decode_u32(&[104, 2, 12, 8]));
+    #[test]
+    fn can_read_child_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(100, 1);
+            let mut bld2 = bld.add_frame(200);
+            bld2.add_u8(300, 3);
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
+        let child_frame = frame.get_frame(200).unwrap().unwrap();
+        assert_eq!(Some(3), child_frame.get_u8(300).unwrap());
+    }
+
+    #[test]
+    fn can_read_child_frames() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(100, 1);
+            for i in 0..2 {
+                let mut bld2 = bld.add_frame(200);
+                bld2.add_u8(300, i);
+            }
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
+        let child_frame: Vec<FrameParser> = frame.get_frames(200).map(|v| v.unwrap()).collect();
+
+        assert_eq!(Some(0), child_frame[0].get_u8(300).unwrap());
+        assert_eq!(Some(1), child_frame[1].get_u8(300).unwrap());
     }
 
     #[test]
-    fn can_not_decode_incompatible_values_into_u32() {
+    fn can_read_nested_frames_by_path() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(1, 1);
+            let mut bld2 = bld.add_frame(2);
+            bld2.add_data(3, &[0]);
+            let mut bld3 = bld2.add_frame(4);
+            bld3.add_u8(5, 5);
+        }
+
+        let frame = FrameParser::new(&data).unwrap();
         assert_eq!(
-            Some(Error::IncompatibleFieldLength(8)),
-            decode_u32(&[0, 0, 0, 0, 104, 2, 12, 8]).err()
+            Some(1),
+            frame.get_path(&[]).unwrap().unwrap().get_u8(1).unwrap()
         );
         assert_eq!(
-            Some(Error::IncompatibleFieldLength(8)),
-            decode_u32(&[0, 0, 0, 1, 255, 255, 255, 255]).err()
+            Some(5),
+            frame.get_path(&[2, 4]).unwrap().unwrap().get_u8(5).unwrap()
+        );
+        assert!(frame.get_path(&[2, 6]).unwrap().is_none());
+        assert!(frame.get_path(&[6, 4]).unwrap().is_none());
+        assert_eq!(
+            Some(Error::InvalidFrameFormat(0)),
+            frame.get_path(&[2, 3, 4]).err()
         );
+
+        let expected: &[u8] = &[5];
+        assert_eq!(Some(expected), frame.get_data_at_path(&[2, 4], 5).unwrap());
+        assert_eq!(None, frame.get_data_at_path(&[2, 4], 6).unwrap());
+        assert_eq!(None, frame.get_data_at_path(&[6], 5).unwrap());
     }
 
     #[test]
-    fn can_read_u32_from_a_frame() {
+    fn malformed_child_frames_do_not_stop_reading_other_child_frames() {
         let mut data = Vec::new();
         {
             let mut bld = FrameBuilder::new(&mut data);
-            bld.add_u8(100, 90);
-            bld.add_u16(200, 1025);
-            bld.add_u32(300, 1744964616);
+            bld.add_frame(200).add_u8(300, 0);
+            bld.add_data(200, &[3]);
+            bld.add_frame(200).add_u8(300, 1);
         }
 
         let frame = FrameParser::new(&data).unwrap();
-        assert_eq!(Some(90), frame.get_u32(100).unwrap());
-        assert_eq!(Some(1025), frame.get_u32(200).unwrap());
-        assert_eq!(Some(1744964616), frame.get_u32(300).unwrap());
-    }
+        let child_frames: Vec<_> = frame.get_frames(200).collect();
 
-    #[test]
-    fn can_read_u32s_from_a_frame() {
-        let data = &[
-            1, // frame format
-            0, 0, 0, 3, // field count = 3
-            0, 1, // tag = 1
-            0, 0, 0, 1,  // field length = 2
-            10, //
-            0, 2, // tag = 2, will be skipped
-            0, 0, 0, 1,  // field length = 2
-            20, //
-            0, 1, // tag = 1
-            0, 0, 0, 1,  // field length = 2
-            30, //
-        ];
-        let frame = FrameParser::new(data).unwrap();
-        let expected: Vec<Result<u32>> = vec![Ok(10), Ok(30)];
-        let actual: Vec<Result<u32>> = frame.get_u32s(1).collect();
-        assert_eq!(expected, actual);
+        assert_eq!(3, child_frames.len());
+        assert_eq!(
+            Some(0),
+            child_frames[0].as_ref().unwrap().get_u8(300).unwrap()
+        );
+        assert_eq!(
+            Some(&Error::InvalidFrameFormat(3)),
+            child_frames[1].as_ref().err()
+        );
+        assert_eq!(
+            Some(1),
+            child_frames[2].as_ref().unwrap().get_u8(300).unwrap()
+        );
     }
 
     #[test]
-    fn can_not_decode_u64_with_zero_bytes() {
-        assert_eq!(
-            Some(Error::IncompatibleFieldLength(0)),
-            decode_u64(&[]).err()
-        );
+    fn can_read_number_slices_in_either_byte_order() {
+        for endianness in [Endianness::Big, Endianness::Little] {
+            let mut data = Vec::new();
+            {
+                let mut bld = FrameBuilder::new_with_endianness(&mut data, endianness);
+                bld.add_u16_slice(1, &[1, 0x203]);
+                bld.add_u32_slice(2, &[]);
+                bld.add_u64_slice(3, &[u64::MAX, 4]);
+                bld.add_f32_slice(4, &[0.5]);
+            }
+            let options = ParseOptions {
+                endianness,
+                ..ParseOptions::default()
+            };
+            let frame = FrameParser::new_with_options(&data, options).unwrap();
+            assert_eq!(Some(vec![1, 0x203]), frame.get_u16_slice(1).unwrap());
+            assert_eq!(Some(vec![]), frame.get_u32_slice(2).unwrap());
+            assert_eq!(Some(vec![u64::MAX, 4]), frame.get_u64_slice(3).unwrap());
+            assert_eq!(Some(vec![0.5]), frame.get_f32_slice(4).unwrap());
+            assert_eq!(
+                Some(Error::IncompatibleFieldLength(4)),
+                frame.get_u64_slice(4).err()
+            );
+        }
     }
 
     #[test]
-    fn can_decode_compatible_values_into_u64() {
-        assert_eq!(Ok(8), decode_u64(&[8]));
-        assert_eq!(Ok(3080), decode_u64(&[12, 8]));
-        assert_eq!(Ok(1744964616), decode_u64(&[104, 2, 12, 8]));
-        assert_eq!(
-            Ok(150626523450313736),
-            decode_u64(&[2, 23, 34, 6, 104, 2, 12, 8])
-        );
+    fn value_and_total_bytes_depend_on_the_frame_format() {
+        let builders: [fn(&mut Vec<u8>) -> FrameBuilder; 3] = [
+            FrameBuilder::new,
+            FrameBuilder::new_checked,
+            FrameBuilder::new_large,
+        ];
+        for (new_builder, total) in builders.iter().zip(&[25, 29, 33]) {
+            let mut data = Vec::new();
+            {
+                let mut bld = new_builder(&mut data);
+                bld.add_data(1, &[1, 2, 3]);
+                bld.add_frame(2);
+            }
+            data.push(0xFF);
+            let (parser, _) = FrameParser::parse_prefix(&data).unwrap();
+            assert_eq!(8, parser.value_bytes());
+            assert_eq!(*total, parser.total_bytes());
+        }
     }
 
     #[test]
-    fn can_read_u64_from_a_frame() {
+    fn fields_of_valid_frames_are_reserved_exactly() {
         let mut data = Vec::new();
         {
             let mut bld = FrameBuilder::new(&mut data);
-            bld.add_u8(100, 90);
-            bld.add_u16(200, 1025);
-            bld.add_u32(300, 1744964616);
-            bld.add_u64(400, 150626523450313736);
+            for tag in 0..1000 {
+                bld.add_u8(tag, tag as u8);
+            }
         }
-
-        let frame = FrameParser::new(&data).unwrap();
-        assert_eq!(Some(90), frame.get_u64(100).unwrap());
-        assert_eq!(Some(1025), frame.get_u64(200).unwrap());
-        assert_eq!(Some(1744964616), frame.get_u64(300).unwrap());
-        assert_eq!(Some(150626523450313736), frame.get_u64(400).unwrap());
+        let parser = FrameParser::new(&data).unwrap();
+        assert_eq!(1000, parser.fields.len());
+        assert_eq!(1000, parser.fields.capacity());
     }
 
     #[test]
-    fn can_read_u64s_from_a_frame() {
-        let data = &[
-            1, // frame format
-            0, 0, 0, 3, // field count = 3
-            0, 1, // tag = 1
-            0, 0, 0, 1,  // field length = 2
-            10, //
-            0, 2, // tag = 2, will be skipped
-            0, 0, 0, 1,  // field length = 2
-            20, //
-            0, 1, // tag = 1
-            0, 0, 0, 1,  // field length = 2
-            30, //
-        ];
-        let frame = FrameParser::new(data).unwrap();
-        let expected: Vec<Result<u64>> = vec![Ok(10), Ok(30)];
-        let actual: Vec<Result<u64>> = frame.get_u64s(1).collect();
-        assert_eq!(expected, actual);
+    fn field_counts_the_body_can_not_hold_are_rejected_before_reading_fields() {
+        // a deterministic spread of field-counts and body lengths around the limit
+        let mut seed: u32 = 12345;
+        for _ in 0..200 {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let body_len = (seed >> 8) as usize % 64;
+            let field_count = match seed % 3 {
+                0 => u32::MAX - (seed >> 20),
+                1 => (body_len / FIELD_HEADER_BYTES) as u32 + 1,
+                _ => (seed >> 16) % 16,
+            };
+            let mut data = vec![1];
+            data.extend_from_slice(&field_count.to_be_bytes());
+            data.extend((0..body_len).map(|i| (i as u8).wrapping_mul(31)));
+
+            let result = FrameParser::new(&data);
+            if field_count as usize > body_len / FIELD_HEADER_BYTES {
+                assert_eq!(Some(Error::IncompleteFieldTagOrLength), result.err());
+                assert_eq!(
+                    Err(Error::IncompleteFieldTagOrLength),
+                    frame_encoded_len(&data)
+                );
+            } else if let Ok(frame) = result {
+                assert_eq!(field_count as usize, frame.len());
+            }
+        }
     }
 
     #[test]
-    fn can_not_decode_bool_with_zero_bytes() {
-        assert_eq!(
-            Some(Error::IncompatibleFieldLength(0)),
-            decode_bool(&[]).err()
-        );
+    fn field_values_longer_than_u32_max_are_too_large() {
+        assert_eq!(Ok(u32::MAX), check_field_length(u32::MAX as usize));
+        if let Some(length) = (u32::MAX as usize).checked_add(1) {
+            assert_eq!(
+                Err(Error::FieldTooLarge(length)),
+                check_field_length(length)
+            );
+        }
     }
 
     #[test]
-    fn can_decode_compatible_values_into_bool() {
-        assert_eq!(Ok(false), decode_bool(&[0x00]));
-        assert_eq!(Ok(true), decode_bool(&[0xFF]));
+    fn generic_get_uses_the_frame_byte_order() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new_with_endianness(&mut data, Endianness::Little);
+            bld.add_u16(1, 0x102);
+            bld.add_bool(2, true);
+            bld.add_data(3, &[1, 2, 3]);
+        }
+        let options = ParseOptions {
+            endianness: Endianness::Little,
+            ..ParseOptions::default()
+        };
+        let frame = FrameParser::new_with_options(&data, options).unwrap();
+        assert_eq!(Some(0x102u16), frame.get(1).unwrap());
+        assert_eq!(Some(0x102u64), frame.get(1).unwrap());
+        assert_eq!(Some(true), frame.get(2).unwrap());
+        assert_eq!(Some(vec![1u8, 2, 3]), frame.get(3).unwrap());
+        assert_eq!(
+            Some(Error::IncompatibleFieldLength(3)),
+            frame.get::<u16>(3).err()
+        );
     }
 
     #[test]
-    fn can_not_decode_incompatible_values_into_bool() {
+    fn cow_fields_are_written_as_their_contents_and_read_borrowed() {
+        use alloc::borrow::Cow;
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add(1, Cow::Borrowed("borrowed"));
+            bld.add(2, Cow::<str>::Owned(String::from("owned")));
+            bld.add(3, Cow::Borrowed(&[0xFFu8, 0xFE][..]));
+            bld.add_str(4, Cow::Borrowed("str"));
+        }
+        let frame = FrameParser::new(&data).unwrap();
+        for (tag, expected) in [(1, "borrowed"), (2, "owned"), (4, "str")] {
+            let value: Cow<str> = frame.get(tag).unwrap().unwrap();
+            assert!(matches!(value, Cow::Borrowed(s) if s == expected));
+        }
+        let value: Cow<[u8]> = frame.get(3).unwrap().unwrap();
+        assert!(matches!(value, Cow::Borrowed(&[0xFF, 0xFE])));
         assert_eq!(
             Some(Error::IncompatibleFieldValue),
-            decode_bool(&[0x01]).err()
+            frame.get::<Cow<str>>(3).err()
         );
     }
 
     #[test]
-    fn can_read_bool_from_a_frame() {
+    fn generic_add_round_trips_with_generic_get() {
+        let addr: core::net::SocketAddr = "[::1]:80".parse().unwrap();
         let mut data = Vec::new();
         {
-            let mut bld = FrameBuilder::new(&mut data);
-            bld.add_bool(100, true);
-            bld.add_bool(200, false);
+            let mut bld = FrameBuilder::new_with_endianness(&mut data, Endianness::Little);
+            bld.add(1, -2i64);
+            bld.add(2, 'x');
+            bld.add(3, String::from("three"));
+            bld.add(4, addr);
+            bld.add(5, &[5u8, 5][..]);
         }
+        let options = ParseOptions {
+            endianness: Endianness::Little,
+            ..ParseOptions::default()
+        };
+        let frame = FrameParser::new_with_options(&data, options).unwrap();
+        assert_eq!(Some(-2i64), frame.get(1).unwrap());
+        assert_eq!(Some('x'), frame.get(2).unwrap());
+        assert_eq!(Some("three"), frame.get(3).unwrap());
+        assert_eq!(Some(addr), frame.get(4).unwrap());
+        assert_eq!(Some(&[5u8, 5][..]), frame.get(5).unwrap());
+        assert_eq!(
+            vec![255, 255, 255, 255, 255, 255, 255, 254],
+            (-2i64).to_field_bytes()
+        );
+    }
 
-        let frame = FrameParser::new(&data).unwrap();
-        assert_eq!(Some(true), frame.get_bool(100).unwrap());
-        assert_eq!(Some(false), frame.get_bool(200).unwrap());
+    #[test]
+    fn semantic_eq_ignores_field_order_in_child_frames() {
+        fn build(reversed: bool, extra: Option<u8>) -> Vec<u8> {
+            let mut data = Vec::new();
+            {
+                let mut bld = FrameBuilder::new(&mut data);
+                let mut fields: Vec<(u16, u8)> = vec![(1, 1), (1, 2), (2, 3)];
+                if reversed {
+                    fields.reverse();
+                }
+                for &(tag, value) in &fields {
+                    bld.add_u8(tag, value);
+                }
+                let mut child = bld.add_frame(3);
+                for &(tag, value) in &fields {
+                    child.add_u8(tag, value);
+                }
+                if let Some(value) = extra {
+                    child.add_u8(4, value);
+                }
+            }
+            data
+        }
+        let forward = build(false, None);
+        let reversed = build(true, None);
+        let extended = build(true, Some(4));
+        let forward = FrameParser::new(&forward).unwrap();
+        let reversed = FrameParser::new(&reversed).unwrap();
+        let extended = FrameParser::new(&extended).unwrap();
+        assert!(forward.semantic_eq(&reversed));
+        assert!(reversed.semantic_eq(&forward));
+        assert!(!forward.semantic_eq(&extended));
+        assert!(!extended.semantic_eq(&forward));
     }
 
     #[test]
-    fn can_read_bools_from_a_frame() {
-        let mut data = Vec::new();
+    fn semantic_eq_counts_repeated_fields() {
+        let mut data1 = Vec::new();
         {
-            let mut bld = FrameBuilder::new(&mut data);
-            bld.add_bool(1, false);
-            bld.add_bool(2, false); // will be ignored
-            bld.add_bool(1, true);
+            let mut bld = FrameBuilder::new(&mut data1);
+            bld.add_u8(1, 1);
+            bld.add_u8(1, 1);
+            bld.add_u8(1, 2);
         }
-        let frame = FrameParser::new(&data).unwrap();
-        let expected: Vec<Result<bool>> = vec![Ok(false), Ok(true)];
-        let actual: Vec<Result<bool>> = frame.get_bools(1).collect();
-        assert_eq!(expected, actual);
+        let mut data2 = Vec::new();
+        {
+            let mut bld = FrameBuilder::new_checked(&mut data2);
+            bld.add_u8(1, 2);
+            bld.add_u8(1, 2);
+            bld.add_u8(1, 1);
+        }
+        let frame1 = FrameParser::new(&data1).unwrap();
+        let frame2 = FrameParser::new(&data2).unwrap();
+        assert!(!frame1.semantic_eq(&frame2));
+        assert!(frame1.semantic_eq(&frame1));
     }
 
     #[test]
-    fn can_read_str_from_a_frame() {
-        let test_str = "short test string";
-        let mut data = Vec::new();
-
+    fn merged_frames_replace_every_base_field_with_an_overridden_tag() {
+        let mut base_data = Vec::new();
         {
-            let mut bld = FrameBuilder::new(&mut data);
-            bld.add_str(100, test_str);
+            let mut bld = FrameBuilder::new(&mut base_data);
+            bld.add_u8(1, 1);
+            bld.add_u8(2, 2);
+            bld.add_u8(1, 3);
+        }
+        let mut override_data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut override_data);
+            bld.add_u8(1, 4);
+            bld.add_u8(1, 5);
         }
+        let base = FrameParser::new(&base_data).unwrap();
+        let overrides = FrameParser::new(&override_data).unwrap();
 
-        let frame = FrameParser::new(&data).unwrap();
-        assert_eq!(Some(test_str), frame.get_str(100).unwrap());
+        let mut data = Vec::new();
+        {
+            let mut bld = PacketFrameBuilder::new(&mut data);
+            assert_eq!(3, bld.add_merged(&base, &overrides));
+        }
+        let frame = FrameParser::new(&data[4..]).unwrap();
+        assert_eq!(
+            vec![(2, &[2][..]), (1, &[4]), (1, &[5])],
+            frame.fields().collect::<Vec<_>>()
+        );
     }
 
     #[test]
-    fn can_read_strs_from_a_frame() {
+    fn validate_frame_agrees_with_frame_parser() {
         let mut data = Vec::new();
         {
-            let mut bld = FrameBuilder::new(&mut data);
-            bld.add_str(1, "hello");
-            bld.add_str(2, "welcome"); // will be ignored
-            bld.add_str(1, "goodbye");
+            let mut bld = FrameBuilder::new_checked(&mut data);
+            bld.add_str(1, "one");
+            bld.add_frame(2).add_u16(3, 3);
         }
-        let frame = FrameParser::new(&data).unwrap();
-        let expected: Vec<Result<&str>> = vec![Ok("hello"), Ok("goodbye")];
-        let actual: Vec<Result<&str>> = frame.get_strs(1).collect();
-        assert_eq!(expected, actual);
+        for end in 0..=data.len() {
+            assert_eq!(
+                FrameParser::new(&data[..end]).err(),
+                validate_frame(&data[..end]).err()
+            );
+        }
+        data[6] ^= 1;
+        assert_eq!(FrameParser::new(&data).err(), validate_frame(&data).err());
+        assert!(validate_frame(&data).is_err());
     }
 
     #[test]
-    #[cfg(feature = "uuid")]
-    fn can_read_uuid_from_a_frame() {
-        let test_uuid = uuid::Uuid::parse_str("40b4e52c-1501-48d3-98eb-e2c66cb76cbf").unwrap();
+    fn validate_frame_with_offset_reports_where_errors_are() {
         let mut data = Vec::new();
-
         {
-            let mut bld = FrameBuilder::new(&mut data);
-            bld.add_uuid(100, &test_uuid);
+            let mut bld = FrameBuilder::new_checked(&mut data);
+            bld.add_u8(1, 10);
+            bld.add_u8(2, 20);
         }
-
-        let frame = FrameParser::new(&data).unwrap();
-        assert_eq!(Some(test_uuid), frame.get_uuid(100).unwrap());
+        let at = |offset, error| Err(ErrorAt { offset, error });
+        assert_eq!(
+            at(0, Error::IncompleteFrameFormat),
+            validate_frame_with_offset(&[])
+        );
+        assert_eq!(
+            at(0, Error::InvalidFrameFormat(9)),
+            validate_frame_with_offset(&[9])
+        );
+        assert_eq!(
+            at(1, Error::IncompleteFrameFieldCount),
+            validate_frame_with_offset(&data[..3])
+        );
+        assert_eq!(
+            at(12, Error::IncompleteFieldTagOrLength),
+            validate_frame_with_offset(&data[..17])
+        );
+        assert_eq!(
+            at(19, Error::IncompleteFrameChecksum),
+            validate_frame_with_offset(&data[..20])
+        );
+        data.push(0);
+        assert_eq!(
+            at(23, Error::UnexpectedData),
+            validate_frame_with_offset(&data)
+        );
+        data[18] ^= 1;
+        assert_eq!(
+            Some(19),
+            validate_frame_with_offset(&data).err().map(|e| e.offset)
+        );
     }
 
     #[test]
-    #[cfg(feature = "uuid")]
-    fn can_read_uuids_from_a_frame() {
-        let test_uuid1 = uuid::Uuid::parse_str("40b4e52c-1501-48d3-98eb-e2c66cb76cbf").unwrap();
-        let test_uuid2 = uuid::Uuid::parse_str("e6bb35e5-547b-4930-b72c-5d50aa60ff49").unwrap();
-        let test_uuid3 = uuid::Uuid::parse_str("451f82e5-c17a-4843-a9c7-5ff3f7ae20fd").unwrap();
+    fn frame_kind_errors_depend_on_the_preferred_kind() {
+        let mut frame = Vec::new();
+        FrameBuilder::new(&mut frame).add_u8(12, 1);
+        assert_eq!(
+            Ok(FrameKind::Frame),
+            detect_frame_kind(&frame, FrameKind::Frame)
+        );
+        frame.push(0);
+        assert_eq!(
+            Err(Error::UnexpectedData),
+            detect_frame_kind(&frame, FrameKind::Frame)
+        );
+        assert_eq!(
+            Err(Error::FrameSizeMismatch {
+                declared: 0x0100_0000,
+                actual: 9
+            }),
+            detect_frame_kind(&frame, FrameKind::PacketFrame)
+        );
+
+        let mut packet_frame = Vec::new();
+        PacketFrameBuilder::new(&mut packet_frame).add_u8(12, 1);
+        assert_eq!(
+            Ok(FrameKind::PacketFrame),
+            detect_frame_kind(&packet_frame, FrameKind::PacketFrame)
+        );
+        assert_eq!(
+            Err(Error::InvalidFrameFormat(0)),
+            detect_frame_kind(&packet_frame[..15], FrameKind::Frame)
+        );
+    }
 
+    #[test]
+    fn validate_frame_tree_reports_invalid_child_frames() {
         let mut data = Vec::new();
         {
             let mut bld = FrameBuilder::new(&mut data);
-            bld.add_uuid(1, &test_uuid1);
-            bld.add_uuid(2, &test_uuid2); // will be ignored
-            bld.add_uuid(1, &test_uuid3);
+            bld.add_frame(1).add_u8(2, 2);
+            bld.add_data(1, &[1, 0, 0, 0, 1]);
         }
-        let frame = FrameParser::new(&data).unwrap();
-        let expected: Vec<Result<uuid::Uuid>> = vec![Ok(test_uuid1), Ok(test_uuid3)];
-        let actual: Vec<Result<uuid::Uuid>> = frame.get_uuids(1).collect();
-        assert_eq!(expected, actual);
+        assert_eq!(Ok(()), validate_frame(&data));
+        assert_eq!(Ok(()), validate_frame_tree(&data, &[2], 0));
+        assert_eq!(
+            Err(Error::IncompleteFieldTagOrLength),
+            validate_frame_tree(&data, &[1], 1)
+        );
     }
 
     #[test]
-    fn can_read_child_frame() {
+    fn visitor_errors_stop_the_walk() {
         let mut data = Vec::new();
         {
             let mut bld = FrameBuilder::new(&mut data);
-            bld.add_u8(100, 1);
-            let mut bld2 = bld.add_frame(200);
-            bld2.add_u8(300, 3);
+            bld.add_u8(1, 1);
+            bld.add_u8(2, 2);
+            bld.add_u8(3, 3);
         }
-
-        let frame = FrameParser::new(&data).unwrap();
-        let child_frame = frame.get_frame(200).unwrap().unwrap();
-        assert_eq!(Some(3), child_frame.get_u8(300).unwrap());
+        let mut visited = Vec::new();
+        let result = parse_with_visitor(&data, |tag, _| {
+            visited.push(tag);
+            if tag == 2 {
+                Err(Error::MissingField(4))
+            } else {
+                Ok(())
+            }
+        });
+        assert_eq!(Err(Error::MissingField(4)), result);
+        assert_eq!(vec![1, 2], visited);
     }
 
     #[test]
-    fn can_read_child_frames() {
-        let mut data = Vec::new();
-        {
-            let mut bld = FrameBuilder::new(&mut data);
-            bld.add_u8(100, 1);
-            for i in 0..2 {
-                let mut bld2 = bld.add_frame(200);
-                bld2.add_u8(300, i);
+    fn negative_numbers_are_sign_extended_when_read_by_a_larger_method() {
+        for &endianness in &[Endianness::Big, Endianness::Little] {
+            let mut data = Vec::new();
+            {
+                let mut bld = FrameBuilder::new_with_endianness(&mut data, endianness);
+                bld.add_i8(1, -1);
+                bld.add_i8(2, i8::MIN);
+                bld.add_i16(3, -1);
+                bld.add_i16(4, i16::MIN);
+                bld.add_i32(5, -1);
+                bld.add_i32(6, i32::MIN);
+                bld.add_i64(7, -1);
+                bld.add_i64(8, i64::MIN);
             }
+            let options = ParseOptions {
+                endianness,
+                ..ParseOptions::default()
+            };
+            let frame = FrameParser::new_with_options(&data, options).unwrap();
+
+            assert_eq!(Some(-1), frame.get_i16(1).unwrap());
+            assert_eq!(Some(-1), frame.get_i32(1).unwrap());
+            assert_eq!(Some(-1), frame.get_i64(1).unwrap());
+            assert_eq!(Some(-1), frame.get_i128(1).unwrap());
+            assert_eq!(Some(i8::MIN as i16), frame.get_i16(2).unwrap());
+            assert_eq!(Some(i8::MIN as i128), frame.get_i128(2).unwrap());
+
+            assert_eq!(Some(-1), frame.get_i32(3).unwrap());
+            assert_eq!(Some(-1), frame.get_i64(3).unwrap());
+            assert_eq!(Some(-1), frame.get_i128(3).unwrap());
+            assert_eq!(Some(i16::MIN as i32), frame.get_i32(4).unwrap());
+            assert_eq!(Some(i16::MIN as i64), frame.get_i64(4).unwrap());
+
+            assert_eq!(Some(-1), frame.get_i64(5).unwrap());
+            assert_eq!(Some(-1), frame.get_i128(5).unwrap());
+            assert_eq!(Some(i32::MIN as i64), frame.get_i64(6).unwrap());
+
+            assert_eq!(Some(-1), frame.get_i128(7).unwrap());
+            assert_eq!(Some(i64::MIN as i128), frame.get_i128(8).unwrap());
+
+            // signed and unsigned are not interchangeable
+            assert_eq!(Some(0xFFFF), frame.get_u32(3).unwrap());
+            assert_eq!(
+                Some(Error::IncompatibleFieldLength(2)),
+                frame.get_i8(3).err()
+            );
         }
+    }
 
-        let frame = FrameParser::new(&data).unwrap();
-        let child_frame: Vec<FrameParser> = frame.get_frames(200).map(|v| v.unwrap()).collect();
-
-        assert_eq!(Some(0), child_frame[0].get_u8(300).unwrap());
-        assert_eq!(Some(1), child_frame[1].get_u8(300).unwrap());
+    #[test]
+    fn lenient_bools_accept_any_non_zero_byte() {
+        for b in 1..=255u8 {
+            assert_eq!(Ok(true), decode_bool_lenient(&[b]));
+        }
+        assert_eq!(Ok(false), decode_bool_lenient(&[0]));
+        assert_eq!(
+            Err(Error::IncompatibleFieldLength(0)),
+            decode_bool_lenient(&[])
+        );
+        assert_eq!(
+            Err(Error::IncompatibleFieldLength(2)),
+            decode_bool_lenient(&[0xFF, 0xFF])
+        );
     }
 }
@@ -0,0 +1,303 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{FrameBuilder, FrameBuilderLike, FrameFormat, FrameParser, ParseOptions, Result};
+
+/// OwnedFrameParser holds a frame in its own buffer.
+///
+/// A [FrameParser] borrows the data it parses, which makes it awkward to keep
+/// around once the buffer it came from goes out of scope.  An `OwnedFrameParser`
+/// owns a validated copy of the frame and hands out [FrameParser]s that borrow from it.
+///
+/// The trade-off is that each call to [OwnedFrameParser::parser] re-reads the field
+/// headers, so callers performing many lookups should hold on to the returned parser.
+/// Holding on to that parser borrows the `OwnedFrameParser` again, so it can not be
+/// moved while the parser is in use.
+///
+/// An existing [FrameParser] can be converted with [FrameParser::into_owned].
+///
+/// ```
+/// # use yatlv::{FrameBuilder, FrameBuilderLike, OwnedFrameParser, Result};
+/// # fn main() -> Result<()> {
+/// let mut frame_data = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new(&mut frame_data);
+///     bld.add_u8(12, 9);
+/// }
+///
+/// let owned = OwnedFrameParser::new(frame_data)?;
+/// assert_eq!(Some(9), owned.parser().get_u8(12)?);
+/// # Ok(()) }
+/// ```
+#[derive(Clone)]
+pub struct OwnedFrameParser {
+    data: Vec<u8>,
+    options: ParseOptions,
+}
+
+impl OwnedFrameParser {
+    /// Take ownership of `frame_data`, checking that it contains a valid frame.
+    pub fn new(frame_data: Vec<u8>) -> Result<OwnedFrameParser> {
+        OwnedFrameParser::new_with_options(frame_data, ParseOptions::default())
+    }
+
+    /// Take ownership of `frame_data`, checking that it contains a valid frame
+    /// when parsed with `options`.
+    ///
+    /// The same options are used by each parser created by [OwnedFrameParser::parser].
+    ///
+    /// With the `compression` feature, a compressed frame (frame-format `0x04`) is
+    /// decompressed and the decompressed frame is held instead.  A decompressed frame
+    /// larger than [ParseOptions::max_decompressed_size] (8MiB by default) is rejected
+    /// with [crate::Error::DecompressedFrameTooLarge], so a small compressed frame can
+    /// not exhaust memory.
+    pub fn new_with_options(
+        frame_data: Vec<u8>,
+        options: ParseOptions,
+    ) -> Result<OwnedFrameParser> {
+        #[cfg(feature = "compression")]
+        let frame_data = match frame_data.first() {
+            Some(4) => decompress_frame(&frame_data, options)?,
+            _ => frame_data,
+        };
+        FrameParser::new_with_options(&frame_data, options)?;
+        Ok(OwnedFrameParser {
+            data: frame_data,
+            options,
+        })
+    }
+
+    /// Create a [FrameParser] that borrows from this frame.
+    pub fn parser(&self) -> FrameParser<'_> {
+        FrameParser::new_with_options(&self.data, self.options)
+            .expect("frame was validated on construction")
+    }
+
+    /// Access the encoded frame (after any decompression).
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Release the encoded frame.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+/// Decompress a frame with frame-format `0x04`, which must hold a frame with
+/// frame-format `0x01`.
+#[cfg(feature = "compression")]
+fn decompress_frame(frame_data: &[u8], options: ParseOptions) -> Result<Vec<u8>> {
+    let (compressed, tail) = crate::read_compressed_frame(&frame_data[1..])?;
+    if !tail.is_empty() {
+        return Err(crate::Error::UnexpectedData);
+    }
+    let frame = crate::decompress(compressed, options.max_decompressed_size)?;
+    match frame.first() {
+        Some(1) => Ok(frame),
+        Some(format) => Err(crate::Error::InvalidFrameFormat(*format as u32)),
+        None => Err(crate::Error::IncompleteFrameFormat),
+    }
+}
+
+impl fmt::Debug for OwnedFrameParser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.parser().fmt(f)
+    }
+}
+
+impl<'a> FrameParser<'a> {
+    /// Copy this frame into an [OwnedFrameParser], so it no longer borrows the
+    /// data it was parsed from.
+    ///
+    /// The fields are copied into a new buffer, keeping the frame-format and the
+    /// options the frame was parsed with.
+    ///
+    /// ```
+    /// # use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser, OwnedFrameParser, Result};
+    /// # fn main() -> Result<()> {
+    /// fn read_child(frame_data: &[u8]) -> Result<OwnedFrameParser> {
+    ///     let parser = FrameParser::new(frame_data)?;
+    ///     Ok(parser.get_frame_required(1)?.into_owned())
+    /// }
+    ///
+    /// let mut frame_data = Vec::new();
+    /// FrameBuilder::new(&mut frame_data).add_frame(1).add_u8(2, 7);
+    ///
+    /// let child = read_child(&frame_data)?;
+    /// drop(frame_data);
+    /// assert_eq!(Some(7), child.parser().get_u8(2)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn into_owned(self) -> OwnedFrameParser {
+        let mut data = Vec::new();
+        {
+            let mut bld = match self.format() {
+                FrameFormat::Format1 => FrameBuilder::new(&mut data),
+                FrameFormat::Format2 => FrameBuilder::new_checked(&mut data),
+                FrameFormat::Format5 => FrameBuilder::new_large(&mut data),
+                FrameFormat::Format3 | FrameFormat::Format4 => {
+                    unreachable!("wide and compressed frames are not parsed by FrameParser")
+                }
+            };
+            for (tag, value) in self.fields() {
+                bld.add_data(tag, value);
+            }
+        }
+        OwnedFrameParser {
+            data,
+            options: self.options(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Endianness, Error};
+
+    #[test]
+    fn can_parse_an_owned_frame() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_str(1, "hello");
+        }
+        let owned = OwnedFrameParser::new(data.clone()).unwrap();
+        assert_eq!(Some("hello"), owned.parser().get_str(1).unwrap());
+        assert_eq!(&data[..], owned.as_bytes());
+        assert_eq!(data, owned.into_bytes());
+    }
+
+    #[test]
+    fn into_owned_keeps_the_format_and_options() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new_checked(&mut data);
+            bld.add_u16(1, 1);
+            bld.add_frame(2).add_u16(3, 3);
+        }
+        let options = ParseOptions {
+            endianness: Endianness::Little,
+            ..ParseOptions::default()
+        };
+        let parser = FrameParser::new_with_options(&data, options).unwrap();
+        let owned = parser.clone().into_owned();
+        assert_eq!(&data[..], owned.as_bytes());
+        assert_eq!(Some(0x100), owned.parser().get_u16(1).unwrap());
+
+        let child = parser.get_frame_required(2).unwrap().into_owned();
+        let cloned = child.clone();
+        drop(child);
+        assert_eq!(FrameFormat::Format1, cloned.parser().format());
+        assert_eq!(Some(0x300), cloned.parser().get_u16(3).unwrap());
+    }
+
+    #[test]
+    fn can_not_own_an_invalid_frame() {
+        assert_eq!(
+            Some(Error::IncompleteFrameFieldCount),
+            OwnedFrameParser::new(vec![1, 0, 0]).err()
+        );
+    }
+
+    #[cfg(feature = "compression")]
+    fn compressed_frame() -> Vec<u8> {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new_compressed(&mut data);
+            bld.add_str(1, "hello ".repeat(50));
+            bld.add_frame(2).add_u8(3, 3);
+        }
+        data
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn can_read_a_compressed_frame() {
+        let data = compressed_frame();
+        assert_eq!(Ok(data.len()), crate::frame_encoded_len(&data));
+        assert_eq!(
+            Some(Error::InvalidFrameFormat(4)),
+            FrameParser::new(&data).err()
+        );
+
+        let owned = OwnedFrameParser::new(data).unwrap();
+        let parser = owned.parser();
+        assert_eq!(crate::FrameFormat::Format1, parser.format());
+        assert_eq!(300, parser.get_str(1).unwrap().unwrap().len());
+        assert_eq!(
+            Some(3),
+            parser.get_frame_required(2).unwrap().get_u8(3).unwrap()
+        );
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn compressed_builders_can_be_reset() {
+        let mut data = Vec::new();
+        let mut bld = FrameBuilder::new_compressed(&mut data);
+        bld.add_u8(1, 1);
+        let first = bld.finish_frame().to_vec();
+        bld.reset();
+        bld.add_u8(1, 2);
+        let second = bld.finish_frame().to_vec();
+        drop(bld);
+        assert_eq!(second, data);
+
+        let first = OwnedFrameParser::new(first).unwrap();
+        assert_eq!(Some(1), first.parser().get_u8(1).unwrap());
+        let second = OwnedFrameParser::new(second).unwrap();
+        assert_eq!(Some(2), second.parser().get_u8(1).unwrap());
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn can_not_read_a_damaged_compressed_frame() {
+        let data = compressed_frame();
+        let last = data.len() - 1;
+        assert_eq!(
+            Some(Error::IncompleteCompressedFrame(last - 5 + 1, last - 5)),
+            OwnedFrameParser::new(data[..last].to_vec()).err()
+        );
+
+        let mut damaged = data.clone();
+        damaged[5] ^= 0xFF;
+        assert_eq!(
+            Some(Error::InvalidCompressedFrame),
+            OwnedFrameParser::new(damaged).err()
+        );
+
+        let mut extended = data;
+        extended.push(0);
+        assert_eq!(
+            Some(Error::UnexpectedData),
+            OwnedFrameParser::new(extended).err()
+        );
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn decompressed_frames_larger_than_the_limit_are_rejected() {
+        let options = ParseOptions {
+            max_decompressed_size: 100,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            Some(Error::DecompressedFrameTooLarge(100)),
+            OwnedFrameParser::new_with_options(compressed_frame(), options).err()
+        );
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn decompressed_frames_are_limited_by_default() {
+        let mut data = Vec::new();
+        FrameBuilder::new_compressed(&mut data).add_data(1, &vec![0; 8 * 1024 * 1024]);
+        assert!(data.len() < 64 * 1024);
+        assert_eq!(
+            Some(Error::DecompressedFrameTooLarge(8 * 1024 * 1024)),
+            OwnedFrameParser::new(data).err()
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use core::convert::TryInto;
+use core::ops::Deref;
+
+use crate::{Error, FrameParser, ParseOptions, Result, SIZE_BYTES};
+
+/// PacketFrameParser reads a packet-frame, as written by
+/// [PacketFrameBuilder](crate::PacketFrameBuilder).
+///
+/// The frame-size must match the length of the data that follows it; otherwise the
+/// packet-frame is rejected with [Error::FrameSizeMismatch].  The enclosed frame is
+/// read with a [FrameParser], and its getters can be called directly on the
+/// `PacketFrameParser`.
+///
+/// Use [FrameParser::from_packet] to read a packet-frame from the start of a larger
+/// buffer.
+///
+/// ```
+/// # use yatlv::{Error, FrameBuilderLike, PacketFrameBuilder, PacketFrameParser, Result};
+/// # fn main() -> Result<()> {
+/// let mut data = Vec::new();
+/// {
+///     let mut bld = PacketFrameBuilder::new(&mut data);
+///     bld.add_u8(12, 9);
+/// }
+///
+/// let parser = PacketFrameParser::new(&data)?;
+/// assert_eq!(Some(9), parser.get_u8(12)?);
+///
+/// data.push(0);
+/// assert_eq!(
+///     Some(Error::FrameSizeMismatch { declared: 12, actual: 13 }),
+///     PacketFrameParser::new(&data).err()
+/// );
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PacketFrameParser<'a> {
+    parser: FrameParser<'a>,
+}
+
+impl<'a> PacketFrameParser<'a> {
+    /// Parse the packet-frame in `data`, using the default [ParseOptions].
+    pub fn new(data: &'a [u8]) -> Result<PacketFrameParser<'a>> {
+        PacketFrameParser::new_with_options(data, ParseOptions::default())
+    }
+
+    /// Parse the packet-frame in `data`, using `options`.
+    ///
+    /// A frame-size larger than [ParseOptions::max_frame_size] is rejected with
+    /// [Error::FrameTooLarge].
+    pub fn new_with_options(
+        data: &'a [u8],
+        options: ParseOptions,
+    ) -> Result<PacketFrameParser<'a>> {
+        if data.len() < SIZE_BYTES {
+            return Err(Error::IncompleteFrameSize);
+        }
+        let (size_bytes, frame_data) = data.split_at(SIZE_BYTES);
+        let frame_size = u32::from_be_bytes(size_bytes.try_into().unwrap());
+        if frame_size > options.max_frame_size {
+            return Err(Error::FrameTooLarge {
+                declared: frame_size,
+                limit: options.max_frame_size,
+            });
+        }
+        if frame_size as usize != frame_data.len() {
+            return Err(Error::FrameSizeMismatch {
+                declared: frame_size as usize,
+                actual: frame_data.len(),
+            });
+        }
+        let parser = FrameParser::new_with_options(frame_data, options)?;
+        Ok(PacketFrameParser { parser })
+    }
+
+    /// The parser for the enclosed frame.
+    pub fn parser(&self) -> &FrameParser<'a> {
+        &self.parser
+    }
+
+    /// Release the parser for the enclosed frame.
+    pub fn into_parser(self) -> FrameParser<'a> {
+        self.parser
+    }
+}
+
+impl<'a> Deref for PacketFrameParser<'a> {
+    type Target = FrameParser<'a>;
+
+    fn deref(&self) -> &FrameParser<'a> {
+        &self.parser
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FrameBuilderLike, PacketFrameBuilder};
+
+    fn packet_frame() -> Vec<u8> {
+        let mut data = Vec::new();
+        {
+            let mut bld = PacketFrameBuilder::new(&mut data);
+            bld.add_str(1, "one");
+            bld.add_frame(2).add_u16(3, 3);
+        }
+        data
+    }
+
+    #[test]
+    fn can_read_a_packet_frame() {
+        let data = packet_frame();
+        let parser = PacketFrameParser::new(&data).unwrap();
+        assert_eq!(2, parser.len());
+        assert_eq!(Some("one"), parser.get_str(1).unwrap());
+        let child = parser.get_frame_required(2).unwrap();
+        assert_eq!(Some(3), child.get_u16(3).unwrap());
+        assert_eq!(1, parser.into_parser().get_strs(1).count());
+    }
+
+    #[test]
+    fn frame_size_must_match_the_data() {
+        let data = packet_frame();
+        let len = data.len();
+        assert_eq!(
+            Some(Error::IncompleteFrameSize),
+            PacketFrameParser::new(&data[..3]).err()
+        );
+        assert_eq!(
+            Some(Error::FrameSizeMismatch {
+                declared: len - SIZE_BYTES,
+                actual: len - SIZE_BYTES - 1
+            }),
+            PacketFrameParser::new(&data[..len - 1]).err()
+        );
+
+        let options = ParseOptions {
+            max_frame_size: 10,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            Some(Error::FrameTooLarge {
+                declared: (len - SIZE_BYTES) as u32,
+                limit: 10
+            }),
+            PacketFrameParser::new_with_options(&data, options).err()
+        );
+    }
+}
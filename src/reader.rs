@@ -0,0 +1,245 @@
+use std::io::{Error, ErrorKind, Read, Result};
+
+use crate::{OwnedFrameParser, ParseOptions, SIZE_BYTES};
+
+/// FrameReader reads a sequence of packet-frames from a stream.
+///
+/// Each packet-frame is read in full (looping over partial reads as needed)
+/// and returned as an [OwnedFrameParser].
+///
+/// ```
+/// # use yatlv::{FrameReader, FrameBuilderLike, PacketFrameBuilder};
+/// # fn main() -> std::io::Result<()> {
+/// let mut stream = Vec::new();
+/// for i in 0..2 {
+///     let mut bld = PacketFrameBuilder::new(&mut stream);
+///     bld.add_u8(12, i);
+/// }
+///
+/// let mut reader = FrameReader::new(&stream[..]);
+/// let frame1 = reader.next_frame()?.unwrap();
+/// let frame2 = reader.next_frame()?.unwrap();
+/// assert_eq!(Some(0), frame1.parser().get_u8(12).unwrap());
+/// assert_eq!(Some(1), frame2.parser().get_u8(12).unwrap());
+/// assert!(reader.next_frame()?.is_none());
+/// # Ok(()) }
+/// ```
+pub struct FrameReader<R> {
+    reader: R,
+    options: ParseOptions,
+}
+
+impl<R: Read> FrameReader<R> {
+    pub fn new(reader: R) -> FrameReader<R> {
+        FrameReader::new_with_options(reader, ParseOptions::default())
+    }
+
+    /// Create a FrameReader that parses each frame with `options`.
+    ///
+    /// A packet-frame whose frame-size is larger than [ParseOptions::max_frame_size]
+    /// is rejected before any of its frame is read, with an error of kind
+    /// [ErrorKind::InvalidData] wrapping [crate::Error::FrameTooLarge].
+    ///
+    /// ```
+    /// # use std::io::ErrorKind;
+    /// # use yatlv::{FrameReader, ParseOptions};
+    /// let options = ParseOptions {
+    ///     max_frame_size: 16 * 1024 * 1024,
+    ///     ..ParseOptions::default()
+    /// };
+    /// // a peer claiming a 4 GiB frame
+    /// let stream = [255, 255, 255, 255, 1, 0, 0, 0, 0];
+    /// let mut reader = FrameReader::new_with_options(&stream[..], options);
+    /// assert_eq!(ErrorKind::InvalidData, reader.next_frame().unwrap_err().kind());
+    /// ```
+    pub fn new_with_options(reader: R, options: ParseOptions) -> FrameReader<R> {
+        FrameReader { reader, options }
+    }
+
+    /// Read the next packet-frame from the stream.
+    ///
+    /// Returns `Ok(None)` if the stream ends cleanly between packet-frames.  If
+    /// the stream ends part way through a packet-frame an error of kind
+    /// [ErrorKind::UnexpectedEof] is returned, while a packet-frame that does not
+    /// contain a valid frame produces an error of kind [ErrorKind::InvalidData]
+    /// wrapping the underlying [crate::Error].
+    pub fn next_frame(&mut self) -> Result<Option<OwnedFrameParser>> {
+        let frame_size = match self.read_frame_size()? {
+            Some(frame_size) => frame_size,
+            None => return Ok(None),
+        };
+        if frame_size > self.options.max_frame_size {
+            let err = crate::Error::FrameTooLarge {
+                declared: frame_size,
+                limit: self.options.max_frame_size,
+            };
+            return Err(Error::new(ErrorKind::InvalidData, err));
+        }
+
+        // read through `take` so that the buffer only grows as data actually arrives
+        let mut frame_data = Vec::new();
+        (&mut self.reader)
+            .take(frame_size as u64)
+            .read_to_end(&mut frame_data)?;
+        if frame_data.len() != frame_size as usize {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+
+        OwnedFrameParser::new_with_options(frame_data, self.options)
+            .map(Some)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+
+    /// Access the underlying stream.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn read_frame_size(&mut self) -> Result<Option<u32>> {
+        let mut size_bytes = [0; SIZE_BYTES];
+        let mut filled = 0;
+        while filled < SIZE_BYTES {
+            match self.reader.read(&mut size_bytes[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(Some(u32::from_be_bytes(size_bytes)))
+    }
+}
+
+impl<R: Read> Iterator for FrameReader<R> {
+    type Item = Result<OwnedFrameParser>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_frame().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FrameBuilderLike, PacketFrameBuilder};
+
+    /// A reader that only ever returns one byte at a time.
+    struct TrickleReader<'a> {
+        data: &'a [u8],
+    }
+
+    impl<'a> Read for TrickleReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            if self.data.is_empty() || buf.is_empty() {
+                return Ok(0);
+            }
+            buf[0] = self.data[0];
+            self.data = &self.data[1..];
+            Ok(1)
+        }
+    }
+
+    fn two_packet_frames() -> Vec<u8> {
+        let mut data = Vec::new();
+        {
+            let mut bld = PacketFrameBuilder::new(&mut data);
+            bld.add_str(1, "hello");
+        }
+        {
+            let mut bld = PacketFrameBuilder::new(&mut data);
+            bld.add_str(1, "goodbye");
+        }
+        data
+    }
+
+    #[test]
+    fn can_read_nothing_from_an_empty_stream() {
+        let mut reader = FrameReader::new(&[][..]);
+        assert!(reader.next_frame().unwrap().is_none());
+    }
+
+    #[test]
+    fn can_read_packet_frames_from_partial_reads() {
+        let data = two_packet_frames();
+        let mut reader = FrameReader::new(TrickleReader { data: &data });
+        let frame1 = reader.next_frame().unwrap().unwrap();
+        let frame2 = reader.next_frame().unwrap().unwrap();
+        assert_eq!(Some("hello"), frame1.parser().get_str(1).unwrap());
+        assert_eq!(Some("goodbye"), frame2.parser().get_str(1).unwrap());
+        assert!(reader.next_frame().unwrap().is_none());
+    }
+
+    #[test]
+    fn can_iterate_over_packet_frames() {
+        let data = two_packet_frames();
+        let frames: Vec<OwnedFrameParser> =
+            FrameReader::new(&data[..]).map(|f| f.unwrap()).collect();
+        assert_eq!(2, frames.len());
+    }
+
+    #[test]
+    fn can_not_read_a_truncated_frame_size() {
+        let mut reader = FrameReader::new(TrickleReader { data: &[0, 0, 1] });
+        let err = reader.next_frame().err().unwrap();
+        assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+    }
+
+    #[test]
+    fn can_not_read_a_truncated_frame() {
+        let data = two_packet_frames();
+        let mut reader = FrameReader::new(&data[..data.len() - 1]);
+        assert!(reader.next_frame().unwrap().is_some());
+        let err = reader.next_frame().err().unwrap();
+        assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+    }
+
+    #[test]
+    fn can_not_read_a_frame_with_a_huge_declared_size() {
+        let data = [255, 255, 255, 255, 1, 0, 0, 0, 0];
+        let mut reader = FrameReader::new(&data[..]);
+        let err = reader.next_frame().err().unwrap();
+        assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+    }
+
+    #[test]
+    fn can_not_read_an_invalid_frame() {
+        let data = [0, 0, 0, 1, 7];
+        let mut reader = FrameReader::new(&data[..]);
+        let err = reader.next_frame().err().unwrap();
+        assert_eq!(ErrorKind::InvalidData, err.kind());
+        let inner = err
+            .into_inner()
+            .unwrap()
+            .downcast::<crate::Error>()
+            .unwrap();
+        assert_eq!(crate::Error::InvalidFrameFormat(7), *inner);
+    }
+
+    #[test]
+    fn can_not_read_a_frame_larger_than_the_limit() {
+        let data = two_packet_frames();
+        let options = ParseOptions {
+            max_frame_size: 17,
+            ..ParseOptions::default()
+        };
+        let mut reader = FrameReader::new_with_options(&data[..], options);
+        assert!(reader.next_frame().unwrap().is_some());
+        let err = reader.next_frame().err().unwrap();
+        assert_eq!(ErrorKind::InvalidData, err.kind());
+        let inner = err
+            .into_inner()
+            .unwrap()
+            .downcast::<crate::Error>()
+            .unwrap();
+        assert_eq!(
+            crate::Error::FrameTooLarge {
+                declared: 18,
+                limit: 17
+            },
+            *inner
+        );
+        // the body of the rejected frame has not been read
+        assert_eq!(18, reader.into_inner().len());
+    }
+}
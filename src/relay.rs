@@ -0,0 +1,94 @@
+use crate::{Error, FrameParser, Result};
+
+/// RelayFrame is a frame read by [FrameParser::new_opaque_unknown], which may have a
+/// frame-format that the [FrameParser] does not understand.
+///
+/// A relay or proxy can forward a frame it can not parse using
+/// [RelayFrame::raw_bytes], whether or not its fields could be read.
+#[derive(Debug, Clone)]
+pub enum RelayFrame<'a> {
+    /// A frame with a frame-format that could be parsed.
+    Parsed(FrameParser<'a>),
+    /// The bytes of a frame with a frame-format that is not understood.
+    Opaque(&'a [u8]),
+}
+
+impl<'a> RelayFrame<'a> {
+    /// The encoded frame.
+    pub fn raw_bytes(&self) -> &'a [u8] {
+        match self {
+            RelayFrame::Parsed(parser) => parser.raw_bytes(),
+            RelayFrame::Opaque(data) => data,
+        }
+    }
+
+    /// The parsed frame, unless the frame is opaque.
+    pub fn parsed(&self) -> Option<&FrameParser<'a>> {
+        match self {
+            RelayFrame::Parsed(parser) => Some(parser),
+            RelayFrame::Opaque(_) => None,
+        }
+    }
+}
+
+impl<'a> FrameParser<'a> {
+    /// Parse a frame, treating a frame-format that [FrameParser::new] rejects with
+    /// [Error::InvalidFrameFormat] as an opaque frame rather than an error.
+    ///
+    /// This is for relays, which should pass on frames written by newer versions of a
+    /// protocol even if they can not read them.  The rest of an opaque frame is not
+    /// checked.  A frame with a known frame-format that is malformed is still an
+    /// error.
+    ///
+    /// ```
+    /// # use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser, RelayFrame, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut data = Vec::new();
+    /// FrameBuilder::new(&mut data).add_u8(12, 1);
+    /// let frame = FrameParser::new_opaque_unknown(&data)?;
+    /// assert_eq!(Some(1), frame.parsed().unwrap().get_u8(12)?);
+    /// assert_eq!(&data[..], frame.raw_bytes());
+    ///
+    /// let future_data = [0x7f, 1, 2, 3];
+    /// let frame = FrameParser::new_opaque_unknown(&future_data)?;
+    /// assert!(matches!(frame, RelayFrame::Opaque(_)));
+    /// assert_eq!(&future_data[..], frame.raw_bytes());
+    /// # Ok(()) }
+    /// ```
+    pub fn new_opaque_unknown(frame_data: &'a [u8]) -> Result<RelayFrame<'a>> {
+        match FrameParser::new(frame_data) {
+            Ok(parser) => Ok(RelayFrame::Parsed(parser)),
+            Err(Error::InvalidFrameFormat(_)) => Ok(RelayFrame::Opaque(frame_data)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FrameBuilder, FrameBuilderLike};
+
+    #[test]
+    fn wide_frames_are_opaque() {
+        let mut data = Vec::new();
+        FrameBuilder::new_wide_tags(&mut data).add(70_000, 1u8);
+        let frame = FrameParser::new_opaque_unknown(&data).unwrap();
+        assert!(frame.parsed().is_none());
+        assert_eq!(&data[..], frame.raw_bytes());
+    }
+
+    #[test]
+    fn malformed_frames_are_still_errors() {
+        let mut data = Vec::new();
+        FrameBuilder::new(&mut data).add_u8(12, 1);
+        assert_eq!(
+            Some(Error::IncompleteFieldValue(1, 0)),
+            FrameParser::new_opaque_unknown(&data[..data.len() - 1]).err()
+        );
+        assert_eq!(
+            Some(Error::IncompleteFrameFormat),
+            FrameParser::new_opaque_unknown(&[]).err()
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Endianness, Error, FrameParser, FromField, Result};
+
+/// The type a field-value is expected to decode as, when checked by a [Schema].
+///
+/// Each type is checked with the same decoding as the matching `get_*` method of
+/// [FrameParser], so numbers use the byte order of the frame.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FieldType {
+    /// Any field-value.
+    Data,
+    /// A boolean (see [FrameParser::get_bool]).
+    Bool,
+    /// An unsigned number that fits in a u8.
+    U8,
+    /// An unsigned number that fits in a u16.
+    U16,
+    /// An unsigned number that fits in a u32.
+    U32,
+    /// An unsigned number that fits in a u64.
+    U64,
+    /// An unsigned number that fits in a u128.
+    U128,
+    /// A signed number that fits in an i8.
+    I8,
+    /// A signed number that fits in an i16.
+    I16,
+    /// A signed number that fits in an i32.
+    I32,
+    /// A signed number that fits in an i64.
+    I64,
+    /// A signed number that fits in an i128.
+    I128,
+    /// A 32-bit float.
+    F32,
+    /// A 64-bit float.
+    F64,
+    /// A single character.
+    Char,
+    /// UTF-8 text.
+    Str,
+    /// A child frame, which is parsed but whose fields are not checked.
+    Frame,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SchemaField {
+    field_type: FieldType,
+    required: bool,
+}
+
+/// A problem found by [Schema::validate], with the field-tag it was found on.
+#[derive(Debug, Eq, PartialEq)]
+pub struct SchemaError {
+    /// The field-tag of the field (or missing field) that is in error.
+    pub tag: u16,
+    /// The error: [Error::MissingField] for a missing required field,
+    /// [Error::DuplicateTag] for a repeated field, or the error from decoding a
+    /// field-value as its declared [FieldType].
+    pub error: Error,
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "field-tag {}: {}", self.tag, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SchemaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Schema declares the fields a frame is expected to hold, so that a frame can be
+/// checked in one call when it is received.
+///
+/// A required field must appear exactly once and an optional field at most once.
+/// Each field that is present must decode as its declared [FieldType].  Fields with
+/// tags that are not in the schema are ignored, so that frames written by newer
+/// versions of a protocol are still accepted.
+///
+/// ```
+/// # use yatlv::{Error, FieldType, FrameBuilder, FrameBuilderLike, FrameParser, Schema, SchemaError};
+/// let mut schema = Schema::new();
+/// schema
+///     .required(1, FieldType::U32)
+///     .required(2, FieldType::Str)
+///     .optional(3, FieldType::Bool);
+///
+/// let mut data = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new(&mut data);
+///     bld.add_u32(1, 7);
+///     bld.add_data(3, &[2]);
+///     bld.add_u8(4, 9);
+/// }
+/// let frame = FrameParser::new(&data).unwrap();
+/// assert_eq!(
+///     Err(vec![
+///         SchemaError { tag: 2, error: Error::MissingField(2) },
+///         SchemaError { tag: 3, error: Error::IncompatibleFieldValue },
+///     ]),
+///     schema.validate(&frame)
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    fields: BTreeMap<u16, SchemaField>,
+}
+
+impl Schema {
+    /// Create a schema with no fields.
+    pub fn new() -> Schema {
+        Schema::default()
+    }
+
+    /// Declare a field that must appear exactly once, replacing any earlier
+    /// declaration of `tag`.
+    pub fn required(&mut self, tag: u16, field_type: FieldType) -> &mut Schema {
+        self.add(tag, field_type, true)
+    }
+
+    /// Declare a field that may appear at most once, replacing any earlier
+    /// declaration of `tag`.
+    pub fn optional(&mut self, tag: u16, field_type: FieldType) -> &mut Schema {
+        self.add(tag, field_type, false)
+    }
+
+    fn add(&mut self, tag: u16, field_type: FieldType, required: bool) -> &mut Schema {
+        self.fields.insert(
+            tag,
+            SchemaField {
+                field_type,
+                required,
+            },
+        );
+        self
+    }
+
+    /// Check `frame` against this schema, returning every problem found, ordered by
+    /// field-tag.
+    pub fn validate<'a>(
+        &self,
+        frame: &'a FrameParser<'a>,
+    ) -> core::result::Result<(), Vec<SchemaError>> {
+        let mut errors = Vec::new();
+        for (&tag, field) in &self.fields {
+            let values: Vec<&[u8]> = frame.get_datas(tag).collect();
+            if values.is_empty() && field.required {
+                errors.push(SchemaError {
+                    tag,
+                    error: Error::MissingField(tag),
+                });
+            }
+            if values.len() > 1 {
+                errors.push(SchemaError {
+                    tag,
+                    error: Error::DuplicateTag(tag),
+                });
+            }
+            for value in values {
+                if let Err(error) = check_value(frame, field.field_type, value) {
+                    errors.push(SchemaError { tag, error });
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn check_value(frame: &FrameParser<'_>, field_type: FieldType, value: &[u8]) -> Result<()> {
+    let endianness = frame.endianness();
+    match field_type {
+        FieldType::Data => Ok(()),
+        FieldType::Bool => check::<bool>(value, endianness),
+        FieldType::U8 => check::<u8>(value, endianness),
+        FieldType::U16 => check::<u16>(value, endianness),
+        FieldType::U32 => check::<u32>(value, endianness),
+        FieldType::U64 => check::<u64>(value, endianness),
+        FieldType::U128 => check::<u128>(value, endianness),
+        FieldType::I8 => check::<i8>(value, endianness),
+        FieldType::I16 => check::<i16>(value, endianness),
+        FieldType::I32 => check::<i32>(value, endianness),
+        FieldType::I64 => check::<i64>(value, endianness),
+        FieldType::I128 => check::<i128>(value, endianness),
+        FieldType::F32 => check::<f32>(value, endianness),
+        FieldType::F64 => check::<f64>(value, endianness),
+        FieldType::Char => check::<char>(value, endianness),
+        FieldType::Str => check::<&str>(value, endianness),
+        FieldType::Frame => frame.parse_child(value).map(drop),
+    }
+}
+
+fn check<'a, T: FromField<'a>>(value: &'a [u8], endianness: Endianness) -> Result<()> {
+    T::from_field_with_endianness(value, endianness).map(drop)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FrameBuilder, FrameBuilderLike, ParseOptions};
+
+    #[test]
+    fn valid_frames_pass() {
+        let mut schema = Schema::new();
+        schema
+            .required(1, FieldType::U16)
+            .optional(2, FieldType::Frame)
+            .optional(3, FieldType::Char);
+
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u8(1, 1);
+            bld.add_frame(2).add_u8(1, 1);
+        }
+        assert_eq!(Ok(()), schema.validate(&FrameParser::new(&data).unwrap()));
+    }
+
+    #[test]
+    fn every_problem_is_reported() {
+        let mut schema = Schema::new();
+        schema
+            .required(1, FieldType::U8)
+            .optional(2, FieldType::Frame)
+            .required(3, FieldType::Data)
+            .optional(1, FieldType::U16);
+
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_u32(1, 1);
+            bld.add_u8(1, 2);
+            bld.add_str(2, "not a frame");
+        }
+        assert_eq!(
+            Err(vec![
+                SchemaError {
+                    tag: 1,
+                    error: Error::DuplicateTag(1)
+                },
+                SchemaError {
+                    tag: 1,
+                    error: Error::IncompatibleFieldLength(4)
+                },
+                SchemaError {
+                    tag: 2,
+                    error: Error::InvalidFrameFormat(b'n' as u32)
+                },
+                SchemaError {
+                    tag: 3,
+                    error: Error::MissingField(3)
+                },
+            ]),
+            schema.validate(&FrameParser::new(&data).unwrap())
+        );
+    }
+
+    #[test]
+    fn child_frames_are_limited_by_max_depth() {
+        let mut schema = Schema::new();
+        schema.required(1, FieldType::Frame);
+
+        let mut data = Vec::new();
+        FrameBuilder::new(&mut data).add_frame(1);
+        let options = ParseOptions {
+            max_depth: 0,
+            ..ParseOptions::default()
+        };
+        let frame = FrameParser::new_with_options(&data, options).unwrap();
+        assert_eq!(
+            Err(vec![SchemaError {
+                tag: 1,
+                error: Error::MaxDepthExceeded(1)
+            }]),
+            schema.validate(&frame)
+        );
+    }
+}
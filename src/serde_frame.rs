@@ -0,0 +1,238 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{FrameBuilder, FrameBuilderLike, FrameFormat, FrameParser};
+
+/// SerdeFrame is a tree of fields that can be used with serde.
+///
+/// Field-values are untyped bytes, so they are represented as lowercase hex
+/// strings.  A field-value that can be parsed as a frame with frame-format `0x01`
+/// is represented as a child frame instead; either way, building the frame again
+/// gives back the original bytes.  Child frames with other frame-formats are kept
+/// as data, as they would not be built again with the same encoding.
+///
+/// With `serde_json`, a frame is an array of fields, each with a `tag` and
+/// either a `data` or a `frame` value:
+///
+/// ```
+/// # use yatlv::{FrameBuilder, FrameBuilderLike, FrameParser, Result, SerdeFrame};
+/// # fn main() -> Result<()> {
+/// let mut frame_data = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new(&mut frame_data);
+///     bld.add_u16(12, 1024);
+///     bld.add_frame(13).add_u8(14, 255);
+/// }
+///
+/// let parser = FrameParser::new(&frame_data)?;
+/// let json = serde_json::to_string(&parser).unwrap();
+/// assert_eq!(
+///     r#"[{"tag":12,"data":"0400"},{"tag":13,"frame":[{"tag":14,"data":"ff"}]}]"#,
+///     json
+/// );
+///
+/// let frame: SerdeFrame = serde_json::from_str(&json).unwrap();
+/// assert_eq!(frame_data, frame.to_bytes());
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SerdeFrame {
+    /// The fields in the frame, in order.
+    pub fields: Vec<SerdeField>,
+}
+
+/// A single field in a [SerdeFrame].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SerdeField {
+    /// The field-tag.
+    pub tag: u16,
+
+    /// The field-value.
+    #[serde(flatten)]
+    pub value: SerdeValue,
+}
+
+/// The value of a [SerdeField].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SerdeValue {
+    /// Raw field-value bytes, represented as a hex string.
+    Data(#[serde(with = "hex")] Vec<u8>),
+
+    /// A child frame.
+    Frame(SerdeFrame),
+}
+
+impl SerdeFrame {
+    /// Add the fields of this frame to a builder.
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, SerdeField, SerdeFrame, SerdeValue};
+    /// let frame = SerdeFrame {
+    ///     fields: vec![SerdeField {
+    ///         tag: 45,
+    ///         value: SerdeValue::Data(vec![90, 9]),
+    ///     }],
+    /// };
+    /// let mut data = Vec::new();
+    /// frame.build(&mut FrameBuilder::new(&mut data));
+    /// assert_eq!(&[
+    ///     1,          // frame-format
+    ///     0, 0, 0, 1, // field count
+    ///     0, 45,      // field-tag
+    ///     0, 0, 0, 2, // field-length
+    ///     90, 9       // field-value
+    /// ], &data[..]);
+    /// ```
+    pub fn build<B: FrameBuilderLike>(&self, bld: &mut B) {
+        for field in &self.fields {
+            match &field.value {
+                SerdeValue::Data(value) => {
+                    bld.add_data(field.tag, value);
+                }
+                SerdeValue::Frame(frame) => frame.build(&mut bld.add_frame(field.tag)),
+            }
+        }
+    }
+
+    /// Encode this frame.
+    ///
+    /// ```
+    /// use yatlv::SerdeFrame;
+    /// assert_eq!(vec![1, 0, 0, 0, 0], SerdeFrame::default().to_bytes());
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        self.build(&mut FrameBuilder::new(&mut data));
+        data
+    }
+}
+
+impl<'a> From<&FrameParser<'a>> for SerdeFrame {
+    fn from(parser: &FrameParser<'a>) -> Self {
+        let fields = parser
+            .fields()
+            .map(|(tag, value)| {
+                let value = match parser.parse_child(value) {
+                    Ok(child) if child.format() == FrameFormat::Format1 => {
+                        SerdeValue::Frame(SerdeFrame::from(&child))
+                    }
+                    _ => SerdeValue::Data(value.to_vec()),
+                };
+                SerdeField { tag, value }
+            })
+            .collect();
+        SerdeFrame { fields }
+    }
+}
+
+impl<'a> Serialize for FrameParser<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        SerdeFrame::from(self).serialize(serializer)
+    }
+}
+
+mod hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&crate::encode_hex(value))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        deserializer.deserialize_str(HexVisitor)
+    }
+
+    struct HexVisitor;
+
+    impl<'de> Visitor<'de> for HexVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a hex string")
+        }
+
+        fn visit_str<E: de::Error>(self, hex: &str) -> Result<Vec<u8>, E> {
+            fn digit(c: u8) -> Option<u8> {
+                (c as char).to_digit(16).map(|d| d as u8)
+            }
+            let invalid = || E::invalid_value(de::Unexpected::Str(hex), &self);
+            let pairs = hex.as_bytes().chunks_exact(2);
+            if !pairs.remainder().is_empty() {
+                return Err(invalid());
+            }
+            pairs
+                .map(|pair| match (digit(pair[0]), digit(pair[1])) {
+                    (Some(high), Some(low)) => Ok(high << 4 | low),
+                    _ => Err(invalid()),
+                })
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_round_trip_a_frame_through_json() {
+        let mut data = Vec::new();
+        {
+            let mut bld = FrameBuilder::new(&mut data);
+            bld.add_str(1, "hello");
+            bld.add_data(2, &[]);
+            let mut bld2 = bld.add_frame(3);
+            bld2.add_u32(4, 4);
+            bld2.add_frame(5);
+        }
+
+        let parser = FrameParser::new(&data).unwrap();
+        let json = serde_json::to_value(&parser).unwrap();
+        assert_eq!(
+            serde_json::json!([
+                {"tag": 1, "data": "68656c6c6f"},
+                {"tag": 2, "data": ""},
+                {"tag": 3, "frame": [
+                    {"tag": 4, "data": "00000004"},
+                    {"tag": 5, "frame": []},
+                ]},
+            ]),
+            json
+        );
+
+        let frame: SerdeFrame = serde_json::from_value(json).unwrap();
+        assert_eq!(SerdeFrame::from(&parser), frame);
+        assert_eq!(data, frame.to_bytes());
+    }
+
+    #[test]
+    fn child_frames_with_other_formats_are_kept_as_data() {
+        let builders: [fn(&mut Vec<u8>) -> FrameBuilder; 2] =
+            [FrameBuilder::new_checked, FrameBuilder::new_large];
+        for new_builder in &builders {
+            let mut child = Vec::new();
+            new_builder(&mut child).add_u8(2, 2);
+            let mut data = Vec::new();
+            FrameBuilder::new(&mut data).add_data(1, &child);
+
+            let parser = FrameParser::new(&data).unwrap();
+            let json = serde_json::to_value(&parser).unwrap();
+            assert!(json[0]["data"].is_string());
+            let frame: SerdeFrame = serde_json::from_value(json).unwrap();
+            assert_eq!(data, frame.to_bytes());
+        }
+    }
+
+    #[test]
+    fn can_not_deserialize_invalid_hex() {
+        for hex in &["abc", "zz", "+1"] {
+            let json = serde_json::json!([{"tag": 1, "data": hex}]);
+            assert!(serde_json::from_value::<SerdeFrame>(json).is_err());
+        }
+    }
+}
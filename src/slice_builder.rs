@@ -0,0 +1,358 @@
+use crate::{
+    check_field_length, Endianness, Error, Result, ToField, FIELD_HEADER_BYTES, FRAME_HEADER_BYTES,
+    SIZE_BYTES,
+};
+
+/// SliceFrameBuilder writes a frame into a fixed `&mut [u8]`, without allocating.
+///
+/// The `add_*` methods mirror those of [crate::FrameBuilderLike], but return
+/// [Error::BufferFull] when the field does not fit in the rest of the buffer.
+/// A field that does not fit is not written, so the builder can still be finished.
+///
+/// ```
+/// use yatlv::{Error, FrameParser, SliceFrameBuilder};
+/// # fn main() -> yatlv::Result<()> {
+/// let mut buf = [0; 16];
+/// let mut bld = SliceFrameBuilder::new(&mut buf)?;
+/// bld.add_u16(45, 7)?;
+/// assert_eq!(Err(Error::BufferFull), bld.add_u16(46, 8));
+/// let len = bld.finish();
+/// assert_eq!(&[
+///     1,          // frame-format
+///     0, 0, 0, 1, // field count
+///     0, 45,      // field-tag
+///     0, 0, 0, 2, // field-length
+///     0, 7        // field-value
+/// ], &buf[..len]);
+///
+/// let parser = FrameParser::new(&buf[..len])?;
+/// assert_eq!(Some(7), parser.get_u16(45)?);
+/// # Ok(()) }
+/// ```
+pub struct SliceFrameBuilder<'a> {
+    field_count: u32,
+    pos: usize,
+    finished: bool,
+    endianness: Endianness,
+    data: &'a mut [u8],
+}
+
+impl<'a> Drop for SliceFrameBuilder<'a> {
+    fn drop(&mut self) {
+        self.complete();
+    }
+}
+
+impl<'a> SliceFrameBuilder<'a> {
+    /// Start a frame at the beginning of `data`.
+    ///
+    /// Fails with [Error::BufferFull] if `data` can not hold an empty frame.
+    pub fn new(data: &mut [u8]) -> Result<SliceFrameBuilder<'_>> {
+        SliceFrameBuilder::new_with_endianness(data, Endianness::Big)
+    }
+
+    /// Start a frame that encodes numbers using the given byte order.
+    ///
+    /// ```
+    /// use yatlv::{Endianness, SliceFrameBuilder};
+    /// # fn main() -> yatlv::Result<()> {
+    /// let mut buf = [0; 32];
+    /// let mut bld = SliceFrameBuilder::new_with_endianness(&mut buf, Endianness::Little)?;
+    /// bld.add_u32(45, 7)?;
+    /// let len = bld.finish();
+    /// assert_eq!(&[7, 0, 0, 0], &buf[len - 4..len]);
+    /// # Ok(()) }
+    /// ```
+    pub fn new_with_endianness(
+        data: &mut [u8],
+        endianness: Endianness,
+    ) -> Result<SliceFrameBuilder<'_>> {
+        if data.len() < FRAME_HEADER_BYTES {
+            return Err(Error::BufferFull);
+        }
+        data[..FRAME_HEADER_BYTES].copy_from_slice(&[1, 0, 0, 0, 0]);
+        Ok(SliceFrameBuilder {
+            field_count: 0,
+            pos: FRAME_HEADER_BYTES,
+            finished: false,
+            endianness,
+            data,
+        })
+    }
+
+    /// The byte order used by the `add_*` methods for numbers.
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// The number of unused bytes left in the buffer.
+    ///
+    /// ```
+    /// use yatlv::SliceFrameBuilder;
+    /// # fn main() -> yatlv::Result<()> {
+    /// let mut buf = [0; 16];
+    /// let mut bld = SliceFrameBuilder::new(&mut buf)?;
+    /// assert_eq!(11, bld.remaining());
+    /// bld.add_u8(45, 7)?;
+    /// assert_eq!(4, bld.remaining());
+    /// # Ok(()) }
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    /// Complete the frame and return the number of bytes it occupies in the buffer.
+    ///
+    /// As with [crate::FrameBuilder::finish], the field-count is written when the
+    /// builder is finished or dropped.
+    pub fn finish(mut self) -> usize {
+        self.complete()
+    }
+
+    fn complete(&mut self) -> usize {
+        if !self.finished {
+            self.data[1..1 + SIZE_BYTES].copy_from_slice(&self.field_count.to_be_bytes());
+            self.finished = true;
+        }
+        self.pos
+    }
+
+    /// Write a field-tag and field-length, checking there is room for the field-value.
+    fn add_field_header(&mut self, tag: u16, len: usize) -> Result<usize> {
+        let encoded_len = check_field_length(len)?;
+        if self.remaining() < FIELD_HEADER_BYTES + len {
+            return Err(Error::BufferFull);
+        }
+        self.data[self.pos..self.pos + 2].copy_from_slice(&tag.to_be_bytes());
+        self.data[self.pos + 2..self.pos + FIELD_HEADER_BYTES]
+            .copy_from_slice(&encoded_len.to_be_bytes());
+        self.field_count += 1;
+        self.pos += FIELD_HEADER_BYTES;
+        Ok(self.pos)
+    }
+
+    /// Add a slice of data as a field to the frame.
+    ///
+    /// Returns the position of the field-value as `(start, len)`, in the same way as
+    /// [crate::FrameBuilderLike::add_data].
+    ///
+    /// ```
+    /// use yatlv::{Error, SliceFrameBuilder};
+    /// # fn main() -> yatlv::Result<()> {
+    /// let mut buf = [0; 16];
+    /// let mut bld = SliceFrameBuilder::new(&mut buf)?;
+    /// assert_eq!((11, 2), bld.add_data(45, &[90, 9])?);
+    /// assert_eq!(Err(Error::BufferFull), bld.add_data(46, &[]));
+    /// # Ok(()) }
+    /// ```
+    pub fn add_data(&mut self, tag: u16, value: &[u8]) -> Result<(usize, usize)> {
+        let start = self.add_field_header(tag, value.len())?;
+        self.pos += value.len();
+        self.data[start..self.pos].copy_from_slice(value);
+        Ok((start, value.len()))
+    }
+
+    /// Add any byte container (such as a `Vec<u8>` or `[u8; N]`) as a field to the frame.
+    pub fn add_bytes<B>(&mut self, tag: u16, value: B) -> Result<(usize, usize)>
+    where
+        B: AsRef<[u8]>,
+    {
+        self.add_data(tag, value.as_ref())
+    }
+
+    /// Add a child frame, built by `build`, as a field to the frame.
+    ///
+    /// If `build` fails, the child frame is not added and its error is returned.
+    ///
+    /// ```
+    /// use yatlv::{FrameParser, SliceFrameBuilder};
+    /// # fn main() -> yatlv::Result<()> {
+    /// let mut buf = [0; 32];
+    /// let mut bld = SliceFrameBuilder::new(&mut buf)?;
+    /// bld.add_frame(45, |child| child.add_u8(60, 7).map(|_| ()))?;
+    /// let len = bld.finish();
+    ///
+    /// let parser = FrameParser::new(&buf[..len])?;
+    /// assert_eq!(Some(7), parser.get_frame_required(45)?.get_u8(60)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn add_frame<F>(&mut self, tag: u16, build: F) -> Result<(usize, usize)>
+    where
+        F: FnOnce(&mut SliceFrameBuilder<'_>) -> Result<()>,
+    {
+        if self.remaining() < FIELD_HEADER_BYTES {
+            return Err(Error::BufferFull);
+        }
+        let value_start = self.pos + FIELD_HEADER_BYTES;
+        let mut child =
+            SliceFrameBuilder::new_with_endianness(&mut self.data[value_start..], self.endianness)?;
+        build(&mut child)?;
+        let len = child.finish();
+        self.add_field_header(tag, len)?;
+        self.pos += len;
+        Ok((value_start, len))
+    }
+
+    /// Add a field to the frame using any type that implements [ToField].
+    ///
+    /// ```
+    /// use yatlv::{FrameParser, SliceFrameBuilder};
+    /// # fn main() -> yatlv::Result<()> {
+    /// let mut buf = [0; 32];
+    /// let mut bld = SliceFrameBuilder::new(&mut buf)?;
+    /// bld.add(45, 7u32)?;
+    /// bld.add(46, "hi")?;
+    /// let len = bld.finish();
+    ///
+    /// let parser = FrameParser::new(&buf[..len])?;
+    /// assert_eq!(Some(7), parser.get_u32(45)?);
+    /// assert_eq!(Some("hi"), parser.get_str(46)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn add<T: ToField>(&mut self, tag: u16, value: T) -> Result<(usize, usize)> {
+        value.encode_field(self.endianness, |value| self.add_data(tag, value))
+    }
+
+    /// Add a bool field to the frame.
+    pub fn add_bool(&mut self, tag: u16, value: bool) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add a u8 field to the frame.
+    pub fn add_u8(&mut self, tag: u16, value: u8) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add a u16 field to the frame.
+    pub fn add_u16(&mut self, tag: u16, value: u16) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add a u32 field to the frame.
+    pub fn add_u32(&mut self, tag: u16, value: u32) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add a u64 field to the frame.
+    pub fn add_u64(&mut self, tag: u16, value: u64) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add a u128 field to the frame.
+    pub fn add_u128(&mut self, tag: u16, value: u128) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add an i8 field to the frame.
+    pub fn add_i8(&mut self, tag: u16, value: i8) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add an i16 field to the frame.
+    pub fn add_i16(&mut self, tag: u16, value: i16) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add an i32 field to the frame.
+    pub fn add_i32(&mut self, tag: u16, value: i32) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add an i64 field to the frame.
+    pub fn add_i64(&mut self, tag: u16, value: i64) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add an i128 field to the frame.
+    pub fn add_i128(&mut self, tag: u16, value: i128) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add an f32 field to the frame.
+    pub fn add_f32(&mut self, tag: u16, value: f32) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add an f64 field to the frame.
+    pub fn add_f64(&mut self, tag: u16, value: f64) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+
+    /// Add a str field to the frame.
+    pub fn add_str<S>(&mut self, tag: u16, value: S) -> Result<(usize, usize)>
+    where
+        S: AsRef<str>,
+    {
+        self.add(tag, value.as_ref())
+    }
+
+    /// Add a char field to the frame.
+    pub fn add_char(&mut self, tag: u16, value: char) -> Result<(usize, usize)> {
+        self.add(tag, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FrameBuilder, FrameBuilderLike};
+
+    #[test]
+    fn writes_the_same_bytes_as_frame_builder() {
+        let mut expected = Vec::new();
+        {
+            let mut bld = FrameBuilder::new_with_endianness(&mut expected, Endianness::Little);
+            bld.add_bool(1, true);
+            bld.add_i16(2, -2);
+            bld.add_u64(3, 3);
+            bld.add_f64(4, 4.5);
+            bld.add_str(5, "five");
+            bld.add_char(6, '€');
+            let mut child = bld.add_frame(7);
+            child.add_u32(8, 8);
+            child.add_frame(9);
+        }
+
+        let mut buf = [0xAA; 128];
+        let mut bld = SliceFrameBuilder::new_with_endianness(&mut buf, Endianness::Little).unwrap();
+        bld.add_bool(1, true).unwrap();
+        bld.add_i16(2, -2).unwrap();
+        bld.add_u64(3, 3).unwrap();
+        bld.add_f64(4, 4.5).unwrap();
+        bld.add_str(5, "five").unwrap();
+        bld.add_char(6, '€').unwrap();
+        bld.add_frame(7, |child| {
+            child.add_u32(8, 8)?;
+            child.add_frame(9, |_| Ok(()))?;
+            Ok(())
+        })
+        .unwrap();
+        let len = bld.finish();
+
+        assert_eq!(&expected[..], &buf[..len]);
+    }
+
+    #[test]
+    fn fields_that_do_not_fit_are_not_written() {
+        let mut buf = [0; 30];
+        assert_eq!(
+            Some(Error::BufferFull),
+            SliceFrameBuilder::new(&mut buf[..4]).err()
+        );
+
+        let mut bld = SliceFrameBuilder::new(&mut buf).unwrap();
+        bld.add_u32(1, 1).unwrap();
+        assert_eq!(15, bld.remaining());
+        assert_eq!(Err(Error::BufferFull), bld.add_u128(2, 2));
+        assert_eq!(
+            Err(Error::BufferFull),
+            bld.add_frame(3, |child| child.add_u8(4, 4).map(|_| ()))
+        );
+        assert_eq!(15, bld.remaining());
+        let len = bld.finish();
+
+        let mut expected = Vec::new();
+        FrameBuilder::new(&mut expected).add_u32(1, 1);
+        assert_eq!(&expected[..], &buf[..len]);
+    }
+}
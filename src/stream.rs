@@ -0,0 +1,330 @@
+use core::convert::TryFrom;
+use std::io::{Error, ErrorKind, Result, Seek, SeekFrom, Write};
+
+use crate::{
+    write_value_in_place, Endianness, FrameBuilderLike, PacketFrameBuilder, FIELD_HEADER_BYTES,
+    FRAME_HEADER_BYTES, SIZE_BYTES,
+};
+
+/// Number of bytes taken by a frame-size, frame-format and field-count.
+const PACKET_HEADER_BYTES: usize = SIZE_BYTES + FRAME_HEADER_BYTES;
+
+/// Buffered fields are passed to a seekable writer once they take this many bytes.
+const FLUSH_BYTES: usize = 8 * 1024;
+
+/// StreamingPacketWriter writes a packet-frame to a writer as its fields are added,
+/// so that a large packet-frame does not have to be held in memory.
+///
+/// With a seekable writer (created by [StreamingPacketWriter::new]) a placeholder
+/// frame-size and field-count are written first, fields are passed to the writer in
+/// chunks of a few KiB (larger field-values are written directly), and the header
+/// is filled in by seeking back when the packet-frame is finished.  A writer that can
+/// not seek (created by [StreamingPacketWriter::new_buffered]) gets the whole
+/// packet-frame in one write when it is finished, as with
+/// [PacketFrameBuilder::write_to].  Either way the bytes written are the same as
+/// those of a [PacketFrameBuilder] with the same fields.
+///
+/// Child frames added with [FrameBuilderLike::add_frame] are buffered until they are
+/// complete.
+///
+/// The `add_*` methods can not fail, so the first error from the writer is kept and
+/// returned by [StreamingPacketWriter::finish]; nothing more is written after an
+/// error.  If the writer is dropped without being finished the packet-frame is still
+/// completed, but any error is lost.
+///
+/// ```
+/// use std::io::Cursor;
+/// use yatlv::{FrameBuilderLike, PacketFrameParser, StreamingPacketWriter};
+/// # fn main() -> std::io::Result<()> {
+/// let mut file = Cursor::new(Vec::new());
+/// let mut bld = StreamingPacketWriter::new(&mut file)?;
+/// bld.add_data(1, &[0xAB; 100_000]);
+/// bld.add_str(2, "done");
+/// assert_eq!(100_025, bld.finish()?);
+///
+/// let data = file.into_inner();
+/// let parser = PacketFrameParser::new(&data).unwrap();
+/// assert_eq!(Some("done"), parser.get_str(2).unwrap());
+/// # Ok(()) }
+/// ```
+pub struct StreamingPacketWriter<W: Write> {
+    writer: W,
+    /// The start of the packet-frame, for a seekable writer.
+    header: Option<HeaderPatch<W>>,
+    /// Bytes not yet passed to the writer, starting with the header until the first
+    /// flush.
+    buffer: Vec<u8>,
+    /// Number of bytes of the packet-frame passed to the writer.
+    written: usize,
+    field_count: u32,
+    endianness: Endianness,
+    error: Option<Error>,
+    finished: bool,
+}
+
+/// Where to write the header of a packet-frame that has already been flushed.
+struct HeaderPatch<W> {
+    start: u64,
+    write_at: fn(&mut W, u64, &[u8]) -> Result<()>,
+}
+
+/// Write `bytes` at `pos`, leaving the writer positioned where it was.
+fn write_at<W: Write + Seek>(writer: &mut W, pos: u64, bytes: &[u8]) -> Result<()> {
+    let end = writer.stream_position()?;
+    writer.seek(SeekFrom::Start(pos))?;
+    writer.write_all(bytes)?;
+    writer.seek(SeekFrom::Start(end))?;
+    Ok(())
+}
+
+impl<W: Write + Seek> StreamingPacketWriter<W> {
+    /// Start a packet-frame at the current position of `writer`.
+    pub fn new(writer: W) -> Result<StreamingPacketWriter<W>> {
+        StreamingPacketWriter::new_with_endianness(writer, Endianness::Big)
+    }
+
+    /// Start a packet-frame at the current position of `writer`, encoding numbers
+    /// using the given byte order.
+    pub fn new_with_endianness(
+        mut writer: W,
+        endianness: Endianness,
+    ) -> Result<StreamingPacketWriter<W>> {
+        let start = writer.stream_position()?;
+        let mut bld = StreamingPacketWriter::new_buffered_with_endianness(writer, endianness);
+        bld.header = Some(HeaderPatch {
+            start,
+            write_at: write_at::<W>,
+        });
+        Ok(bld)
+    }
+}
+
+impl<W: Write> StreamingPacketWriter<W> {
+    /// Start a packet-frame that is held in memory until it is finished, for a
+    /// writer that can not seek.
+    pub fn new_buffered(writer: W) -> StreamingPacketWriter<W> {
+        StreamingPacketWriter::new_buffered_with_endianness(writer, Endianness::Big)
+    }
+
+    /// Start a packet-frame that is held in memory until it is finished, encoding
+    /// numbers using the given byte order.
+    pub fn new_buffered_with_endianness(
+        writer: W,
+        endianness: Endianness,
+    ) -> StreamingPacketWriter<W> {
+        let mut buffer = Vec::with_capacity(PACKET_HEADER_BYTES);
+        buffer.extend_from_slice(&[0, 0, 0, 0, 1, 0, 0, 0, 0]);
+        StreamingPacketWriter {
+            writer,
+            header: None,
+            buffer,
+            written: 0,
+            field_count: 0,
+            endianness,
+            error: None,
+            finished: false,
+        }
+    }
+
+    /// Complete the packet-frame, returning the number of bytes it occupies
+    /// (including the frame-size) or the first error from the writer.
+    ///
+    /// A packet-frame whose frame-size does not fit in a u32 fails with an error of
+    /// kind [ErrorKind::InvalidInput], after its fields have been written.
+    pub fn finish(mut self) -> Result<usize> {
+        self.complete()
+    }
+
+    fn complete(&mut self) -> Result<usize> {
+        self.finished = true;
+        let len = self.written + self.buffer.len();
+        let frame_size = u32::try_from(len - SIZE_BYTES);
+
+        let mut header = [0; PACKET_HEADER_BYTES];
+        header[..SIZE_BYTES].copy_from_slice(&frame_size.unwrap_or(0).to_be_bytes());
+        header[SIZE_BYTES] = 1;
+        header[SIZE_BYTES + 1..].copy_from_slice(&self.field_count.to_be_bytes());
+
+        if self.written == 0 {
+            self.buffer[..PACKET_HEADER_BYTES].copy_from_slice(&header);
+            self.flush_buffer();
+        } else {
+            self.flush_buffer();
+            if let (None, Some(patch)) = (&self.error, &self.header) {
+                if let Err(e) = (patch.write_at)(&mut self.writer, patch.start, &header) {
+                    self.error = Some(e);
+                }
+            }
+        }
+
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        frame_size.map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "packet-frame is too large for its frame-size",
+            )
+        })?;
+        Ok(len)
+    }
+
+    /// Pass buffered bytes to a seekable writer once there are enough of them.
+    fn maybe_flush(&mut self) {
+        if self.header.is_some() && self.buffer.len() >= FLUSH_BYTES {
+            self.flush_buffer();
+        }
+    }
+
+    fn flush_buffer(&mut self) {
+        let buffer = core::mem::take(&mut self.buffer);
+        self.write_through(&buffer);
+        self.buffer = buffer;
+        self.buffer.clear();
+    }
+
+    /// Pass `bytes` to the writer, unless it has already failed.
+    fn write_through(&mut self, bytes: &[u8]) {
+        if self.error.is_none() {
+            if let Err(e) = self.writer.write_all(bytes) {
+                self.error = Some(e);
+            }
+        }
+        self.written += bytes.len();
+    }
+
+    /// Write the field-tag and field-length of a field, returning the position of
+    /// its field-value.
+    fn push_field_header(&mut self, tag: u16, len: usize) -> usize {
+        self.field_count += 1;
+        self.maybe_flush();
+        self.buffer.extend_from_slice(&tag.to_be_bytes());
+        self.buffer.extend_from_slice(&(len as u32).to_be_bytes());
+        self.written + self.buffer.len()
+    }
+}
+
+impl<W: Write> Drop for StreamingPacketWriter<W> {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = self.complete();
+        }
+    }
+}
+
+impl<W: Write> FrameBuilderLike for StreamingPacketWriter<W> {
+    fn add_data(&mut self, tag: u16, value: &[u8]) -> (usize, usize) {
+        let value_start = self.push_field_header(tag, value.len());
+        if self.header.is_some() && value.len() >= FLUSH_BYTES {
+            self.flush_buffer();
+            self.write_through(value);
+        } else {
+            self.buffer.extend_from_slice(value);
+        }
+        (value_start, value.len())
+    }
+
+    fn add_with<F>(&mut self, tag: u16, write: F) -> (usize, usize)
+    where
+        F: FnOnce(&mut Vec<u8>),
+    {
+        let value_start = self.push_field_header(tag, 0);
+        let value_pos = self.buffer.len();
+        let length = write_value_in_place(
+            &mut self.buffer,
+            &mut self.finished,
+            value_pos,
+            FIELD_HEADER_BYTES,
+            write,
+        );
+        self.buffer[value_pos - SIZE_BYTES..value_pos]
+            .copy_from_slice(&(length as u32).to_be_bytes());
+        (value_start, length)
+    }
+
+    fn add_frame(&mut self, tag: u16) -> PacketFrameBuilder<'_> {
+        self.field_count += 1;
+        self.maybe_flush();
+        self.buffer.reserve(FIELD_HEADER_BYTES);
+        self.buffer.extend_from_slice(&tag.to_be_bytes());
+        PacketFrameBuilder::new_with_endianness(&mut self.buffer, self.endianness)
+    }
+
+    fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn add_fields<B: FrameBuilderLike>(bld: &mut B) -> Vec<(usize, usize)> {
+        let mut positions = Vec::new();
+        for i in 0..1000u32 {
+            positions.push(bld.add_u32(1, i));
+        }
+        positions.push(bld.add_data(2, &[7; 3 * FLUSH_BYTES]));
+        bld.add_frame(3).add_str(4, "child");
+        positions.push(bld.add_with(5, |value| value.extend_from_slice(b"with")));
+        positions.push(bld.add_u16(6, 0x102));
+        positions
+    }
+
+    fn expected(endianness: Endianness) -> (Vec<u8>, Vec<(usize, usize)>) {
+        let mut data = Vec::new();
+        let positions = add_fields(&mut PacketFrameBuilder::new_with_endianness(
+            &mut data, endianness,
+        ));
+        (data, positions)
+    }
+
+    #[test]
+    fn seekable_writers_get_the_same_packet_frame() {
+        let (expected, expected_positions) = expected(Endianness::Little);
+        let mut file = Cursor::new(vec![9, 9]);
+        file.set_position(2);
+        let mut bld =
+            StreamingPacketWriter::new_with_endianness(&mut file, Endianness::Little).unwrap();
+        let positions = add_fields(&mut bld);
+        assert!(bld.written > 0);
+        assert!(bld.buffer.len() < FLUSH_BYTES);
+        assert_eq!(expected.len(), bld.finish().unwrap());
+
+        assert_eq!(expected_positions, positions);
+        assert_eq!(expected.len() + 2, file.position() as usize);
+        assert_eq!(&[9, 9], &file.get_ref()[..2]);
+        assert_eq!(&expected, &file.get_ref()[2..]);
+    }
+
+    #[test]
+    fn other_writers_get_the_same_packet_frame() {
+        let (expected, expected_positions) = expected(Endianness::Big);
+        let mut out = Vec::new();
+        let mut bld = StreamingPacketWriter::new_buffered(&mut out);
+        let positions = add_fields(&mut bld);
+        assert_eq!(0, bld.written);
+        drop(bld);
+
+        assert_eq!(expected_positions, positions);
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    #[should_panic(expected = "add_with must only append to the Vec it is given")]
+    fn add_with_must_only_append() {
+        let mut bld = StreamingPacketWriter::new(Cursor::new(Vec::new())).unwrap();
+        bld.add_u8(1, 1);
+        bld.add_with(2, |value| value.clear());
+    }
+
+    #[test]
+    fn the_first_write_error_is_returned_by_finish() {
+        let mut space = [0; 2 * FLUSH_BYTES];
+        let mut bld = StreamingPacketWriter::new(Cursor::new(&mut space[..])).unwrap();
+        bld.add_data(1, &[1; 2 * FLUSH_BYTES]);
+        bld.add_u8(2, 2);
+        assert_eq!(ErrorKind::WriteZero, bld.finish().unwrap_err().kind());
+    }
+}
@@ -0,0 +1,88 @@
+use core::convert::TryFrom;
+
+use crate::{Error, Result, SCHEMA_ID_TAG};
+
+/// Tag is a field-tag that has been checked not to be one of the tags reserved by
+/// this crate's conventions (currently only [SCHEMA_ID_TAG]).
+///
+/// The builder and parser methods take field-tags as `u16`, so a `Tag` is converted
+/// with [Tag::get] or `into()`.  Defining a protocol's tags as `Tag` constants moves
+/// the check to where the tags are defined, so a reserved tag can not be used for
+/// data by mistake.
+///
+/// ```
+/// use yatlv::{Error, FrameBuilder, FrameBuilderLike, FrameParser, Tag};
+/// const NAME: Tag = Tag::new_const(1);
+///
+/// let mut data = Vec::new();
+/// FrameBuilder::new_with_schema(&mut data, 3).add_str(NAME.get(), "name");
+/// let parser = FrameParser::new(&data).unwrap();
+/// assert_eq!(Some("name"), parser.get_str(NAME.into()).unwrap());
+///
+/// assert_eq!(Ok(NAME), Tag::new(1));
+/// assert_eq!(Err(Error::ReservedTag(0)), Tag::new(0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tag(u16);
+
+impl Tag {
+    /// Check that `tag` is not reserved, failing with [Error::ReservedTag] if it is.
+    pub const fn new(tag: u16) -> Result<Tag> {
+        if Tag::is_reserved(tag) {
+            Err(Error::ReservedTag(tag))
+        } else {
+            Ok(Tag(tag))
+        }
+    }
+
+    /// Create a Tag for use in a constant, panicking (at compile time for a constant)
+    /// if `tag` is reserved.
+    pub const fn new_const(tag: u16) -> Tag {
+        if Tag::is_reserved(tag) {
+            panic!("field-tag is reserved");
+        }
+        Tag(tag)
+    }
+
+    /// Whether `tag` is reserved by one of this crate's conventions.
+    pub const fn is_reserved(tag: u16) -> bool {
+        tag == SCHEMA_ID_TAG
+    }
+
+    /// The field-tag.
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+}
+
+impl From<Tag> for u16 {
+    fn from(tag: Tag) -> u16 {
+        tag.0
+    }
+}
+
+impl TryFrom<u16> for Tag {
+    type Error = Error;
+
+    fn try_from(tag: u16) -> Result<Tag> {
+        Tag::new(tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_reserved_tags_are_rejected() {
+        assert_eq!(Err(Error::ReservedTag(SCHEMA_ID_TAG)), Tag::try_from(0));
+        assert_eq!(Ok(Tag(1)), Tag::try_from(1));
+        assert_eq!(u16::MAX, u16::from(Tag::new(u16::MAX).unwrap()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn reserved_constant_tags_panic() {
+        Tag::new_const(SCHEMA_ID_TAG);
+    }
+}
@@ -0,0 +1,131 @@
+use alloc::collections::BTreeSet;
+
+use crate::{Endianness, Error, FrameBuilderLike, PacketFrameBuilder, Result, ToField};
+
+/// UniqueFrameBuilder wraps another builder, checking that each tag is only used once.
+///
+/// This is intended to catch mistakes (such as a copy-pasted tag constant) in
+/// protocols where every tag must be unique.  Adding a field with a tag that has
+/// already been used panics; [UniqueFrameBuilder::try_add] returns
+/// [Error::DuplicateTag] instead.  The tracking cost is only paid by frames built
+/// with this wrapper.
+///
+/// Only the fields of the wrapped frame are checked; child frames created by
+/// [FrameBuilderLike::add_frame] are ordinary builders.
+///
+/// ```
+/// # use yatlv::{FrameBuilder, FrameBuilderLike, UniqueFrameBuilder, Error};
+/// let mut data = Vec::new();
+/// let mut bld = UniqueFrameBuilder::new(FrameBuilder::new(&mut data));
+/// bld.add_u8(1, 1);
+/// bld.add_str(2, "two");
+/// assert_eq!(Err(Error::DuplicateTag(1)), bld.try_add(1, 3u8));
+/// assert_eq!(21, bld.into_inner().finish());
+/// ```
+///
+/// ```should_panic
+/// # use yatlv::{FrameBuilder, FrameBuilderLike, UniqueFrameBuilder};
+/// let mut data = Vec::new();
+/// let mut bld = UniqueFrameBuilder::new(FrameBuilder::new(&mut data));
+/// bld.add_u8(1, 1);
+/// bld.add_u8(1, 2); // panics
+/// ```
+pub struct UniqueFrameBuilder<B> {
+    inner: B,
+    tags: BTreeSet<u16>,
+}
+
+impl<B: FrameBuilderLike> UniqueFrameBuilder<B> {
+    /// Wrap `inner`, which should not have had any fields added yet.
+    pub fn new(inner: B) -> UniqueFrameBuilder<B> {
+        UniqueFrameBuilder {
+            inner,
+            tags: BTreeSet::new(),
+        }
+    }
+
+    /// Whether a field with `tag` has already been added.
+    pub fn contains_tag(&self, tag: u16) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    /// Add a field using any type that implements [ToField], failing with
+    /// [Error::DuplicateTag] (and leaving the frame unchanged) if `tag` has
+    /// already been used.
+    pub fn try_add<T: ToField>(&mut self, tag: u16, value: T) -> Result<(usize, usize)> {
+        if self.contains_tag(tag) {
+            return Err(Error::DuplicateTag(tag));
+        }
+        Ok(self.add(tag, value))
+    }
+
+    /// Release the wrapped builder, so that it can be finished.
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    fn use_tag(&mut self, tag: u16) {
+        if !self.tags.insert(tag) {
+            panic!("duplicate field with tag {}", tag);
+        }
+    }
+}
+
+impl<B: FrameBuilderLike> FrameBuilderLike for UniqueFrameBuilder<B> {
+    fn add_data(&mut self, tag: u16, value: &[u8]) -> (usize, usize) {
+        self.use_tag(tag);
+        self.inner.add_data(tag, value)
+    }
+
+    fn add_frame(&mut self, tag: u16) -> PacketFrameBuilder<'_> {
+        self.use_tag(tag);
+        self.inner.add_frame(tag)
+    }
+
+    fn endianness(&self) -> Endianness {
+        self.inner.endianness()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FrameBuilder, FrameParser, ParseOptions};
+
+    #[test]
+    fn frames_built_with_unique_tags_pass_the_duplicate_check() {
+        let mut data = Vec::new();
+        {
+            let mut bld = UniqueFrameBuilder::new(FrameBuilder::new_with_endianness(
+                &mut data,
+                Endianness::Little,
+            ));
+            bld.add_u16(1, 1);
+            let mut child = bld.add_frame(2);
+            child.add_u8(1, 1);
+            child.add_u8(1, 2);
+            drop(child);
+            assert!(bld.contains_tag(2));
+            assert!(!bld.contains_tag(3));
+            assert_eq!(Err(Error::DuplicateTag(2)), bld.try_add(2, true));
+            assert!(bld.try_add(3, true).is_ok());
+        }
+        let options = ParseOptions {
+            endianness: Endianness::Little,
+            reject_duplicate_tags: true,
+            ..ParseOptions::default()
+        };
+        let frame = FrameParser::new_with_options(&data, options).unwrap();
+        assert_eq!(Some(1), frame.get_u16(1).unwrap());
+        assert_eq!(3, frame.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate field with tag 2")]
+    fn adding_a_duplicate_child_frame_panics() {
+        let mut data = Vec::new();
+        let mut bld = UniqueFrameBuilder::new(FrameBuilder::new(&mut data));
+        bld.add_u8(2, 1);
+        bld.add_frame(2);
+    }
+}
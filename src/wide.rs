@@ -0,0 +1,305 @@
+use alloc::vec::Vec;
+
+use crate::{
+    check_field_count, read_field_value, read_frame_field_count, read_frame_format,
+    read_wide_field_tag_and_length, Endianness, Error, FrameFormat, FrameParser, FromField,
+    PacketFrameBuilder, ParseOptions, Result, ToField, SIZE_BYTES, WIDE_FIELD_HEADER_BYTES,
+};
+
+/// WideFrameBuilder pushes a frame with 32 bit field-tags (frame-format `0x03`)
+/// into a mutable `Vec<u8>`.
+///
+/// This is for protocols whose field-tags do not fit in 16 bits, for example
+/// because the tags are partitioned into namespaces.  It is usually created with
+/// [FrameBuilder::new_wide_tags](crate::FrameBuilder::new_wide_tags).
+///
+/// Child frames added with [WideFrameBuilder::add_frame] are ordinary frames with
+/// 16 bit field-tags.
+///
+/// ```
+/// use yatlv::{FrameBuilder, FrameBuilderLike, WideFrameParser};
+/// const NAMESPACE: u32 = 0x0002_0000;
+///
+/// let mut data = Vec::new();
+/// {
+///     let mut bld = FrameBuilder::new_wide_tags(&mut data);
+///     bld.add(NAMESPACE | 1, "name");
+///     bld.add_frame(NAMESPACE | 2).add_u8(1, 9);
+/// }
+///
+/// let parser = WideFrameParser::new(&data).unwrap();
+/// assert_eq!(Some("name"), parser.get(NAMESPACE | 1).unwrap());
+/// let child = parser.get_frame(NAMESPACE | 2).unwrap().unwrap();
+/// assert_eq!(Some(9), child.get_u8(1).unwrap());
+/// ```
+pub struct WideFrameBuilder<'a> {
+    field_count: u32,
+    field_start: usize,
+    finished: bool,
+    endianness: Endianness,
+    data: &'a mut Vec<u8>,
+}
+
+impl<'a> Drop for WideFrameBuilder<'a> {
+    fn drop(&mut self) {
+        self.complete();
+    }
+}
+
+impl<'a> WideFrameBuilder<'a> {
+    /// Create a WideFrameBuilder that encodes numbers as big-endian.
+    pub fn new(data: &mut Vec<u8>) -> WideFrameBuilder<'_> {
+        WideFrameBuilder::new_with_endianness(data, Endianness::Big)
+    }
+
+    /// Create a WideFrameBuilder that encodes numbers using the given byte order.
+    pub fn new_with_endianness(data: &mut Vec<u8>, endianness: Endianness) -> WideFrameBuilder<'_> {
+        let field_start = data.len();
+        data.extend_from_slice(&[3, 0, 0, 0, 0]);
+
+        WideFrameBuilder {
+            field_count: 0,
+            field_start,
+            finished: false,
+            endianness,
+            data,
+        }
+    }
+
+    /// The byte order used to encode numbers.
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Add a field with raw bytes as its field-value.
+    ///
+    /// Returns the offset of the field-value from the start of the frame and its length.
+    pub fn add_data(&mut self, tag: u32, value: &[u8]) -> (usize, usize) {
+        self.field_count += 1;
+        self.data.reserve(WIDE_FIELD_HEADER_BYTES + value.len());
+        self.data.extend_from_slice(&tag.to_be_bytes());
+        self.data
+            .extend_from_slice(&(value.len() as u32).to_be_bytes());
+        let value_start = self.data.len() - self.field_start;
+        self.data.extend_from_slice(value);
+        (value_start, value.len())
+    }
+
+    /// Add a field using any type that implements [ToField].
+    ///
+    /// ```
+    /// use yatlv::{FrameBuilder, WideFrameParser};
+    /// let mut data = Vec::new();
+    /// {
+    ///     let mut bld = FrameBuilder::new_wide_tags(&mut data);
+    ///     bld.add(70_000, 1234u32);
+    ///     bld.add(70_001, true);
+    /// }
+    /// let parser = WideFrameParser::new(&data).unwrap();
+    /// assert_eq!(Some(1234u32), parser.get(70_000).unwrap());
+    /// assert_eq!(Some(true), parser.get(70_001).unwrap());
+    /// ```
+    pub fn add<T: ToField>(&mut self, tag: u32, value: T) -> (usize, usize) {
+        let endianness = self.endianness;
+        value.encode_field(endianness, |v| self.add_data(tag, v))
+    }
+
+    /// Add a child frame, which uses 16 bit field-tags.
+    pub fn add_frame(&mut self, tag: u32) -> PacketFrameBuilder<'_> {
+        self.field_count += 1;
+        self.data.reserve(4);
+        self.data.extend_from_slice(&tag.to_be_bytes());
+        PacketFrameBuilder::new_with_endianness(self.data, self.endianness)
+    }
+
+    /// Complete the frame and return the number of bytes it occupies in the buffer.
+    pub fn finish(mut self) -> usize {
+        self.complete()
+    }
+
+    fn complete(&mut self) -> usize {
+        if !self.finished {
+            let field_count_pos = self.field_start + 1;
+            self.data[field_count_pos..field_count_pos + SIZE_BYTES]
+                .copy_from_slice(&self.field_count.to_be_bytes());
+            self.finished = true;
+        }
+        self.data.len() - self.field_start
+    }
+}
+
+/// WideFrameParser reads a frame with 32 bit field-tags (frame-format `0x03`).
+///
+/// Apart from the width of the field-tags, it reads fields in the same way as
+/// [FrameParser], using [FromField] to decode field-values.  Frames with any other
+/// frame-format are rejected with [Error::InvalidFrameFormat].
+///
+/// ```
+/// use yatlv::{Error, FrameBuilder, FrameBuilderLike, WideFrameParser};
+/// let mut data = Vec::new();
+/// FrameBuilder::new(&mut data).add_u8(1, 1);
+/// assert_eq!(Some(Error::InvalidFrameFormat(1)), WideFrameParser::new(&data).err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct WideFrameParser<'a> {
+    fields: Vec<(u32, &'a [u8])>,
+    endianness: Endianness,
+}
+
+impl<'a> WideFrameParser<'a> {
+    /// Parse a wide frame whose numbers are big-endian.
+    pub fn new(frame_data: &'a [u8]) -> Result<WideFrameParser<'a>> {
+        WideFrameParser::new_with_endianness(frame_data, Endianness::Big)
+    }
+
+    /// Parse a wide frame whose numbers are encoded using the given byte order.
+    pub fn new_with_endianness(
+        frame_data: &'a [u8],
+        endianness: Endianness,
+    ) -> Result<WideFrameParser<'a>> {
+        let (format, body) = read_frame_format(frame_data)?;
+        if format != FrameFormat::Format3 {
+            return Err(Error::InvalidFrameFormat(frame_data[0] as u32));
+        }
+        let (field_count, mut body) = read_frame_field_count(body)?;
+        check_field_count(field_count, body, WIDE_FIELD_HEADER_BYTES)?;
+        let mut fields = Vec::with_capacity(field_count as usize);
+        for _ in 0..field_count {
+            let (tag, length, tail) = read_wide_field_tag_and_length(body)?;
+            let (value, tail) = read_field_value(tail, length)?;
+            fields.push((tag, value));
+            body = tail;
+        }
+        if body.is_empty() {
+            Ok(WideFrameParser { fields, endianness })
+        } else {
+            Err(Error::UnexpectedData)
+        }
+    }
+
+    /// The byte order used to decode numbers.
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// The number of fields in the frame.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Whether the frame has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Iterate over the tags and values of all fields, in the order they were added.
+    pub fn fields<'b>(&'b self) -> impl Iterator<Item = (u32, &'a [u8])> + 'b {
+        self.fields.iter().copied()
+    }
+
+    /// Read the value of the first field with `search_tag`.
+    pub fn get_data(&self, search_tag: u32) -> Option<&'a [u8]> {
+        self.fields
+            .iter()
+            .find(|(tag, _)| *tag == search_tag)
+            .map(|(_, value)| *value)
+    }
+
+    /// Read the values of all fields with `search_tag`.
+    pub fn get_datas<'b>(&'b self, search_tag: u32) -> impl Iterator<Item = &'a [u8]> + 'b {
+        self.fields
+            .iter()
+            .filter(move |(tag, _)| *tag == search_tag)
+            .map(|(_, value)| *value)
+    }
+
+    /// Read a field using any type that implements [FromField].
+    pub fn get<T: FromField<'a>>(&self, search_tag: u32) -> Result<Option<T>> {
+        let endianness = self.endianness;
+        self.get_data(search_tag)
+            .map(|value| T::from_field_with_endianness(value, endianness))
+            .transpose()
+    }
+
+    /// Read a child frame, which uses 16 bit field-tags.
+    pub fn get_frame(&self, search_tag: u32) -> Result<Option<FrameParser<'a>>> {
+        let options = ParseOptions {
+            endianness: self.endianness,
+            ..ParseOptions::default()
+        };
+        self.get_data(search_tag)
+            .map(|value| FrameParser::parse(value, options, 1))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{frame_encoded_len, FrameBuilderLike};
+
+    fn wide_frame(endianness: Endianness) -> Vec<u8> {
+        let mut data = Vec::new();
+        {
+            let mut bld = WideFrameBuilder::new_with_endianness(&mut data, endianness);
+            bld.add(0x1234_5678, 1u16);
+            bld.add_frame(u32::MAX).add_str(3, "child");
+            bld.add(0x1234_5678, 2u16);
+        }
+        data
+    }
+
+    #[test]
+    fn can_round_trip_wide_tags() {
+        for &endianness in &[Endianness::Big, Endianness::Little] {
+            let data = wide_frame(endianness);
+            let parser = WideFrameParser::new_with_endianness(&data, endianness).unwrap();
+            assert_eq!(3, parser.len());
+            assert_eq!(Some(1u16), parser.get(0x1234_5678).unwrap());
+            assert_eq!(2, parser.get_datas(0x1234_5678).count());
+            assert_eq!(None, parser.get_data(0x5678));
+            let child = parser.get_frame(u32::MAX).unwrap().unwrap();
+            assert_eq!(Some("child"), child.get_str(3).unwrap());
+            assert_eq!(endianness, child.endianness());
+        }
+    }
+
+    #[test]
+    fn frame_parser_rejects_wide_frames() {
+        let data = wide_frame(Endianness::Big);
+        assert_eq!(
+            Some(Error::InvalidFrameFormat(3)),
+            FrameParser::new(&data).err()
+        );
+    }
+
+    #[test]
+    fn can_find_the_encoded_len_of_a_wide_frame() {
+        let mut data = wide_frame(Endianness::Big);
+        let len = data.len();
+        data.extend_from_slice(&[9, 9]);
+        assert_eq!(Ok(len), frame_encoded_len(&data));
+        assert_eq!(
+            Err(Error::IncompleteFieldValue(2, 1)),
+            frame_encoded_len(&data[..len - 1])
+        );
+        assert_eq!(
+            Some(Error::UnexpectedData),
+            WideFrameParser::new(&data).err()
+        );
+    }
+
+    #[test]
+    fn field_counts_the_body_can_not_hold_are_rejected() {
+        let mut data = vec![3, 0, 0, 0, 2];
+        data.extend_from_slice(&[0; WIDE_FIELD_HEADER_BYTES + 7]);
+        assert_eq!(
+            Some(Error::IncompleteFieldTagOrLength),
+            WideFrameParser::new(&data).err()
+        );
+        assert_eq!(
+            Err(Error::IncompleteFieldTagOrLength),
+            frame_encoded_len(&data)
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! Derive macros for [yatlv](https://docs.rs/yatlv/).
+//!
+//! These are re-exported by yatlv when its `derive` feature is enabled; see
+//! `yatlv::ToFrame` and `yatlv::FromFrame` for details of the generated code.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Data, DeriveInput, Expr, Fields, GenericArgument, Ident, PathArguments, Type,
+};
+
+/// Derive `yatlv::ToFrame` for a struct with named fields.
+#[proc_macro_derive(ToFrame, attributes(yatlv))]
+pub fn derive_to_frame(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_frame(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `yatlv::FromFrame` for a struct with named fields.
+#[proc_macro_derive(FromFrame, attributes(yatlv))]
+pub fn derive_from_frame(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_frame(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// How many times a struct field appears in the frame.
+enum Repeat {
+    One,
+    Optional,
+    Repeated,
+}
+
+/// How a single value is stored in the frame.
+enum Kind<'a> {
+    /// Numbers, bools and chars, stored using the `add_<name>`/`get_<name>` methods.
+    Scalar(&'a Ident),
+    Str,
+    Bytes,
+    Uuid,
+    Frame(&'a Type),
+}
+
+struct FrameField<'a> {
+    ident: &'a Ident,
+    tag: Expr,
+    repeat: Repeat,
+    kind: Kind<'a>,
+}
+
+const SCALARS: &[&str] = &[
+    "bool", "char", "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128", "f32",
+    "f64",
+];
+
+fn frame_fields(input: &DeriveInput) -> syn::Result<Vec<FrameField<'_>>> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(unsupported(input)),
+        },
+        _ => return Err(unsupported(input)),
+    };
+    fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().expect("named field");
+            let (repeat, kind) = classify(&field.ty);
+            Ok(FrameField {
+                ident,
+                tag: field_tag(field)?,
+                repeat,
+                kind,
+            })
+        })
+        .collect()
+}
+
+fn unsupported(input: &DeriveInput) -> syn::Error {
+    syn::Error::new_spanned(
+        input,
+        "yatlv frames can only be derived for structs with named fields",
+    )
+}
+
+fn field_tag(field: &syn::Field) -> syn::Result<Expr> {
+    let mut tag = None;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("yatlv")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                tag = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported yatlv attribute"))
+            }
+        })?;
+    }
+    tag.ok_or_else(|| syn::Error::new_spanned(field, "missing #[yatlv(tag = ...)] attribute"))
+}
+
+/// The type argument of `ty` if it is `name<T>`.
+fn type_argument<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != name {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn classify(ty: &Type) -> (Repeat, Kind<'_>) {
+    if let Some(inner) = type_argument(ty, "Option") {
+        (Repeat::Optional, kind(inner))
+    } else if let Some(inner) = type_argument(ty, "Vec").filter(|inner| !is_named(inner, "u8")) {
+        (Repeat::Repeated, kind(inner))
+    } else {
+        (Repeat::One, kind(ty))
+    }
+}
+
+fn kind(ty: &Type) -> Kind<'_> {
+    if let Some(inner) = type_argument(ty, "Vec") {
+        if is_named(inner, "u8") {
+            return Kind::Bytes;
+        }
+    }
+    if let Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            if segment.arguments.is_empty() {
+                let ident = &segment.ident;
+                if SCALARS.iter().any(|s| ident == s) {
+                    return Kind::Scalar(ident);
+                } else if ident == "String" {
+                    return Kind::Str;
+                } else if ident == "Uuid" {
+                    return Kind::Uuid;
+                }
+            }
+        }
+    }
+    Kind::Frame(ty)
+}
+
+fn is_named(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(path) => path.path.is_ident(name),
+        _ => false,
+    }
+}
+
+fn to_frame(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let adds = frame_fields(input)?.into_iter().map(|field| {
+        let ident = field.ident;
+        let add = add_value(&field);
+        match field.repeat {
+            Repeat::One => quote! { { let value = &self.#ident; #add } },
+            Repeat::Optional => quote! { if let Some(value) = &self.#ident { #add } },
+            Repeat::Repeated => quote! { for value in &self.#ident { #add } },
+        }
+    });
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::yatlv::ToFrame for #name #ty_generics #where_clause {
+            fn to_frame<B: ::yatlv::FrameBuilderLike>(&self, bld: &mut B) {
+                #(#adds)*
+            }
+        }
+    })
+}
+
+/// Add `value` (a reference to a single value) to `bld`.
+fn add_value(field: &FrameField) -> TokenStream2 {
+    let tag = &field.tag;
+    match &field.kind {
+        Kind::Scalar(name) => {
+            let add = format_ident!("add_{}", name);
+            quote! { bld.#add(#tag, *value); }
+        }
+        Kind::Str => quote! { bld.add_str(#tag, value); },
+        Kind::Bytes => quote! { bld.add_data(#tag, value); },
+        Kind::Uuid => quote! { bld.add_uuid(#tag, value); },
+        Kind::Frame(_) => quote! {
+            ::yatlv::ToFrame::to_frame(value, &mut bld.add_frame(#tag));
+        },
+    }
+}
+
+fn from_frame(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let gets = frame_fields(input)?.into_iter().map(|field| {
+        let ident = field.ident;
+        let tag = &field.tag;
+        let get = match field.repeat {
+            Repeat::One => {
+                let get = get_value(&field);
+                quote! { #get?.ok_or(::yatlv::Error::MissingField(#tag))? }
+            }
+            Repeat::Optional => {
+                let get = get_value(&field);
+                quote! { #get? }
+            }
+            Repeat::Repeated => {
+                let get = get_values(&field);
+                quote! { #get.collect::<::yatlv::Result<_>>()? }
+            }
+        };
+        quote! { #ident: #get }
+    });
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::yatlv::FromFrame for #name #ty_generics #where_clause {
+            fn from_frame(parser: &::yatlv::FrameParser<'_>) -> ::yatlv::Result<Self> {
+                ::yatlv::Result::Ok(#name { #(#gets),* })
+            }
+        }
+    })
+}
+
+/// Read the first value as a `Result<Option<_>>`.
+fn get_value(field: &FrameField) -> TokenStream2 {
+    let tag = &field.tag;
+    match &field.kind {
+        Kind::Scalar(name) => {
+            let get = format_ident!("get_{}", name);
+            quote! { parser.#get(#tag) }
+        }
+        Kind::Str => quote! { parser.get_string(#tag) },
+        Kind::Bytes => quote! { ::yatlv::Result::Ok(parser.get_bytes(#tag)) },
+        Kind::Uuid => quote! { parser.get_uuid(#tag) },
+        Kind::Frame(ty) => quote! {
+            parser.get_frame(#tag).and_then(|frame| {
+                frame
+                    .map(|frame| <#ty as ::yatlv::FromFrame>::from_frame(&frame))
+                    .transpose()
+            })
+        },
+    }
+}
+
+/// Read every value as an iterator of `Result<_>`.
+fn get_values(field: &FrameField) -> TokenStream2 {
+    let tag = &field.tag;
+    match &field.kind {
+        Kind::Scalar(name) => {
+            let get = format_ident!("get_{}s", name);
+            quote! { parser.#get(#tag) }
+        }
+        Kind::Str => quote! { parser.get_strings(#tag) },
+        Kind::Bytes => quote! { parser.get_bytes_all(#tag).map(::yatlv::Result::Ok) },
+        Kind::Uuid => quote! { parser.get_uuids(#tag) },
+        Kind::Frame(ty) => quote! {
+            parser.get_frames(#tag).map(|frame| {
+                frame.and_then(|frame| <#ty as ::yatlv::FromFrame>::from_frame(&frame))
+            })
+        },
+    }
+}